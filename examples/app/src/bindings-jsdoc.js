@@ -125,28 +125,154 @@ export const universalConstant = 42;
 /** tauri-specta globals **/
 
 import {
-	invoke as TAURI_INVOKE,
+	invoke as __TAURI_INVOKE__,
 	Channel as TAURI_CHANNEL,
 } from "@tauri-apps/api/core";
 import * as TAURI_API_EVENT from "@tauri-apps/api/event";
 
-/** @typedef {typeof import("@tauri-apps/api/window").WebviewWindowHandle} __WebviewWindowHandle__ */
+/** @typedef {import("@tauri-apps/api/webviewWindow").WebviewWindow} __WebviewWindow__ */
+
+/**
+ * @typedef {{
+ *   signal?: AbortSignal;
+ *   window?: string;
+ *   target?: string | TAURI_API_EVENT.EventTarget;
+ * }} __ListenOptions__
+ */
 
 /**
  * @template T
  * @typedef {{
  *   listen: (
- *	   cb: TAURI_API_EVENT.EventCallback<T>
+ *	   cb: TAURI_API_EVENT.EventCallback<T>,
+ *	   options?: __ListenOptions__
  *	 ) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
  *	 once: (
- *	   cb: TAURI_API_EVENT.EventCallback<T>
+ *	   cb: TAURI_API_EVENT.EventCallback<T>,
+ *	   options?: __ListenOptions__
  *	 ) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
  *	 emit: T extends null
  *	   ? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
  *     : (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+ *	 emitTo: T extends null
+ *	   ? (target: string | TAURI_API_EVENT.EventTarget, payload?: T) => ReturnType<typeof TAURI_API_EVENT.emitTo>
+ *     : (target: string | TAURI_API_EVENT.EventTarget, payload: T) => ReturnType<typeof TAURI_API_EVENT.emitTo>;
  *	}} __EventObj__<T>
  */
 
+/** @typedef {Pick<typeof TAURI_API_EVENT, "listen" | "once" | "emit" | "emitTo">} __EventHandlers__ */
+
+let TAURI_INVOKE = __TAURI_INVOKE__;
+/** @type {__EventHandlers__} */
+let TAURI_EVENT = TAURI_API_EVENT;
+
+/**
+ * Replace the `invoke` used by every command, Eg. with a mock in tests. Pass `null` to restore the default.
+ * @param {typeof __TAURI_INVOKE__ | null} handler
+ */
+const __setInvokeHandler = (handler) => {
+	TAURI_INVOKE = handler ?? __TAURI_INVOKE__;
+};
+
+/**
+ * Replace the `listen`, `once`, `emit` and `emitTo` used by every event, Eg. with mocks in tests. Pass `null` to restore the defaults.
+ * @param {Partial<__EventHandlers__> | null} handlers
+ */
+const __setEventHandlers = (handlers) => {
+	TAURI_EVENT = {
+		listen: handlers?.listen ?? TAURI_API_EVENT.listen,
+		once: handlers?.once ?? TAURI_API_EVENT.once,
+		emit: handlers?.emit ?? TAURI_API_EVENT.emit,
+		emitTo: handlers?.emitTo ?? TAURI_API_EVENT.emitTo,
+	};
+};
+
+/**
+ * Unlisten once the signal is aborted, even if it's aborted before the listener is registered.
+ * @param {() => Promise<TAURI_API_EVENT.UnlistenFn>} register
+ * @param {__ListenOptions__} [options]
+ * @returns {Promise<TAURI_API_EVENT.UnlistenFn>}
+ */
+function __listen__(register, options) {
+	const signal = options?.signal;
+	if (signal?.aborted) return Promise.resolve(() => {});
+
+	const unlisten = register();
+	// Waiting on the registration handles the signal aborting while it's still in flight.
+	signal?.addEventListener("abort", () => unlisten.then((f) => f()), { once: true });
+	return unlisten;
+}
+
+/**
+ * The options to listen with, targeting `options.target`, `options.window` or else the window the event is registered for.
+ * @param {__ListenOptions__} [options]
+ * @param {string} [label]
+ */
+function __listenTarget__(options, label) {
+	const target = options?.target ?? options?.window ?? label;
+	return target === undefined ? undefined : { target };
+}
+
+/**
+ * Invoke a command with a new channel and iterate over its messages, ending once the command returns or throwing if it fails.
+ *
+ * Channels can't apply backpressure, so messages are buffered until they're consumed.
+ * Returning early drops the channel and any buffered messages.
+ * @template T
+ * @param {(channel: TAURI_CHANNEL<T>) => Promise<unknown>} invoke
+ * @returns {AsyncIterable<T>}
+ */
+function __channelStream__(invoke) {
+	return {
+		[Symbol.asyncIterator]() {
+			/** @type {TAURI_CHANNEL<T>} */
+			const channel = new TAURI_CHANNEL();
+			/** @type {T[]} */
+			const buffer = [];
+			/** @type {{ done: boolean, error?: { value: unknown } }} */
+			let state = { done: false };
+			let wake = () => {};
+
+			channel.onmessage = (message) => {
+				buffer.push(message);
+				wake();
+			};
+			invoke(channel).then(
+				() => {
+					if (!state.done) state = { done: true };
+					wake();
+				},
+				(value) => {
+					if (!state.done) state = { done: true, error: { value } };
+					wake();
+				},
+			);
+
+			return {
+				async next() {
+					while (buffer.length === 0 && !state.done)
+						await new Promise((resolve) => (wake = resolve));
+
+					if (buffer.length > 0) return { done: false, value: /** @type {T} */ (buffer.shift()) };
+					if (state.error) {
+						const { value } = state.error;
+						state = { done: true };
+						throw value;
+					}
+					return { done: true, value: undefined };
+				},
+				async return() {
+					channel.onmessage = () => {};
+					buffer.length = 0;
+					state = { done: true };
+					wake();
+					return { done: true, value: undefined };
+				},
+			};
+		},
+	};
+}
+
 /**
  * @template T,E
  * @typedef { { status: "ok", data: T } | { status: "error", error: E } } Result
@@ -155,33 +281,48 @@ import * as TAURI_API_EVENT from "@tauri-apps/api/event";
 /**
  * @template {Record<string, any>} T
  * @param {Record<keyof T, string>} mappings
+ * @param {Partial<Record<keyof T, string>>} [windows]
  * @returns {{
  * 	 [K in keyof T]: __EventObj__<T[K]> & {
- *	   (handle: __WebviewWindowHandle__): __EventObj__<T[K]>;
+ *	   (handle: __WebviewWindow__): __EventObj__<T[K]>;
  *   };
  * }}
  */
-function __makeEvents__(mappings) {
+function __makeEvents__(mappings, windows) {
 	return new Proxy(
 		{},
 		{
 			get: (_, event) => {
 				const name = mappings[event];
+				const label = windows?.[event];
 
-				new Proxy(() => {}, {
+				return new Proxy(() => {}, {
 					apply: (_, __, [window]) => ({
-						listen: (arg) => window.listen(name, arg),
-						once: (arg) => window.once(name, arg),
+						listen: (arg, options) =>
+							__listen__(() => window.listen(name, arg), options),
+						once: (arg, options) =>
+							__listen__(() => window.once(name, arg), options),
 						emit: (arg) => window.emit(name, arg),
+						emitTo: (target, arg) => window.emitTo(target, name, arg),
 					}),
 					get: (_, command) => {
 						switch (command) {
 							case "listen":
-								return (arg) => TAURI_API_EVENT.listen(name, arg);
+								return (arg, options) =>
+									__listen__(
+										() => TAURI_EVENT.listen(name, arg, __listenTarget__(options, label)),
+										options,
+									);
 							case "once":
-								return (arg) => TAURI_API_EVENT.once(name, arg);
+								return (arg, options) =>
+									__listen__(
+										() => TAURI_EVENT.once(name, arg, __listenTarget__(options, label)),
+										options,
+									);
 							case "emit":
-								return (arg) => TAURI_API_EVENT.emit(name, arg);
+								return (arg) => TAURI_EVENT.emit(name, arg);
+							case "emitTo":
+								return (target, arg) => TAURI_EVENT.emitTo(target, name, arg);
 						}
 					},
 				});
@@ -189,3 +330,5 @@ function __makeEvents__(mappings) {
 		},
 	);
 }
+
+export { __setInvokeHandler, __setEventHandlers };
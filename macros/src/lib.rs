@@ -9,7 +9,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
     parse_macro_input, parse_quote, ConstParam, DeriveInput, GenericParam, Generics, LifetimeParam,
-    TypeParam, WhereClause,
+    LitStr, TypeParam, WhereClause,
 };
 
 #[proc_macro_derive(Event, attributes(tauri_specta))]
@@ -17,10 +17,34 @@ pub fn derive_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let crate_ref = quote!(tauri_specta);
 
     let DeriveInput {
-        ident, generics, ..
+        attrs,
+        ident,
+        generics,
+        ..
     } = parse_macro_input!(input);
 
-    let name = ident.to_string().to_kebab_case();
+    let mut window = None;
+    let mut event_name = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("tauri_specta")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("window") {
+                window = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("event_name") {
+                event_name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported tauri_specta attribute"))
+            }
+        });
+
+        if let Err(err) = result {
+            return err.into_compile_error().into();
+        }
+    }
+
+    let name = event_name.unwrap_or_else(|| ident.to_string().to_kebab_case());
+    let window = window.map(|window| quote!(const WINDOW: Option<&'static str> = Some(#window);));
     let bounds = generics_with_ident_and_bounds_only(&generics);
     let type_args = generics_with_ident_only(&generics);
     let where_bound = add_type_to_where_clause(&generics);
@@ -29,6 +53,7 @@ pub fn derive_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         #[automatically_derived]
         impl #bounds #crate_ref::Event for #ident #type_args #where_bound {
             const NAME: &'static str = #name;
+            #window
         }
     }
     .into()
@@ -0,0 +1,78 @@
+//! Helpers for exporting bindings from a `build.rs` file.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use specta_typescript::Typescript;
+//! use tauri_specta::{build_script::BuildScript, Builder};
+//!
+//! fn main() {
+//!     BuildScript::new(Builder::<tauri::Wry>::new()).run(Typescript::default());
+//! }
+//! ```
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use tauri::Runtime;
+
+use crate::{Builder, LanguageExt};
+
+/// The environment variable which overrides where the bindings are written.
+pub const BINDINGS_PATH_ENV: &str = "TAURI_SPECTA_BINDINGS_PATH";
+
+/// Export the bindings of a [`Builder`] from a `build.rs` file.
+///
+/// On construction this tells Cargo to rerun the build script whenever `src/` or the [`BINDINGS_PATH_ENV`] environment variable changes.
+///
+/// The bindings are written to the path in [`BINDINGS_PATH_ENV`] if it's set, otherwise `../src/bindings.ts` relative to `CARGO_MANIFEST_DIR`.
+/// Relative paths are resolved against `CARGO_MANIFEST_DIR`.
+pub struct BuildScript<R: Runtime = tauri::Wry> {
+    builder: Builder<R>,
+    path: PathBuf,
+}
+
+impl<R: Runtime> BuildScript<R> {
+    /// Construct a new build script from a [`Builder`].
+    pub fn new(builder: Builder<R>) -> Self {
+        println!("cargo:rerun-if-changed=src");
+        println!("cargo:rerun-if-env-changed={BINDINGS_PATH_ENV}");
+
+        let path = env::var_os(BINDINGS_PATH_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new("../src/bindings.ts").to_path_buf());
+
+        Self { builder, path }
+    }
+
+    /// Override the path the bindings are written to.
+    ///
+    /// This takes precedence over the [`BINDINGS_PATH_ENV`] environment variable.
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Export the bindings.
+    ///
+    /// A failed export is reported as a `cargo:warning` instead of aborting the build.
+    pub fn run<L>(self, language: L)
+    where
+        L: LanguageExt,
+        L::Error: Send + Sync + 'static,
+    {
+        let path = match env::var_os("CARGO_MANIFEST_DIR") {
+            Some(manifest_dir) => Path::new(&manifest_dir).join(&self.path),
+            None => self.path,
+        };
+
+        if let Err(err) = self.builder.export(language, &path) {
+            println!(
+                "cargo:warning=Tauri Specta failed to export bindings to '{}': {err}",
+                path.display()
+            );
+        }
+    }
+}
@@ -1,17 +1,23 @@
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    ffi::OsString,
+    fmt,
     fs::{self, File},
     io::Write,
     path::Path,
+    sync::Arc,
 };
 
 use crate::{
-    event::EventRegistryMeta, Commands, ErrorHandlingMode, EventRegistry, Events, LanguageExt,
+    apply_as_prefix, event::EventRegistryMeta, type_graph, AcronymStyle, BuilderValidationError,
+    Case, Commands, Constant, Error, ErrorHandlingMode, EventRegistry, Events, ItemType,
+    LanguageExt, ModuleFormat, QueryKind, TraitObjectPolicy,
 };
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use serde::Serialize;
 use specta::{
-    datatype::{DataType, Function},
+    datatype::{DataType, DeprecatedType, Function, FunctionResultVariant, PrimitiveType},
     NamedType, SpectaID, Type, TypeMap,
 };
 use tauri::{ipc::Invoke, Manager, Runtime};
@@ -83,39 +89,184 @@ use tauri::{ipc::Invoke, Manager, Runtime};
 pub struct Builder<R: Runtime = tauri::Wry> {
     // TODO: Can we just hold a `ExportContext` here to make it a bit neater???
     plugin_name: Option<&'static str>,
-    commands: Commands<R>,
+    invoke_handler: InvokeHandler<R>,
+    middleware: Vec<Middleware<R>>,
     command_types: Vec<Function>,
     error_handling: ErrorHandlingMode,
+    invoke_options: bool,
+    channel_streams: bool,
     events: BTreeMap<&'static str, DataType>,
     event_sids: BTreeSet<SpectaID>,
+    event_windows: BTreeMap<&'static str, &'static str>,
+    event_sid_windows: BTreeMap<SpectaID, &'static str>,
+    event_sid_names: BTreeMap<SpectaID, &'static str>,
+    duplicate_events: Vec<(&'static str, [SpectaID; 2])>,
+    event_plugins: BTreeMap<&'static str, Option<&'static str>>,
+    event_sid_plugins: BTreeMap<SpectaID, Option<&'static str>>,
+    /// The events registered with [`Self::additional_events`], which aren't mounted by [`Self::mount_events`].
+    additional_event_sids: BTreeSet<SpectaID>,
     types: TypeMap,
-    constants: HashMap<Cow<'static, str>, serde_json::Value>,
+    explicit_types: BTreeSet<SpectaID>,
+    constants: BTreeMap<Cow<'static, str>, Constant>,
+    constant_groups: BTreeMap<Cow<'static, str>, BTreeMap<Cow<'static, str>, Constant>>,
+    schema_version_constant: Option<Cow<'static, str>>,
+    // `BTreeMap`s like the builder's other maps, so merging and exporting stay deterministic.
+    deprecated_commands: BTreeMap<Cow<'static, str>, DeprecatedType>,
+    deprecated_events: BTreeMap<Cow<'static, str>, DeprecatedType>,
+    command_case: Case,
+    event_case: Case,
+    acronym_style: AcronymStyle,
+    command_renames: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    event_renames: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    command_namespaces: BTreeMap<Cow<'static, str>, Vec<Cow<'static, str>>>,
+    event_namespaces: BTreeMap<&'static str, Vec<Cow<'static, str>>>,
+    query_kinds: BTreeMap<Cow<'static, str>, QueryKind>,
+    required_permissions: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    module_format: ModuleFormat,
+    tauri_api_path: Option<Cow<'static, str>>,
+    type_prefix: Option<Cow<'static, str>>,
+    type_suffix: Cow<'static, str>,
+    max_recursion_depth: u32,
+    trait_object_policy: TraitObjectPolicy,
+    type_aliases: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    type_overrides: BTreeMap<SpectaID, Cow<'static, str>>,
+    type_imports: BTreeMap<SpectaID, Cow<'static, str>>,
+    enum_variants: BTreeSet<SpectaID>,
+    internal_types: BTreeSet<SpectaID>,
+    skip_unchanged: bool,
+    /// The kind and name of every item which collided while merging with [`MergePolicy::Error`].
+    conflicts: Vec<(&'static str, String)>,
+}
+
+impl<R: Runtime> Clone for Builder<R> {
+    fn clone(&self) -> Self {
+        Self {
+            plugin_name: self.plugin_name,
+            invoke_handler: self.invoke_handler.clone(),
+            middleware: self.middleware.clone(),
+            command_types: self.command_types.clone(),
+            error_handling: self.error_handling,
+            invoke_options: self.invoke_options,
+            channel_streams: self.channel_streams,
+            events: self.events.clone(),
+            event_sids: self.event_sids.clone(),
+            event_windows: self.event_windows.clone(),
+            event_sid_windows: self.event_sid_windows.clone(),
+            event_sid_names: self.event_sid_names.clone(),
+            duplicate_events: self.duplicate_events.clone(),
+            event_plugins: self.event_plugins.clone(),
+            event_sid_plugins: self.event_sid_plugins.clone(),
+            additional_event_sids: self.additional_event_sids.clone(),
+            types: self.types.clone(),
+            explicit_types: self.explicit_types.clone(),
+            constants: self.constants.clone(),
+            constant_groups: self.constant_groups.clone(),
+            schema_version_constant: self.schema_version_constant.clone(),
+            deprecated_commands: self.deprecated_commands.clone(),
+            deprecated_events: self.deprecated_events.clone(),
+            command_case: self.command_case,
+            event_case: self.event_case,
+            acronym_style: self.acronym_style.clone(),
+            command_renames: self.command_renames.clone(),
+            event_renames: self.event_renames.clone(),
+            command_namespaces: self.command_namespaces.clone(),
+            event_namespaces: self.event_namespaces.clone(),
+            query_kinds: self.query_kinds.clone(),
+            required_permissions: self.required_permissions.clone(),
+            module_format: self.module_format,
+            tauri_api_path: self.tauri_api_path.clone(),
+            type_prefix: self.type_prefix.clone(),
+            type_suffix: self.type_suffix.clone(),
+            max_recursion_depth: self.max_recursion_depth,
+            trait_object_policy: self.trait_object_policy,
+            type_aliases: self.type_aliases.clone(),
+            type_overrides: self.type_overrides.clone(),
+            type_imports: self.type_imports.clone(),
+            enum_variants: self.enum_variants.clone(),
+            internal_types: self.internal_types.clone(),
+            skip_unchanged: self.skip_unchanged,
+            conflicts: self.conflicts.clone(),
+        }
+    }
+}
+
+impl<R: Runtime> fmt::Debug for Builder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field("plugin_name", &self.plugin_name)
+            .field("commands", &self.command_names())
+            .field("events", &self.event_names())
+            .field("constants", &self.constant_keys())
+            .field("types", &self.types.len())
+            .finish_non_exhaustive()
+    }
 }
 
 impl<R: Runtime> Default for Builder<R> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Runtime> Builder<R> {
+    /// Construct a new Tauri Specta builder.
+    ///
+    /// The runtime is inferred from how the builder is used, Eg. when passing [`Self::invoke_handler`] to [`tauri::Builder::invoke_handler`].
+    /// When it's only used for exporting, name the runtime with `Builder::<tauri::Wry>::new()` or use `<Builder>::default()`, which defaults to [`tauri::Wry`].
+    pub fn new() -> Self {
         Self {
             plugin_name: None,
-            commands: Commands::default(),
+            invoke_handler: Commands::default().0,
+            middleware: Default::default(),
             command_types: Default::default(),
             error_handling: Default::default(),
+            invoke_options: false,
+            channel_streams: false,
             events: Default::default(),
             event_sids: Default::default(),
+            event_windows: Default::default(),
+            event_sid_windows: Default::default(),
+            event_sid_names: Default::default(),
+            duplicate_events: Default::default(),
+            event_plugins: Default::default(),
+            event_sid_plugins: Default::default(),
+            additional_event_sids: Default::default(),
             types: TypeMap::default(),
-            constants: HashMap::default(),
+            explicit_types: Default::default(),
+            constants: BTreeMap::default(),
+            constant_groups: Default::default(),
+            schema_version_constant: None,
+            deprecated_commands: Default::default(),
+            deprecated_events: Default::default(),
+            command_case: Default::default(),
+            event_case: Default::default(),
+            acronym_style: Default::default(),
+            command_renames: Default::default(),
+            event_renames: Default::default(),
+            command_namespaces: Default::default(),
+            event_namespaces: Default::default(),
+            query_kinds: Default::default(),
+            required_permissions: Default::default(),
+            module_format: Default::default(),
+            tauri_api_path: None,
+            type_prefix: None,
+            type_suffix: Default::default(),
+            max_recursion_depth: 32,
+            trait_object_policy: Default::default(),
+            type_aliases: Default::default(),
+            type_overrides: Default::default(),
+            type_imports: Default::default(),
+            enum_variants: Default::default(),
+            internal_types: Default::default(),
+            skip_unchanged: true,
+            conflicts: Default::default(),
         }
     }
-}
-
-impl<R: Runtime> Builder<R> {
-    /// Construct a new Tauri Specta builder.
-    pub fn new() -> Self {
-        Self::default()
-    }
 
     /// Set the name of the current plugin name.
     ///
     /// This is used to ensure the generated bindings correctly reference the plugin.
+    /// The exported Typescript types are prefixed with the plugin name so they don't collide with the types of the app or other plugins, unless [`Self::type_prefix`] is set.
     pub fn plugin_name(self, plugin_name: &'static str) -> Self {
         Self {
             plugin_name: Some(plugin_name),
@@ -125,7 +276,8 @@ impl<R: Runtime> Builder<R> {
 
     /// Register commands with the builder.
     ///
-    /// **WARNING:** This method will overwrite any previously registered commands.
+    /// This can be called multiple times to register commands from different modules.
+    /// Registering two commands with the same name is reported by [`Self::validate`] and will cause exporting to fail.
     ///
     /// # Example
     ///
@@ -141,16 +293,62 @@ impl<R: Runtime> Builder<R> {
     /// let mut builder = Builder::<tauri::Wry>::new().commands(collect_commands![hello_world]);
     /// ```
     pub fn commands(mut self, commands: Commands<R>) -> Self {
-        Self {
-            command_types: (commands.1)(&mut self.types),
-            commands,
-            ..self
+        let command_types = (commands.1)(&mut self.types);
+        for (i, function) in command_types.iter().enumerate() {
+            if command_types[..i]
+                .iter()
+                .any(|f| f.name() == function.name())
+            {
+                self.conflicts
+                    .push(("command", function.name().to_string()));
+            }
         }
+
+        self.merge_with_policy(
+            Self {
+                invoke_handler: commands.0,
+                command_types,
+                ..Self::new()
+            },
+            MergePolicy::Error,
+        )
+    }
+
+    /// Wrap every command with a middleware, Eg. for logging, timing or authentication.
+    ///
+    /// The middleware is called with each invoke and the next handler in the chain, which it should call to run the command.
+    /// Returning without calling `next` prevents the command from running, in which case the middleware should reject the invoke itself.
+    /// The name of the command is available through `invoke.message.command()`.
+    ///
+    /// Middlewares compose in registration order, so the first one registered is the outermost.
+    /// When builders are merged, the middlewares of the other builder only wrap its own commands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().add_middleware(|invoke, next| {
+    ///     let command = invoke.message.command().to_string();
+    ///     let start = Instant::now();
+    ///     let handled = next(invoke);
+    ///     println!("{command} took {:?}", start.elapsed());
+    ///     handled
+    /// });
+    /// ```
+    pub fn add_middleware(
+        mut self,
+        middleware: impl Fn(Invoke<R>, &dyn Fn(Invoke<R>) -> bool) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
     }
 
     /// Register events with the builder.
     ///
-    /// **WARNING:** This method will overwrite any previously registered events.
+    /// This can be called multiple times to register events from different modules.
+    /// Registering two events with the same name will cause exporting to fail.
     ///
     /// # Example
     ///
@@ -165,33 +363,124 @@ impl<R: Runtime> Builder<R> {
     /// let mut builder = Builder::<tauri::Wry>::new().events(collect_events![DemoEvent]);
     /// ```
     pub fn events(mut self, events: Events) -> Self {
+        for (name, (existing, registration), sid) in &events.1 {
+            // The displaced event's type is registered so the error can say where it's defined.
+            registration(&mut self.types);
+            self.duplicate_events.push((name, [*existing, *sid]));
+        }
+        for (name, (sid, ..)) in &events.0 {
+            if let Some((existing, _)) = self
+                .event_sid_names
+                .iter()
+                .find(|(existing, n)| *n == name && *existing != sid)
+            {
+                self.duplicate_events.push((name, [*existing, *sid]));
+            }
+        }
+
         let mut event_sids = BTreeSet::new();
+        let mut event_windows = BTreeMap::new();
+        let mut event_sid_windows = BTreeMap::new();
+        let mut event_sid_names = BTreeMap::new();
         let events = events
             .0
             .iter()
-            .map(|(k, build)| {
-                let (sid, dt) = build(&mut self.types);
-                event_sids.insert(sid);
+            .map(|(k, (sid, window, build))| {
+                let dt = build(&mut self.types);
+                event_sids.insert(*sid);
+                event_sid_names.insert(*sid, *k);
+                if let Some(window) = *window {
+                    event_windows.insert(*k, window);
+                    event_sid_windows.insert(*sid, window);
+                }
                 (*k, dt)
             })
             .collect();
 
-        self.types
-            .remove(<tauri::ipc::Channel<()> as specta::NamedType>::sid());
+        self.merge_with_policy(
+            Self {
+                events,
+                event_sids,
+                event_windows,
+                event_sid_windows,
+                event_sid_names,
+                ..Self::new()
+            },
+            MergePolicy::Error,
+        )
+    }
 
-        Self {
-            events,
-            event_sids,
-            ..self
+    /// Register events which are exported like [`Self::events`], but aren't mounted by [`Self::mount_events`].
+    ///
+    /// This is useful for events of a module which is only enabled at runtime. Mount them once it's enabled with [`Self::mount_additional_events`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Serialize, Deserialize};
+    /// use specta::Type;
+    /// use tauri_specta::{Builder, collect_events, Event};
+    ///
+    /// #[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+    /// pub struct ScriptOutput(String);
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().additional_events(collect_events![ScriptOutput]);
+    /// ```
+    pub fn additional_events(self, events: Events) -> Self {
+        let sids = events.0.values().map(|(sid, ..)| *sid).collect::<Vec<_>>();
+        let mut builder = self.events(events);
+        builder.additional_event_sids.extend(sids);
+        builder
+    }
+
+    /// Group commands and events under a namespace in the generated bindings.
+    ///
+    /// The commands and events registered by `f` are exported within a namespace with the camelCased `name` instead of at the top level.
+    /// Namespaces can be nested.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, collect_commands};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn get_user() {}
+    ///
+    /// // Exported as `users.commands.getUser()`
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .namespace("users", |b| b.commands(collect_commands![get_user]));
+    /// ```
+    pub fn namespace(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        let name = name.into();
+        let mut namespace = f(Self::new());
+
+        for function in &namespace.command_types {
+            namespace
+                .command_namespaces
+                .entry(function.name().clone())
+                .or_default()
+                .insert(0, name.clone());
+        }
+        for event in namespace.events.keys() {
+            namespace
+                .event_namespaces
+                .entry(*event)
+                .or_default()
+                .insert(0, name.clone());
         }
+
+        self.merge_with_policy(namespace, MergePolicy::Error)
     }
 
     /// This method is deprecated. Please use [Self::typ].
     #[deprecated(note = "Use `Self::ty` instead")]
-    pub fn ty<T: NamedType>(mut self) -> Self {
-        let dt = T::definition_named_data_type(&mut self.types);
-        self.types.insert(T::sid(), dt);
-        self
+    pub fn ty<T: NamedType>(self) -> Self {
+        self.typ::<T>()
     }
 
     /// Export a new type with the frontend.
@@ -212,147 +501,2654 @@ impl<R: Runtime> Builder<R> {
     ///
     /// let mut builder = Builder::<tauri::Wry>::new().typ::<MyStruct>();
     /// ```
+    ///
+    /// Generic types are exported once with their type parameters, Eg. `.typ::<Page<User>>()` exports `Page<T>` and `User`.
     pub fn typ<T: NamedType>(mut self) -> Self {
         let dt = T::definition_named_data_type(&mut self.types);
         self.types.insert(T::sid(), dt);
+        self.explicit_types.insert(T::sid());
+        // The definition only holds the type parameters, so register the type arguments by referencing the type.
+        T::reference(&mut self.types, &[]);
         self
     }
 
-    /// Export a constant value to the frontend.
+    /// Override the generated Typescript for a type.
     ///
-    /// This is useful to share application-wide constants or expose data which is generated by Rust.
+    /// This is useful for types from external crates which you can't annotate with `#[specta(type = ...)]`.
+    /// The type is exported as an alias of `ty`, so everywhere it's used resolves to `ty`.
+    /// The type is inserted verbatim into the generated bindings, so it must be a valid Typescript type.
     ///
     /// # Example
     ///
     /// ```
     /// use tauri_specta::Builder;
+    /// use specta::Type;
     ///
-    /// let mut builder = Builder::<tauri::Wry>::new().constant("CONSTANT_NAME","ANY_CONSTANT_VALUE");
+    /// #[derive(Type)]
+    /// pub struct Uuid(u128);
+    ///
+    /// // Exported as `export type Uuid = string;`
+    /// let mut builder = Builder::<tauri::Wry>::new().override_type::<Uuid>("string");
     /// ```
-    #[track_caller]
-    pub fn constant<T: Serialize + Type>(mut self, k: impl Into<Cow<'static, str>>, v: T) -> Self {
-        self.constants.insert(
-            k.into(),
-            serde_json::to_value(v).expect("Tauri Specta failed to serialize constant"),
-        );
+    pub fn override_type<T: NamedType>(mut self, ty: impl Into<Cow<'static, str>>) -> Self {
+        self.type_overrides.insert(T::sid(), ty.into());
+        self.type_imports.remove(&T::sid());
         self
     }
 
-    /// Set the error handling mode for the generated bindings.
-    pub fn error_handling(mut self, error_handling: ErrorHandlingMode) -> Self {
-        self.error_handling = error_handling;
+    /// Override the generated Typescript for a type with a type which must be imported.
+    ///
+    /// This is the same as [`Self::override_type`], except `import` is added to the generated bindings so `ty` can refer to it.
+    /// Exporting fails if `ty` is also the name of an exported type, as they would conflict. Rename the import instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    /// use specta::Type;
+    ///
+    /// #[derive(Type)]
+    /// pub struct Decimal(String);
+    ///
+    /// // Exported as `export type Decimal = MoneyDecimal;`
+    /// let mut builder = Builder::<tauri::Wry>::new().override_type_with_import::<Decimal>(
+    ///     "MoneyDecimal",
+    ///     r#"import type { Decimal as MoneyDecimal } from "./money";"#,
+    /// );
+    /// ```
+    pub fn override_type_with_import<T: NamedType>(
+        mut self,
+        ty: impl Into<Cow<'static, str>>,
+        import: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.type_overrides.insert(T::sid(), ty.into());
+        self.type_imports.insert(T::sid(), import.into());
         self
     }
 
-    // TODO: Maybe method to merge in a `TypeCollection`
+    /// Export an array of the variant names of an enum alongside its type.
+    ///
+    /// This is useful for iterating the variants at runtime, Eg. to render select options, without duplicating them in your frontend.
+    /// The enum is exported as `<EnumName>Variants` and the names match how serde serializes the variants, including any `#[serde(rename)]` or `#[serde(rename_all)]`.
+    /// Types which aren't enums are exported as usual without an array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    /// use specta::Type;
+    ///
+    /// #[derive(Type)]
+    /// #[serde(rename_all = "lowercase")]
+    /// pub enum Theme {
+    ///     Light,
+    ///     Dark,
+    /// }
+    ///
+    /// // Exported as `export const ThemeVariants = ["light", "dark"] as const;`
+    /// let mut builder = Builder::<tauri::Wry>::new().enum_variants::<Theme>();
+    /// ```
+    pub fn enum_variants<T: NamedType>(mut self) -> Self {
+        self.enum_variants.insert(T::sid());
+        self.typ::<T>()
+    }
 
-    // TODO: Should we put a `.build` command here to ensure it's immutable from now on?
+    /// Exclude a type from the generated bindings.
+    ///
+    /// This is useful for internal types which end up in the bindings, Eg. because a command accepts them, but shouldn't be part of your frontend's API.
+    /// The type's definition is replaced with `unknown`, so everywhere it's used is typed as `unknown`, and it's marked as internal like [`Self::mark_internal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    /// use specta::Type;
+    ///
+    /// #[derive(Type)]
+    /// pub struct InternalState {
+    ///     generation: u32,
+    /// }
+    ///
+    /// // Exported as `export type InternalState = unknown;`
+    /// let mut builder = Builder::<tauri::Wry>::new().exclude_type::<InternalState>();
+    /// ```
+    pub fn exclude_type<T: NamedType>(self) -> Self {
+        self.override_type::<T>("unknown").mark_internal::<T>()
+    }
 
-    /// The Tauri invoke handler to trigger commands registered with the builder.
-    pub fn invoke_handler(&self) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
-        let commands = self.commands.0.clone();
-        move |invoke| commands(invoke)
+    /// Mark a type as internal so it's not re-exported from the `index.ts` generated by [`Self::export_ts_dir`].
+    ///
+    /// The type is still exported from `types.ts` so the other generated files can reference it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    /// use serde::{Serialize, Deserialize};
+    /// use specta::Type;
+    ///
+    /// #[derive(Serialize, Deserialize, Type)]
+    /// pub struct CacheEntry {
+    ///     key: String
+    /// }
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().mark_internal::<CacheEntry>();
+    /// ```
+    pub fn mark_internal<T: NamedType>(mut self) -> Self {
+        self.internal_types.insert(T::sid());
+        self
     }
 
-    /// Mount all of the events in the builder onto a Tauri app.
+    /// Export a type alias to the frontend.
     ///
-    /// This should be called within [`tauri::Builder::setup`](tauri::Builder::setup) like the example below.
+    /// This is useful to document the meaning of a primitive type at the API boundary without a Rust newtype.
+    /// The type is inserted verbatim into the generated bindings, so it must be a valid Typescript type.
     ///
     /// # Example
     ///
-    /// ```rust,no_run
-    /// use tauri_specta::{Builder, collect_events};
+    /// ```
+    /// use tauri_specta::Builder;
     ///
-    /// let mut builder = Builder::<tauri::Wry>::new().events(collect_events![]);
+    /// // Exported as `export type UserId = string;`
+    /// let mut builder = Builder::<tauri::Wry>::new().type_alias("UserId", "string");
+    /// ```
+    pub fn type_alias(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        ty: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.type_aliases.insert(name.into(), ty.into());
+        self
+    }
+
+    /// Export a constant value to the frontend.
+    ///
+    /// This is useful to share application-wide constants or expose data which is generated by Rust.
+    ///
+    /// When exporting to Typescript the constant will be exported with `as const` so it keeps its literal type.
+    /// Constants of a named type (Eg. `Settings`, `Vec<Settings>` or `Option<Settings>`) are annotated with that type instead.
+    /// Use [`Self::constant_widened`] if you would prefer the widened type (Eg. `string` instead of `"kiosk"`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized, Eg. a map with non-string keys. Use [`Self::try_constant`] to handle this instead.
+    ///
+    /// # Example
     ///
-    /// tauri::Builder::default()
-    ///     .setup(move |app| {
-    ///         builder.mount_events(app);
-    ///             
-    ///         Ok(())
-    ///     })
-    ///     // on an actual app, remove the string argument
-    ///     .run(tauri::generate_context!("tests/tauri.conf.json"))
-    ///     .expect("error while running tauri application");
     /// ```
-    pub fn mount_events(&self, handle: &impl Manager<R>) {
-        let registry = EventRegistry::get_or_manage(handle);
-        let mut map = registry.0.write().expect("Failed to lock EventRegistry");
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().constant("CONSTANT_NAME","ANY_CONSTANT_VALUE");
+    /// ```
+    #[track_caller]
+    pub fn constant<T: Serialize + Type>(self, k: impl Into<Cow<'static, str>>, v: T) -> Self {
+        self.constant_inner(k.into(), v, true)
+    }
 
-        for sid in &self.event_sids {
-            map.insert(
-                sid.clone(),
-                EventRegistryMeta {
-                    plugin_name: self.plugin_name,
-                },
-            );
-        }
+    /// Export a constant value to the frontend without preserving its literal type.
+    ///
+    /// Refer to [`Self::constant`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value can't be serialized, like [`Self::constant`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// // Exported as `export const APP_MODE = "kiosk";` which is typed as `string`.
+    /// let mut builder = Builder::<tauri::Wry>::new().constant_widened("APP_MODE", "kiosk");
+    /// ```
+    #[track_caller]
+    pub fn constant_widened<T: Serialize + Type>(
+        self,
+        k: impl Into<Cow<'static, str>>,
+        v: T,
+    ) -> Self {
+        self.constant_inner(k.into(), v, false)
     }
 
-    /// Export the bindings to a string.
+    /// Export a constant value to the frontend, returning an error if it can't be serialized.
     ///
-    /// You should prefer to use [`Self::export`], unless you need explicit control over saving.
+    /// Refer to [`Self::constant`] for more information.
     ///
     /// # Example
+    ///
     /// ```
-    /// use std::{
-    ///     fs::File,
-    ///     io::Write
-    /// };
-    /// use specta_typescript::Typescript;
+    /// use std::collections::BTreeMap;
+    /// use tauri_specta::{Builder, Error};
     ///
-    /// println!(
-    ///     "{}",
-    ///     tauri_specta::Builder::<tauri::Wry>::new()
-    ///         .export_str(Typescript::new())
-    ///         .unwrap()
-    /// );
+    /// // JSON objects only support string keys.
+    /// let limits = BTreeMap::from([((1, 2), "small")]);
+    ///
+    /// let result = Builder::<tauri::Wry>::new().try_constant("LIMITS", limits);
+    /// assert!(matches!(result, Err(Error::Constant { .. })));
     /// ```
-    pub fn export_str<L: LanguageExt>(&self, language: L) -> Result<String, L::Error> {
-        // TODO: Handle duplicate type names
-        // TODO: Serde checking
+    pub fn try_constant<T: Serialize + Type>(
+        self,
+        k: impl Into<Cow<'static, str>>,
+        v: T,
+    ) -> Result<Self, Error> {
+        self.try_constant_inner(k.into(), v, true)
+    }
 
-        language.render(&crate::ExportContext {
-            // TODO: Don't clone stuff
-            commands: self.command_types.clone(),
-            error_handling: self.error_handling,
-            events: self.events.clone(),
-            type_map: self.types.clone(),
-            constants: self.constants.clone(),
-            plugin_name: self.plugin_name,
-        })
+    #[track_caller]
+    fn constant_inner<T: Serialize + Type>(
+        self,
+        k: Cow<'static, str>,
+        v: T,
+        as_const: bool,
+    ) -> Self {
+        self.try_constant_inner(k, v, as_const)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
-    /// Export the bindings to a file.
+    fn try_constant_inner<T: Serialize + Type>(
+        mut self,
+        k: Cow<'static, str>,
+        v: T,
+        as_const: bool,
+    ) -> Result<Self, Error> {
+        let value = match serde_json::to_value(v) {
+            Ok(value) => value,
+            Err(source) => return Err(Error::Constant { name: k, source }),
+        };
+        let ty = T::reference(&mut self.types, &[]).inner;
+        self.constants.insert(
+            k,
+            Constant {
+                value,
+                ty,
+                as_const,
+            },
+        );
+        Ok(self)
+    }
+
+    /// Export a constant containing a hash of the commands, events and types, which can be used to detect when the frontend is out of date with the backend.
+    ///
+    /// The hash is deterministic, so it only changes when the signature of a command, the payload of an event or the structure of a type changes.
+    /// Exposing it with a command lets the frontend compare it against its own copy and prompt the user to refresh if they differ.
     ///
     /// # Example
+    ///
     /// ```
-    /// use tauri_specta::{Builder, collect_commands, collect_events};
-    /// use specta_typescript::Typescript;
+    /// use tauri_specta::Builder;
     ///
-    /// let mut builder = Builder::<tauri::Wry>::new()
-    ///     .commands(collect_commands![])
-    ///     .events(collect_events![]);
+    /// // Exported as `export const SCHEMA_VERSION = "a3f2b19c" as const;`
+    /// let mut builder = Builder::<tauri::Wry>::new().schema_version_constant("SCHEMA_VERSION");
+    /// ```
+    pub fn schema_version_constant(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.schema_version_constant = Some(name.into());
+        self
+    }
+
+    /// Group constants under a single object in the generated bindings.
+    ///
+    /// The constants registered by `f` are exported as the properties of an object with the camelCased `name` instead of at the top level.
+    /// The property names are also camelCased.
+    ///
+    /// Groups can't be nested, and a group can't have the same name as a top-level constant.
+    /// Both are reported by [`Self::validate`] and will cause exporting to fail.
+    ///
+    /// # Example
     ///
-    /// #[cfg(debug_assertions)] // only export on debug builds.
-    /// builder
-    ///     .export(Typescript::default(), "../src/bindings.ts")
-    ///     .expect("Failed to export typescript bindings");
     /// ```
-    pub fn export<L: LanguageExt>(
-        &self,
-        language: L,
-        path: impl AsRef<Path>,
-    ) -> Result<(), L::Error> {
-        let path = path.as_ref();
-        if let Some(export_dir) = path.parent() {
-            fs::create_dir_all(export_dir)?;
+    /// use tauri_specta::Builder;
+    ///
+    /// // Exported as `export const db = { poolSize: 10, timeoutSecs: 30 } as const;`
+    /// let mut builder = Builder::<tauri::Wry>::new().constant_group("db", |b| {
+    ///     b.constant("pool_size", 10_u32)
+    ///         .constant("timeout_secs", 30_u32)
+    /// });
+    /// ```
+    pub fn constant_group(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        let name = name.into();
+        let mut group = f(Self::new());
+
+        for nested in std::mem::take(&mut group.constant_groups).into_keys() {
+            group
+                .conflicts
+                .push(("nested constant group", format!("{name}.{nested}")));
         }
 
-        let mut file = File::create(&path)?;
-        write!(file, "{}", self.export_str(&language)?)?;
-        language.format(path).ok(); // TODO: Error handling
+        let constants = std::mem::take(&mut group.constants);
+        group
+            .constant_groups
+            .entry(name)
+            .or_default()
+            .extend(constants);
 
-        Ok(())
+        self.merge_with_policy(group, MergePolicy::Error)
     }
+
+    /// Set the case of the functions generated for commands. Defaults to [`Case::CamelCase`].
+    ///
+    /// Commands are still invoked with their Rust name. Commands renamed with [`Self::rename_command`] keep the name they were given.
+    /// Types always keep the name of the Rust type, use `#[specta(rename = "...")]` to change it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, Case};
+    ///
+    /// // `commands.GetUserById(id)`
+    /// let mut builder = Builder::<tauri::Wry>::new().command_case(Case::PascalCase);
+    /// ```
+    pub fn command_case(mut self, case: Case) -> Self {
+        self.command_case = case;
+        self
+    }
+
+    /// Set the case of the properties generated for events. Defaults to [`Case::CamelCase`].
+    ///
+    /// Refer to [`Self::command_case`] for more information.
+    pub fn event_case(mut self, case: Case) -> Self {
+        self.event_case = case;
+        self
+    }
+
+    /// Set how acronyms are cased in the camelCased and PascalCased identifiers generated for commands and events. Defaults to [`AcronymStyle::Lower`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{AcronymStyle, Builder};
+    ///
+    /// // `commands.getUserURL()` instead of `commands.getUserUrl()`
+    /// let mut builder =
+    ///     Builder::<tauri::Wry>::new().acronym_style(AcronymStyle::Preserve(vec!["URL".into(), "ID".into()]));
+    /// ```
+    pub fn acronym_style(mut self, acronym_style: AcronymStyle) -> Self {
+        self.acronym_style = acronym_style;
+        self
+    }
+
+    /// Rename the function generated for a command in the bindings.
+    ///
+    /// By default commands are generated as the camelCased name of the Rust function, Eg. `getUserById` for `get_user_by_id`.
+    /// The command is still invoked with its Rust name so this doesn't require any changes on the Rust side.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().rename_command("get_user_by_id", "fetchUser");
+    /// ```
+    pub fn rename_command(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        rename: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.command_renames.insert(name.into(), rename.into());
+        self
+    }
+
+    /// Rename the property an event is accessed through in the bindings, Eg. `events.onUserUpdated` instead of `events.userUpdated`.
+    ///
+    /// The event is still emitted with its name, refer to [`Self::rename_command`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().rename_event("user-updated", "onUserUpdated");
+    /// ```
+    pub fn rename_event(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        rename: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.event_renames.insert(name.into(), rename.into());
+        self
+    }
+
+    /// Mark a command as deprecated in the generated bindings.
+    ///
+    /// This is useful for commands which should keep working while the frontend migrates away from them.
+    /// Prefer Rust's `#[deprecated]` attribute on the command itself when it's deprecated for Rust callers too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .deprecate_command("old_command", "Use newCommand instead");
+    /// ```
+    pub fn deprecate_command(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        note: impl Into<Option<&'static str>>,
+    ) -> Self {
+        self.deprecated_commands
+            .insert(name.into(), deprecated_type(note.into()));
+        self
+    }
+
+    /// Mark an event as deprecated in the generated bindings.
+    ///
+    /// Refer to [`Self::deprecate_command`] for more information.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().deprecate_event("old-event", None);
+    /// ```
+    pub fn deprecate_event(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        note: impl Into<Option<&'static str>>,
+    ) -> Self {
+        self.deprecated_events
+            .insert(name.into(), deprecated_type(note.into()));
+        self
+    }
+
+    /// Override whether a command is generated as a query or a mutation by [`Self::export_react_query`].
+    ///
+    /// By default commands starting with `get_`, `list_` or `fetch_` are queries and every other command is a mutation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, QueryKind};
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .command_query_kind("search_users", QueryKind::Query);
+    /// ```
+    pub fn command_query_kind(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        kind: QueryKind,
+    ) -> Self {
+        self.query_kinds.insert(name.into(), kind);
+        self
+    }
+
+    /// Annotate a command with the Tauri v2 [permission](https://v2.tauri.app/security/permissions) a capability must grant to invoke it.
+    ///
+    /// The generated function is documented with a `@requires-permission` JSDoc tag
+    /// and the permission is included in [`Self::export_permissions_manifest`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .required_permission("my_command", "my-plugin:allow-my-command");
+    /// ```
+    pub fn required_permission(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        permission: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.required_permissions
+            .insert(name.into(), permission.into());
+        self
+    }
+
+    /// Set the error handling mode for the generated bindings.
+    pub fn error_handling(mut self, error_handling: ErrorHandlingMode) -> Self {
+        self.error_handling = error_handling;
+        self
+    }
+
+    /// Add a trailing `options` parameter to every generated command function, which is passed to Tauri's `invoke` as is.
+    ///
+    /// This allows setting headers on a single call, Eg. a trace ID which is read by middleware on the Rust side.
+    /// The arguments passed to the command are unaffected and commands without arguments accept the options as their only parameter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// // `commands.getUser(id, { headers: { "x-trace-id": traceId } })`
+    /// let mut builder = Builder::<tauri::Wry>::new().invoke_options(true);
+    /// ```
+    pub fn invoke_options(mut self, invoke_options: bool) -> Self {
+        self.invoke_options = invoke_options;
+        self
+    }
+
+    /// Generate a `{command}Stream` variant of every command whose last argument is a [`Channel`](tauri::ipc::Channel), returning an `AsyncIterable` of its messages.
+    ///
+    /// The variant creates the channel itself, so it takes the other arguments of the command.
+    /// The stream ends once the command returns and throws if the command fails, so the command should only return after it has sent its last message.
+    /// Channels can't apply backpressure, so messages are buffered until they're consumed.
+    /// Returning early, Eg. by breaking out of a `for await` loop, drops the channel and any buffered messages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// // `for await (const line of commands.streamLogsStream(filter)) { ... }`
+    /// let mut builder = Builder::<tauri::Wry>::new().channel_streams(true);
+    /// ```
+    pub fn channel_streams(mut self, channel_streams: bool) -> Self {
+        self.channel_streams = channel_streams;
+        self
+    }
+
+    /// Set the module format of the generated Javascript bindings.
+    ///
+    /// Defaults to [`ModuleFormat::Auto`].
+    /// Only [`ModuleFormat::Deno`] affects Typescript bindings, as otherwise the Typescript compiler controls their module format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, ModuleFormat};
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().module_format(ModuleFormat::CommonJs);
+    /// ```
+    pub fn module_format(mut self, module_format: ModuleFormat) -> Self {
+        self.module_format = module_format;
+        self
+    }
+
+    /// Import `@tauri-apps/api` from a different module specifier, Eg. a package alias in a monorepo or a mock module in tests.
+    ///
+    /// The submodules are imported relative to it, so `.tauri_api_path("~/lib/tauri-api")` imports `invoke` from `~/lib/tauri-api/core`.
+    /// This applies to every Typescript and Javascript exporter and takes precedence over the `npm:` specifier used for [`ModuleFormat::Deno`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().tauri_api_path("~/lib/tauri-api");
+    /// ```
+    pub fn tauri_api_path(mut self, path: impl Into<Cow<'static, str>>) -> Self {
+        self.tauri_api_path = Some(path.into());
+        self
+    }
+
+    /// Prepend a prefix to the name of every exported type, Eg. `AppUser` for `User`, so the types of multiple packages in a monorepo don't clash.
+    ///
+    /// This applies to the named types in the Typescript and Javascript bindings and every reference to them. Primitives and inline types are unaffected.
+    ///
+    /// Defaults to the [plugin name](Self::plugin_name) in PascalCase, Eg. `FsWatchEntry` for `Entry` in the `fs-watch` plugin, or no prefix outside of a plugin.
+    /// Set an empty prefix to keep the Rust names of a plugin's types.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().type_prefix("App");
+    /// ```
+    pub fn type_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.type_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Append a suffix to the name of every exported type, Eg. `UserDto` for `User`.
+    ///
+    /// Refer to [`Self::type_prefix`] for more information.
+    pub fn type_suffix(mut self, suffix: impl Into<Cow<'static, str>>) -> Self {
+        self.type_suffix = suffix.into();
+        self
+    }
+
+    /// Set how many named types deep the Typescript and Javascript types are validated before being exported.
+    ///
+    /// A recursive type like `struct TreeNode { parent: Option<Box<TreeNode>> }` nests itself forever,
+    /// so the types nested deeper than this are validated as `unknown` and a warning is logged when the `log` feature is enabled.
+    /// Types are always exported by reference, so this doesn't change the exported bindings.
+    ///
+    /// Defaults to `32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().max_recursion_depth(10);
+    /// ```
+    pub fn max_recursion_depth(mut self, depth: u32) -> Self {
+        self.max_recursion_depth = depth;
+        self
+    }
+
+    /// Set how [`TraitObject`](crate::TraitObject)s are exported.
+    ///
+    /// Defaults to [`TraitObjectPolicy::Error`], as a trait object can't be typed and will accept anything the frontend sends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, TraitObjectPolicy};
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().allow_trait_objects(TraitObjectPolicy::AsUnknown);
+    /// ```
+    pub fn allow_trait_objects(mut self, policy: TraitObjectPolicy) -> Self {
+        self.trait_object_policy = policy;
+        self
+    }
+
+    /// Skip writing exported files whose contents are unchanged.
+    ///
+    /// This is enabled by default so regenerating identical bindings doesn't touch the file and trigger your frontend's file watcher.
+    /// The contents are compared after the formatter has run, so it doesn't cause the file to be rewritten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// // Always rewrite the bindings, Eg. to bump their modification time.
+    /// let mut builder = Builder::<tauri::Wry>::new().skip_unchanged(false);
+    /// ```
+    pub fn skip_unchanged(mut self, skip_unchanged: bool) -> Self {
+        self.skip_unchanged = skip_unchanged;
+        self
+    }
+
+    /// Merge the commands, events, types and constants of another builder into this one.
+    ///
+    /// This is useful for splitting up a large application into multiple modules which each construct their own [`Builder`].
+    /// Any collisions are resolved using [`MergePolicy::Override`], refer to [`Self::merge_with_policy`] to configure this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, collect_commands};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn login() {}
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn open_file() {}
+    ///
+    /// let auth = Builder::<tauri::Wry>::new().commands(collect_commands![login]);
+    /// let files = Builder::<tauri::Wry>::new().commands(collect_commands![open_file]);
+    ///
+    /// let builder = auth.merge(files);
+    /// ```
+    pub fn merge(self, other: Self) -> Self {
+        self.merge_with_policy(other, MergePolicy::default())
+    }
+
+    /// Merge another builder into this one, resolving any collisions using the given [`MergePolicy`].
+    ///
+    /// The plugin name and error handling mode of `self` are preserved.
+    ///
+    /// If `other` is a plugin with a different [plugin name](Self::plugin_name), its events keep the plugin's prefix
+    /// and are exported within a namespace with the plugin's name, Eg. `fsWatch.events.fileChanged` for a `fs-watch` plugin.
+    #[track_caller]
+    pub fn merge_with_policy(mut self, mut other: Self, policy: MergePolicy) -> Self {
+        // The other builder's middlewares only wrap its own commands.
+        other.invoke_handler = other.handler();
+        other.middleware.clear();
+
+        if let Some(plugin) = other.plugin_name.filter(|p| Some(*p) != self.plugin_name) {
+            for name in self.events.keys() {
+                self.event_plugins.entry(name).or_insert(self.plugin_name);
+            }
+            for sid in &self.event_sids {
+                self.event_sid_plugins
+                    .entry(*sid)
+                    .or_insert(self.plugin_name);
+            }
+
+            for name in other.events.keys() {
+                if !other.event_plugins.contains_key(name) {
+                    other.event_plugins.insert(name, Some(plugin));
+                    other
+                        .event_namespaces
+                        .entry(name)
+                        .or_default()
+                        .insert(0, plugin.into());
+                }
+            }
+            for sid in &other.event_sids {
+                other.event_sid_plugins.entry(*sid).or_insert(Some(plugin));
+            }
+        }
+
+        // The events which lost a name collision, so only the event which kept the name is mounted.
+        // With `MergePolicy::Error` both are kept so the error can name each of them.
+        let mut dropped_events = BTreeSet::new();
+        let mut kept_names = BTreeSet::new();
+        for (name, dt) in std::mem::take(&mut other.events) {
+            if self.events.contains_key(name) {
+                let replace = policy.collision(&mut self.conflicts, "event", name);
+                if policy != MergePolicy::Error {
+                    let losing = match replace {
+                        true => &self.event_sid_names,
+                        false => &other.event_sid_names,
+                    };
+                    dropped_events.extend(
+                        losing
+                            .iter()
+                            .filter(|(_, n)| **n == name)
+                            .map(|(sid, _)| (*sid, replace)),
+                    );
+                }
+
+                if !replace {
+                    kept_names.insert(name);
+                    continue;
+                }
+                self.event_windows.remove(name);
+                self.event_plugins.remove(name);
+            }
+
+            self.events.insert(name, dt);
+        }
+        for (sid, from_self) in dropped_events {
+            match from_self {
+                true => self.forget_event(&sid),
+                false => other.forget_event(&sid),
+            }
+        }
+        other
+            .event_windows
+            .retain(|name, _| !kept_names.contains(name));
+        other
+            .event_plugins
+            .retain(|name, _| !kept_names.contains(name));
+
+        self.event_sids.extend(other.event_sids);
+        self.event_windows.extend(other.event_windows);
+        self.event_plugins.extend(other.event_plugins);
+        self.event_sid_plugins.extend(other.event_sid_plugins);
+        self.additional_event_sids
+            .extend(other.additional_event_sids);
+        self.event_sid_windows.extend(other.event_sid_windows);
+        self.event_sid_names.extend(other.event_sid_names);
+        let existing_commands = self
+            .command_types
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<BTreeSet<_>>();
+        let other_commands = other
+            .command_types
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<BTreeSet<_>>();
+
+        let mut replace = true;
+        for name in existing_commands.intersection(&other_commands) {
+            replace = policy.collision(&mut self.conflicts, "command", name);
+        }
+
+        self.invoke_handler = if replace {
+            self.command_types
+                .retain(|f| !other_commands.contains(f.name()));
+            dispatch(other_commands, other.invoke_handler, self.invoke_handler)
+        } else {
+            dispatch(existing_commands, self.invoke_handler, other.invoke_handler)
+        };
+        for function in other.command_types {
+            if !self
+                .command_types
+                .iter()
+                .any(|f| f.name() == function.name())
+            {
+                self.command_types.push(function);
+            }
+        }
+
+        self.duplicate_events.extend(other.duplicate_events);
+
+        self.merge_types(&other.types, policy);
+        self.explicit_types.extend(other.explicit_types);
+
+        for (key, value) in other.constants {
+            if self.constants.contains_key(&key)
+                && !policy.collision(&mut self.conflicts, "constant", &key)
+            {
+                continue;
+            }
+
+            self.constants.insert(key, value);
+        }
+        for (group, constants) in other.constant_groups {
+            let existing = self.constant_groups.entry(group.clone()).or_default();
+            for (key, value) in constants {
+                if existing.contains_key(&key)
+                    && !policy.collision(&mut self.conflicts, "constant", &format!("{group}.{key}"))
+                {
+                    continue;
+                }
+
+                existing.insert(key, value);
+            }
+        }
+        self.deprecated_commands.extend(other.deprecated_commands);
+        self.deprecated_events.extend(other.deprecated_events);
+        self.command_renames.extend(other.command_renames);
+        self.event_renames.extend(other.event_renames);
+        self.command_namespaces.extend(other.command_namespaces);
+        self.event_namespaces.extend(other.event_namespaces);
+        self.query_kinds.extend(other.query_kinds);
+        self.required_permissions.extend(other.required_permissions);
+        for (name, ty) in other.type_aliases {
+            if self.type_aliases.contains_key(&name)
+                && !policy.collision(&mut self.conflicts, "type alias", &name)
+            {
+                continue;
+            }
+
+            self.type_aliases.insert(name, ty);
+        }
+        self.type_overrides.extend(other.type_overrides);
+        self.type_imports.extend(other.type_imports);
+        self.enum_variants.extend(other.enum_variants);
+        self.internal_types.extend(other.internal_types);
+        self.conflicts.extend(other.conflicts);
+
+        self
+    }
+
+    /// Forget an event which lost a name collision when merging, so it isn't mounted.
+    fn forget_event(&mut self, sid: &SpectaID) {
+        self.event_sids.remove(sid);
+        self.event_sid_plugins.remove(sid);
+        self.additional_event_sids.remove(sid);
+        self.event_sid_windows.remove(sid);
+        self.event_sid_names.remove(sid);
+    }
+
+    /// Merge a collection of types into the builder.
+    ///
+    /// This is useful for libraries which ship a pre-built [`TypeMap`] of their types so you don't need to call [`Self::typ`] for each of them.
+    /// Types which have already been registered with a different definition are resolved using the given [`MergePolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::{Builder, MergePolicy};
+    /// use specta::{Type, TypeMap};
+    ///
+    /// #[derive(Type)]
+    /// pub struct MyStruct {
+    ///     a: String
+    /// }
+    ///
+    /// let mut types = TypeMap::default();
+    /// MyStruct::reference(&mut types, &[]);
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().merge_type_collection(types, MergePolicy::Keep);
+    /// ```
+    #[track_caller]
+    pub fn merge_type_collection(mut self, types: TypeMap, policy: MergePolicy) -> Self {
+        self.merge_types(&types, policy);
+        self.explicit_types.extend(types.iter().map(|(sid, _)| sid));
+        self
+    }
+
+    /// Register a collection of types with the builder.
+    ///
+    /// Types which have already been registered are preserved.
+    /// If a type is registered again with a different definition, exporting will return an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tauri_specta::Builder;
+    /// use specta::{Type, TypeMap};
+    ///
+    /// #[derive(Type)]
+    /// pub struct MyStruct {
+    ///     a: String
+    /// }
+    ///
+    /// let mut types = TypeMap::default();
+    /// MyStruct::reference(&mut types, &[]);
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().types(types);
+    /// ```
+    pub fn types(mut self, types: impl Into<TypeMap>) -> Self {
+        let types = types.into();
+        self.merge_types(&types, MergePolicy::Error);
+        self.explicit_types.extend(types.iter().map(|(sid, _)| sid));
+        self
+    }
+
+    #[track_caller]
+    fn merge_types(&mut self, types: &TypeMap, policy: MergePolicy) {
+        for (sid, ndt) in types.iter() {
+            match self.types.get(sid) {
+                Some(existing) if existing == ndt => {}
+                Some(_) => {
+                    if policy.collision(&mut self.conflicts, "type", ndt.name()) {
+                        self.types.insert(sid, ndt.clone());
+                    }
+                }
+                None => self.types.insert(sid, ndt.clone()),
+            }
+        }
+    }
+
+    /// The Tauri invoke handler to trigger commands registered with the builder.
+    pub fn invoke_handler(&self) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+        let commands = self.handler();
+        move |invoke| commands(invoke)
+    }
+
+    /// The invoke handler wrapped with the middlewares, so the first one registered is the outermost.
+    fn handler(&self) -> InvokeHandler<R> {
+        self.middleware
+            .iter()
+            .rev()
+            .fold(self.invoke_handler.clone(), |next, middleware| {
+                let middleware = middleware.clone();
+                Arc::new(move |invoke| middleware(invoke, &*next))
+            })
+    }
+
+    /// Combine the invoke handlers of multiple builders into a single handler.
+    ///
+    /// Tauri only accepts a single [`tauri::Builder::invoke_handler`], so this allows independent modules to each construct their own [`Builder`] without merging them with [`Self::merge`].
+    /// Each invoke is dispatched to the first builder which registered the command.
+    ///
+    /// If multiple builders register a command with the same name the first one wins, which is reported by a debug assertion.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_specta::{Builder, collect_commands};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn login() {}
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn open_file() {}
+    ///
+    /// let auth = Builder::<tauri::Wry>::new().commands(collect_commands![login]);
+    /// let files = Builder::<tauri::Wry>::new().commands(collect_commands![open_file]);
+    ///
+    /// tauri::Builder::default()
+    ///     .invoke_handler(Builder::merge_invoke_handlers([&auth, &files]))
+    ///     // on an actual app, remove the string argument
+    ///     .run(tauri::generate_context!("tests/tauri.conf.json"))
+    ///     .expect("error while running tauri application");
+    /// ```
+    pub fn merge_invoke_handlers<'a>(
+        builders: impl IntoIterator<Item = &'a Self>,
+    ) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+        let mut claimed = BTreeSet::new();
+        let handlers = builders
+            .into_iter()
+            .map(|builder| {
+                let commands = builder
+                    .command_types
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .filter(|name| {
+                        let unclaimed = claimed.insert(name.clone());
+                        debug_assert!(
+                            unclaimed,
+                            "tauri-specta: command '{name}' is registered by multiple builders"
+                        );
+                        unclaimed
+                    })
+                    .collect::<BTreeSet<_>>();
+
+                (commands, builder.handler())
+            })
+            .collect::<Vec<_>>();
+
+        move |invoke| match handlers
+            .iter()
+            .find(|(commands, _)| commands.contains(invoke.message.command()))
+        {
+            Some((_, handler)) => handler(invoke),
+            None => false,
+        }
+    }
+
+    /// Mount all of the events in the builder onto a Tauri app.
+    ///
+    /// This should be called within [`tauri::Builder::setup`](tauri::Builder::setup) like the example below.
+    ///
+    /// Any [`Manager`] can be used, so this also works with an [`AppHandle`](tauri::AppHandle) from a plugin's setup hook.
+    /// Mounting the same events multiple times is a no-op.
+    /// Events registered with [`Self::additional_events`] are skipped, as they're mounted with [`Self::mount_additional_events`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_specta::{Builder, collect_events};
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().events(collect_events![]);
+    ///
+    /// tauri::Builder::default()
+    ///     .setup(move |app| {
+    ///         builder.mount_events(app);
+    ///             
+    ///         Ok(())
+    ///     })
+    ///     // on an actual app, remove the string argument
+    ///     .run(tauri::generate_context!("tests/tauri.conf.json"))
+    ///     .expect("error while running tauri application");
+    /// ```
+    pub fn mount_events(&self, handle: &impl Manager<R>) {
+        let registry = EventRegistry::get_or_manage(handle);
+        let mut map = registry.0.write().expect("Failed to lock EventRegistry");
+
+        for sid in self.event_sids.difference(&self.additional_event_sids) {
+            map.insert(
+                *sid,
+                self.event_registry_meta(sid, self.event_sid_names[sid], None),
+            );
+        }
+    }
+
+    /// Mount additional events onto a Tauri app after [`Self::mount_events`], Eg. for a module which is only enabled at runtime.
+    ///
+    /// Register the events with [`Self::additional_events`] to include them in the exported bindings without mounting them at startup.
+    /// Events are scoped to their plugin like with [`Self::mount_events`] and mounting an event which is already mounted is a no-op.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use serde::{Serialize, Deserialize};
+    /// use specta::Type;
+    /// use tauri_specta::{Builder, collect_events, Event};
+    ///
+    /// #[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+    /// pub struct ScriptOutput(String);
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new().additional_events(collect_events![ScriptOutput]);
+    ///
+    /// tauri::Builder::default()
+    ///     .setup(move |app| {
+    ///         builder.mount_events(app);
+    ///
+    ///         // Later, once scripting is enabled.
+    ///         builder.mount_additional_events(app, collect_events![ScriptOutput]);
+    ///
+    ///         Ok(())
+    ///     })
+    ///     // on an actual app, remove the string argument
+    ///     .run(tauri::generate_context!("tests/tauri.conf.json"))
+    ///     .expect("error while running tauri application");
+    /// ```
+    pub fn mount_additional_events(&self, handle: &impl Manager<R>, events: Events) {
+        let registry = EventRegistry::get_or_manage(handle);
+        let mut map = registry.0.write().expect("Failed to lock EventRegistry");
+
+        for (name, (sid, window, _)) in events.0 {
+            map.entry(sid)
+                .or_insert_with(|| self.event_registry_meta(&sid, name, window));
+        }
+    }
+
+    /// The metadata an event is mounted with, preferring what was registered with the builder over `name` and `window`.
+    fn event_registry_meta(
+        &self,
+        sid: &SpectaID,
+        name: &'static str,
+        window: Option<&'static str>,
+    ) -> EventRegistryMeta {
+        EventRegistryMeta {
+            name: self.event_sid_names.get(sid).copied().unwrap_or(name),
+            plugin_name: self
+                .event_sid_plugins
+                .get(sid)
+                .copied()
+                .unwrap_or(self.plugin_name),
+            window: self.event_sid_windows.get(sid).copied().or(window),
+        }
+    }
+
+    /// Export the bindings to a string.
+    ///
+    /// You should prefer to use [`Self::export`], unless you need explicit control over saving.
+    ///
+    /// The returned string is exactly what [`Self::export`] would write to disk, before the language's formatter is run.
+    /// No files are touched.
+    ///
+    /// # Example
+    /// ```
+    /// use std::{
+    ///     fs::File,
+    ///     io::Write
+    /// };
+    /// use specta_typescript::Typescript;
+    ///
+    /// println!(
+    ///     "{}",
+    ///     tauri_specta::Builder::<tauri::Wry>::new()
+    ///         .export_str(Typescript::new())
+    ///         .unwrap()
+    /// );
+    /// ```
+    pub fn export_str<L>(&self, language: L) -> Result<String, Error>
+    where
+        L: LanguageExt,
+        L::Error: Send + Sync + 'static,
+    {
+        // TODO: Serde checking
+
+        language
+            .render(&self.export_context()?)
+            .map_err(|err| Error::Export(Box::new(err)))
+    }
+
+    /// Export the bindings to a file.
+    ///
+    /// # Example
+    /// ```
+    /// use tauri_specta::{Builder, collect_commands, collect_events};
+    /// use specta_typescript::Typescript;
+    ///
+    /// let mut builder = Builder::<tauri::Wry>::new()
+    ///     .commands(collect_commands![])
+    ///     .events(collect_events![]);
+    ///
+    /// #[cfg(debug_assertions)] // only export on debug builds.
+    /// builder
+    ///     .export(Typescript::default(), "../src/bindings.ts")
+    ///     .expect("Failed to export typescript bindings");
+    /// ```
+    pub fn export<L>(&self, language: L, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        L: LanguageExt,
+        L::Error: Send + Sync + 'static,
+    {
+        let path = match language.extension() {
+            Some(ext)
+                if !path
+                    .as_ref()
+                    .to_string_lossy()
+                    .ends_with(&format!(".{ext}")) =>
+            {
+                path.as_ref().with_extension(ext)
+            }
+            _ => path.as_ref().to_path_buf(),
+        };
+        let path = path.as_path();
+
+        let mut cfg = self.export_context()?;
+        if cfg.module_format == ModuleFormat::Auto {
+            cfg.module_format = detect_module_format(path);
+        }
+        let bindings = language
+            .render(&cfg)
+            .map_err(|err| Error::Export(Box::new(err)))?;
+
+        if let Some(export_dir) = path.parent() {
+            fs::create_dir_all(export_dir).map_err(Error::io(export_dir))?;
+        }
+
+        write_formatted(&language, path, &bindings, self.skip_unchanged)
+    }
+
+    /// Export every type as a [JSON Schema](https://json-schema.org) (draft-07) document.
+    ///
+    /// A `<TypeName>.json` file is written into `dir` for each type, containing the type and every type it references under `definitions`.
+    /// If any constants are registered they are written to `constants.json` using the `const` keyword.
+    ///
+    /// To export a single document which also describes your commands and events use [`crate::JsonSchema`] with [`Self::export`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_json_schema("../schemas")
+    ///     .expect("Failed to export json schemas");
+    /// ```
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_json_schema(&self, dir: impl AsRef<Path>) -> Result<(), Error> {
+        crate::lang::json_schema::export(&self.export_context()?, dir.as_ref())
+    }
+
+    /// Export the Tauri v2 [permission](https://v2.tauri.app/security/permissions) files for the commands into `dir`, which is typically `permissions/` in your plugin.
+    ///
+    /// This writes the same files as the `tauri-plugin` build tooling:
+    ///  - `autogenerated/commands/<command>.toml` - an `allow-<command>` and `deny-<command>` permission for each command.
+    ///  - `default.toml` - a `default` permission set which allows every command.
+    ///
+    /// The permissions generated in `default.toml` are kept between `# tauri-specta:start` and `# tauri-specta:end` comments,
+    /// so anything else you add to the file is preserved. If those comments are removed the file is left untouched.
+    /// Files are only rewritten when their contents change, so this is safe to call from a `build.rs`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::{collect_commands, Builder};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn ping() {}
+    ///
+    /// let builder = Builder::<tauri::Wry>::new()
+    ///     .plugin_name("my-plugin")
+    ///     .commands(collect_commands![ping]);
+    ///
+    /// builder
+    ///     .export_permissions("./permissions")
+    ///     .expect("Failed to export permissions");
+    /// ```
+    pub fn export_permissions(&self, dir: impl AsRef<Path>) -> Result<(), Error> {
+        crate::permissions::export(&self.export_context()?, dir.as_ref())
+    }
+
+    /// Export a Typescript file mapping each command to the permission registered with [`Self::required_permission`].
+    ///
+    /// The file contains `export const REQUIRED_PERMISSIONS = { myCommand: "my-plugin:allow-my-command" } as const;`,
+    /// keyed by the name of the generated function, so the frontend can check its capabilities grant every permission it needs.
+    /// Commands without a required permission are omitted.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new()
+    ///     .required_permission("my_command", "my-plugin:allow-my-command");
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_permissions_manifest("../src/permissions.ts")
+    ///     .expect("Failed to export permissions manifest");
+    /// ```
+    pub fn export_permissions_manifest(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        crate::permissions::export_manifest(&self.export_context()?, path.as_ref())
+    }
+
+    /// Export a JSON manifest of the commands and events, for tooling which needs to know the IPC surface of your app.
+    ///
+    /// Each command lists the name it's invoked with, the name of its generated function, its arguments, result and error types, docs and whether it's deprecated.
+    /// Each event lists the name it's emitted with, the name of its generated property and its payload type.
+    /// Types are described with [JSON Schema](https://json-schema.org) and every named type they reference is included under `$defs`.
+    ///
+    /// The manifest is sorted so it's stable across runs and can be diffed to catch changes to your commands and events.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_manifest("../ipc-manifest.json")
+    ///     .expect("Failed to export manifest");
+    /// ```
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_manifest(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        crate::lang::json_schema::export_manifest(&self.export_context()?, path.as_ref())
+    }
+
+    /// Export an [OpenAPI 3.0](https://spec.openapis.org/oas/v3.0.3) YAML document, so tools like Swagger UI can document your commands.
+    ///
+    /// Each command is described as a `POST /commands/{name}` endpoint, with its arguments as the request body and its result as the `200` response.
+    /// Commands returning a [`Result`] describe their error as the `default` response.
+    /// The types are included under `components/schemas` and the version of the document is the hash used by [`Self::schema_version_constant`].
+    ///
+    /// The events are exported to an [AsyncAPI 2.6](https://www.asyncapi.com/docs/reference/specification/v2.6.0) document alongside it,
+    /// with `.asyncapi` inserted before the extension, Eg. `openapi.asyncapi.yaml`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_openapi("../docs/openapi.yaml")
+    ///     .expect("Failed to export OpenAPI document");
+    /// ```
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_openapi(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let cfg = self.export_context()?;
+        let version = schema_version(&cfg.commands, &cfg.events, &cfg.type_map);
+
+        crate::lang::json_schema::export_openapi(&cfg, &version, path.as_ref())
+    }
+
+    /// Export the Typescript bindings into a directory, split across multiple files.
+    ///
+    /// The following files are written into `dir`:
+    ///  - `types.ts` - every type used by your commands, events and constants.
+    ///  - `commands.ts` - the `commands` object and any namespaces.
+    ///  - `events.ts` - the `events` object, if any events are registered.
+    ///  - `constants.ts` - the constants, if any are registered.
+    ///  - `globals.ts` - the runtime helpers shared by the other files.
+    ///  - `index.ts` - re-exports everything above by name, excluding types marked with [`Self::mark_internal`].
+    ///
+    /// Use [`Self::export_ts_dir_with_config`] to configure the output, Eg. to skip generating `index.ts`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::Builder;
+    /// use specta_typescript::Typescript;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_ts_dir(Typescript::default(), "../src/bindings")
+    ///     .expect("Failed to export typescript bindings");
+    /// ```
+    #[cfg(feature = "typescript")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+    pub fn export_ts_dir(
+        &self,
+        language: specta_typescript::Typescript,
+        dir: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.export_ts_dir_with_config(language, dir, DirConfig::default())
+    }
+
+    /// Export the Typescript bindings into a directory using the given [`DirConfig`].
+    ///
+    /// Refer to [`Self::export_ts_dir`] for more information.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::{Builder, DirConfig};
+    /// use specta_typescript::Typescript;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_ts_dir_with_config(
+    ///         Typescript::default(),
+    ///         "../src/bindings",
+    ///         DirConfig::new().index_file(false),
+    ///     )
+    ///     .expect("Failed to export typescript bindings");
+    /// ```
+    #[cfg(feature = "typescript")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+    pub fn export_ts_dir_with_config(
+        &self,
+        language: specta_typescript::Typescript,
+        dir: impl AsRef<Path>,
+        config: DirConfig,
+    ) -> Result<(), Error> {
+        let dir = dir.as_ref();
+        let mut cfg = self.export_context()?;
+        if cfg.module_format == ModuleFormat::Auto {
+            cfg.module_format = detect_module_format(&dir.join("index.ts"));
+        }
+        let files = crate::lang::ts::render_dir(&language, &cfg, &config)
+            .map_err(|err| Error::Export(Box::new(err)))?;
+
+        fs::create_dir_all(dir).map_err(Error::io(dir))?;
+        for (name, contents) in files {
+            write_formatted(&language, &dir.join(name), &contents, self.skip_unchanged)?;
+        }
+
+        Ok(())
+    }
+
+    /// Export a [TanStack Query](https://tanstack.com/query) hook for every command.
+    ///
+    /// Queries are wrapped in `useQuery` keyed by the command name and its arguments and mutations are wrapped in `useMutation`.
+    /// Refer to [`Self::command_query_kind`] for how commands are classified.
+    ///
+    /// The hooks invoke the commands directly so commands returning a `Result` reject with their error, regardless of [`ErrorHandlingMode`].
+    /// The types are exported with `language`'s configuration, Eg. how BigInts are exported, and the file is formatted with its formatter.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use specta_typescript::Typescript;
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_react_query(Typescript::default(), "../src/hooks.ts")
+    ///     .expect("Failed to export react query hooks");
+    /// ```
+    #[cfg(feature = "react-query")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "react-query")))]
+    pub fn export_react_query(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.export_framework(language, path.as_ref(), crate::lang::react_query::render)
+    }
+
+    /// Export the file rendered by a frontend framework exporter, formatted with `language`'s formatter.
+    #[cfg(any(feature = "react-query", feature = "vue", feature = "svelte"))]
+    fn export_framework(
+        &self,
+        language: specta_typescript::Typescript,
+        path: &Path,
+        render: fn(
+            &specta_typescript::Typescript,
+            &crate::ExportContext,
+        ) -> Result<String, specta_typescript::ExportError>,
+    ) -> Result<(), Error> {
+        let contents = render(&language, &self.export_context()?)
+            .map_err(|err| Error::Export(Box::new(err)))?;
+
+        if let Some(export_dir) = path.parent() {
+            fs::create_dir_all(export_dir).map_err(Error::io(export_dir))?;
+        }
+
+        write_formatted(&language, path, &contents, self.skip_unchanged)
+    }
+
+    /// Export a [Vue 3](https://vuejs.org) composable for every command and event.
+    ///
+    /// Command composables invoke the command when the component is mounted and whenever a reactive argument changes.
+    /// They return the reactive `data`, `loading` and `error` state along with a `refetch` function.
+    /// Event composables listen to the event while the component is mounted.
+    /// The types are exported with `language`'s configuration, Eg. how BigInts are exported, and the file is formatted with its formatter.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use specta_typescript::Typescript;
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_vue_composables(Typescript::default(), "../src/composables.ts")
+    ///     .expect("Failed to export vue composables");
+    /// ```
+    #[cfg(feature = "vue")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vue")))]
+    pub fn export_vue_composables(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.export_framework(language, path.as_ref(), crate::lang::vue::render)
+    }
+
+    /// Export a [Svelte](https://svelte.dev) store for every command and event.
+    ///
+    /// Each command becomes a function returning a store of its `{ data, loading, error }` state with a `refetch` method.
+    /// Each event becomes a readable store of its latest payload, which is `null` until the event is first emitted.
+    /// The types are exported with `language`'s configuration, Eg. how BigInts are exported, and the file is formatted with its formatter.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use specta_typescript::Typescript;
+    /// use tauri_specta::Builder;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_svelte(Typescript::default(), "../src/stores.ts")
+    ///     .expect("Failed to export svelte stores");
+    /// ```
+    #[cfg(feature = "svelte")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "svelte")))]
+    pub fn export_svelte(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.export_framework(language, path.as_ref(), crate::lang::svelte::render)
+    }
+
+    /// Find the cycles of named types which reference each other, Eg. `struct TreeNode { children: Vec<TreeNode> }`.
+    ///
+    /// Recursive types are exported by reference so cycles are fine, but this can be used to catch accidental ones before exporting.
+    /// Every type which is part of a cycle is reported in one of them, so a type which is part of several cycles may not be reported in all of them.
+    ///
+    /// # Example
+    /// ```
+    /// use tauri_specta::Builder;
+    ///
+    /// #[derive(specta::Type)]
+    /// pub struct TreeNode {
+    ///     children: Vec<TreeNode>,
+    /// }
+    ///
+    /// let cycles = Builder::<tauri::Wry>::new()
+    ///     .typ::<TreeNode>()
+    ///     .check_type_cycles()
+    ///     .unwrap_err();
+    /// assert_eq!(cycles[0].to_string(), "TreeNode → TreeNode");
+    /// ```
+    pub fn check_type_cycles(&self) -> Result<(), Vec<TypeCycle>> {
+        let cycles = type_cycles(&self.types);
+        match cycles.is_empty() {
+            true => Ok(()),
+            false => Err(cycles),
+        }
+    }
+
+    /// The name of every registered command, in registration order.
+    ///
+    /// These are the names the frontend invokes, including the `plugin:{name}|` prefix if a [plugin name](Self::plugin_name) is set.
+    ///
+    /// # Example
+    /// ```
+    /// use tauri_specta::{Builder, collect_commands};
+    ///
+    /// #[tauri::command]
+    /// #[specta::specta]
+    /// fn hello_world() {}
+    ///
+    /// let builder = Builder::<tauri::Wry>::new()
+    ///     .plugin_name("greeter")
+    ///     .commands(collect_commands![hello_world]);
+    /// assert_eq!(builder.command_names(), ["plugin:greeter|hello_world"]);
+    /// ```
+    pub fn command_names(&self) -> Vec<String> {
+        self.command_types
+            .iter()
+            .map(|function| {
+                self.plugin_name
+                    .map(|n| apply_as_prefix(n, function.name(), ItemType::Command))
+                    .unwrap_or_else(|| function.name().to_string())
+            })
+            .collect()
+    }
+
+    /// The name of every registered event, sorted alphabetically.
+    ///
+    /// These are the names the events are emitted with, including the `plugin:{name}:` prefix of the plugin each event belongs to.
+    pub fn event_names(&self) -> Vec<String> {
+        self.events
+            .keys()
+            .map(|name| {
+                self.event_plugins
+                    .get(name)
+                    .copied()
+                    .unwrap_or(self.plugin_name)
+                    .map(|n| apply_as_prefix(n, name, ItemType::Event))
+                    .unwrap_or_else(|| name.to_string())
+            })
+            .collect()
+    }
+
+    /// The key of every registered constant, sorted alphabetically.
+    ///
+    /// Constants registered with [`Self::constant_group`] are keyed as `{group}.{key}`.
+    pub fn constant_keys(&self) -> Vec<Cow<'static, str>> {
+        self.constants
+            .keys()
+            .cloned()
+            .chain(self.constant_groups.iter().flat_map(|(group, constants)| {
+                constants
+                    .keys()
+                    .map(move |key| format!("{group}.{key}").into())
+            }))
+            .collect()
+    }
+
+    /// Check the builder for configuration problems which would break exporting or produce broken bindings.
+    ///
+    /// This reports every problem at once instead of failing on the first one like the export methods,
+    /// so it can be used in a unit test to catch mistakes before they cause CI failures.
+    ///
+    /// It checks for:
+    ///  - commands or events registered with the same name,
+    ///  - constants with the same name as a command,
+    ///  - types from different Rust paths which are exported with the same name,
+    ///  - a [plugin name](Self::plugin_name) which can't be used in a TypeScript identifier,
+    ///  - any other items which collided while merging builders with [`MergePolicy::Error`].
+    ///
+    /// # Example
+    /// ```
+    /// use tauri_specta::{Builder, BuilderValidationError};
+    ///
+    /// let errors = Builder::<tauri::Wry>::new()
+    ///     .plugin_name("my plugin")
+    ///     .validate()
+    ///     .unwrap_err();
+    /// assert!(matches!(errors[0], BuilderValidationError::InvalidPluginName { .. }));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<BuilderValidationError>> {
+        let mut errors = Vec::new();
+
+        let mut reported_events = BTreeSet::new();
+        for (name, sids) in &self.duplicate_events {
+            if reported_events.insert(*name) {
+                errors.push(BuilderValidationError::DuplicateEventName {
+                    name: (*name).into(),
+                    types: sids
+                        .iter()
+                        .map(|sid| describe_type(&self.types, *sid))
+                        .collect(),
+                });
+            }
+        }
+
+        for (kind, name) in &self.conflicts {
+            errors.push(match *kind {
+                "command" => BuilderValidationError::DuplicateCommandName {
+                    name: name.clone().into(),
+                },
+                "nested constant group" => {
+                    let (group, nested) = name.split_once('.').unwrap_or_default();
+                    BuilderValidationError::NestedConstantGroup {
+                        group: group.to_string().into(),
+                        name: nested.to_string().into(),
+                    }
+                }
+                "event" => {
+                    if !reported_events.insert(name.as_str()) {
+                        continue;
+                    }
+
+                    BuilderValidationError::DuplicateEventName {
+                        name: name.clone().into(),
+                        types: self
+                            .event_sid_names
+                            .iter()
+                            .filter(|(_, n)| **n == name)
+                            .map(|(sid, _)| describe_type(&self.types, *sid))
+                            .collect(),
+                    }
+                }
+                kind => BuilderValidationError::Conflict {
+                    kind,
+                    name: name.clone().into(),
+                },
+            });
+        }
+
+        // Namespaced commands aren't exported at the top level, so they can't be shadowed.
+        for name in self.constants.keys() {
+            if let Some(command) = self.command_types.iter().find(|f| {
+                !self.command_namespaces.contains_key(f.name())
+                    && crate::ident(
+                        &self.command_renames,
+                        self.command_case,
+                        &self.acronym_style,
+                        f.name(),
+                    ) == *name
+            }) {
+                errors.push(BuilderValidationError::ConstantShadowsCommand {
+                    name: name.clone(),
+                    command: command.name().clone(),
+                });
+            }
+        }
+
+        for name in self.constant_groups_shadowing_constants() {
+            errors
+                .push(BuilderValidationError::ConstantGroupShadowsConstant { name: name.clone() });
+        }
+
+        let mut types = self.types.clone();
+        types.remove(crate::channel_sid());
+        for (name, sids) in duplicate_type_names(&types) {
+            errors.push(BuilderValidationError::DuplicateTypeName {
+                name: name.clone(),
+                types: sids.iter().map(|sid| describe_type(&types, *sid)).collect(),
+            });
+        }
+
+        if let Some(name) = self.plugin_name {
+            if !is_valid_plugin_name(name) {
+                errors.push(BuilderValidationError::InvalidPluginName { name });
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Report which registered types are used by the commands, events and constants.
+    ///
+    /// Each type is either reachable from a command's arguments or result, an event's payload or a constant,
+    /// registered with [`Self::typ`] or [`Self::types`] but unreachable, or only registered transitively because an unreachable type references it.
+    /// This is useful after a refactor to find `.typ::<T>()` calls which are no longer needed.
+    ///
+    /// # Example
+    /// ```
+    /// use tauri_specta::{Builder, TypeCoverage};
+    ///
+    /// #[derive(specta::Type)]
+    /// pub struct Unused {
+    ///     a: String,
+    /// }
+    ///
+    /// let report = Builder::<tauri::Wry>::new()
+    ///     .typ::<Unused>()
+    ///     .type_coverage_report();
+    /// assert_eq!(report.orphaned().collect::<Vec<_>>(), ["Unused"]);
+    /// println!("{report}");
+    /// ```
+    pub fn type_coverage_report(&self) -> TypeCoverageReport {
+        let mut roots = Vec::new();
+        for function in &self.command_types {
+            for (_, ty) in function.args() {
+                roots.extend(type_graph::references(ty));
+            }
+            match function.result() {
+                Some(FunctionResultVariant::Value(t)) => roots.extend(type_graph::references(t)),
+                Some(FunctionResultVariant::Result(t, e)) => {
+                    roots.extend(type_graph::references(t));
+                    roots.extend(type_graph::references(e));
+                }
+                None => {}
+            }
+        }
+        for ty in self.events.values().chain(
+            self.constants
+                .values()
+                .chain(
+                    self.constant_groups
+                        .values()
+                        .flat_map(|group| group.values()),
+                )
+                .map(|constant| &constant.ty),
+        ) {
+            roots.extend(type_graph::references(ty));
+        }
+
+        let reachable = reachable_types(&self.types, roots.into_iter().map(|(sid, _)| sid));
+        let explicit = self
+            .explicit_types
+            .iter()
+            .copied()
+            .filter(|sid| !reachable.contains(sid));
+        let transitive = reachable_types(&self.types, explicit);
+
+        let mut types = self
+            .types
+            .iter()
+            // `Channel` is provided by `@tauri-apps/api` instead of being exported.
+            .filter(|(sid, _)| *sid != crate::channel_sid())
+            .map(|(sid, ndt)| {
+                let coverage = if reachable.contains(&sid) {
+                    TypeCoverage::Reachable
+                } else if self.explicit_types.contains(&sid) {
+                    TypeCoverage::Unreachable
+                } else {
+                    debug_assert!(transitive.contains(&sid));
+                    TypeCoverage::Transitive
+                };
+
+                (ndt.name().to_string(), coverage)
+            })
+            .collect::<Vec<_>>();
+        types.sort();
+
+        TypeCoverageReport { types }
+    }
+
+    /// Export the graph of the exported types, with an edge from each type to every type it contains.
+    ///
+    /// This is useful to find out why a type is being exported or to document the architecture of your app.
+    /// The graph contains exactly the types which would be exported with [`Self::export`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use tauri_specta::{Builder, GraphFormat};
+    ///
+    /// let builder = Builder::<tauri::Wry>::new();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export_type_graph(GraphFormat::Dot, "./types.dot")
+    ///     .expect("Failed to export type graph");
+    /// ```
+    pub fn export_type_graph(
+        &self,
+        format: GraphFormat,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        let types = self.export_context()?.type_map;
+
+        let graph = types
+            .iter()
+            .map(|(_, ndt)| {
+                let contains = type_graph::references(&ndt.inner)
+                    .into_iter()
+                    .filter_map(|(sid, _)| types.get(sid))
+                    .map(|ndt| ndt.name().to_string())
+                    .collect::<BTreeSet<_>>();
+
+                (ndt.name().to_string(), contains)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let contents = match format {
+            GraphFormat::Dot => {
+                let mut dot = "digraph types {\n".to_string();
+                for name in graph.keys() {
+                    dot += &format!("\t{name:?};\n");
+                }
+                for (name, contains) in &graph {
+                    for other in contains {
+                        dot += &format!("\t{name:?} -> {other:?};\n");
+                    }
+                }
+                dot + "}\n"
+            }
+            GraphFormat::Json => {
+                serde_json::to_string_pretty(&graph).map_err(|err| Error::Export(Box::new(err)))?
+                    + "\n"
+            }
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(Error::io(dir))?;
+        }
+        fs::write(path, contents).map_err(Error::io(path))
+    }
+
+    /// The constant groups which are exported with the same name as a top-level constant.
+    fn constant_groups_shadowing_constants(&self) -> Vec<&Cow<'static, str>> {
+        self.constant_groups
+            .keys()
+            .filter(|group| {
+                self.constants
+                    .contains_key(group.to_lower_camel_case().as_str())
+            })
+            .collect()
+    }
+
+    fn export_context(&self) -> Result<crate::ExportContext, Error> {
+        if let Some((name, sids)) = self.duplicate_events.first() {
+            return Err(Error::DuplicateEventName {
+                name: (*name).into(),
+                types: sids
+                    .iter()
+                    .map(|sid| describe_type(&self.types, *sid))
+                    .collect(),
+            });
+        }
+
+        let shadowing_groups = self.constant_groups_shadowing_constants();
+        if !self.conflicts.is_empty() || !shadowing_groups.is_empty() {
+            return Err(Error::Conflict(
+                self.conflicts
+                    .iter()
+                    .map(|(kind, name)| format!("{kind} '{name}'"))
+                    .chain(
+                        shadowing_groups
+                            .into_iter()
+                            .map(|name| format!("constant and constant group '{name}'")),
+                    )
+                    .collect(),
+            ));
+        }
+
+        let mut types = self.types.clone();
+        // `Channel` is imported from `@tauri-apps/api` so it must not be exported.
+        types.remove(crate::channel_sid());
+
+        if let Some((name, sids)) = duplicate_type_names(&types).into_iter().next() {
+            return Err(Error::DuplicateTypeName {
+                name: name.clone(),
+                types: sids.iter().map(|sid| describe_type(&types, *sid)).collect(),
+            });
+        }
+
+        for sid in self.type_imports.keys() {
+            let Some(ty) = self.type_overrides.get(sid) else {
+                continue;
+            };
+
+            if let Some((other, _)) = types.iter().find(|(_, ndt)| ndt.name() == ty) {
+                return Err(Error::TypeOverrideCollision {
+                    name: ty.clone(),
+                    overridden: sid.type_name(),
+                    conflict: other.type_name(),
+                });
+            }
+        }
+
+        #[cfg_attr(not(feature = "log"), allow(unused_variables))]
+        if let Some(ndt) = types.get(crate::trait_object::trait_object_sid()) {
+            match self.trait_object_policy {
+                TraitObjectPolicy::AsUnknown => {}
+                TraitObjectPolicy::Warn => {
+                    #[cfg(feature = "log")]
+                    log::warn!(
+                        "Tauri Specta exported a trait object as `{}` which is `unknown`. Its type is only known at runtime",
+                        ndt.name()
+                    );
+                }
+                TraitObjectPolicy::Error => return Err(Error::TraitObject),
+            }
+        }
+
+        let mut constants = self.constants.clone();
+        if let Some(name) = &self.schema_version_constant {
+            constants.insert(
+                name.clone(),
+                Constant {
+                    value: schema_version(&self.command_types, &self.events, &types).into(),
+                    ty: DataType::Primitive(PrimitiveType::String),
+                    as_const: true,
+                },
+            );
+        }
+
+        Ok(crate::ExportContext {
+            // TODO: Don't clone stuff
+            commands: self.command_types.clone(),
+            error_handling: self.error_handling,
+            invoke_options: self.invoke_options,
+            channel_streams: self.channel_streams,
+            events: self.events.clone(),
+            event_windows: self.event_windows.clone(),
+            event_plugins: self.event_plugins.clone(),
+            type_map: types,
+            constants,
+            constant_groups: self.constant_groups.clone(),
+            deprecated_commands: self.deprecated_commands.clone(),
+            deprecated_events: self.deprecated_events.clone(),
+            command_case: self.command_case,
+            event_case: self.event_case,
+            acronym_style: self.acronym_style.clone(),
+            command_renames: self.command_renames.clone(),
+            event_renames: self.event_renames.clone(),
+            command_namespaces: self.command_namespaces.clone(),
+            event_namespaces: self.event_namespaces.clone(),
+            query_kinds: self.query_kinds.clone(),
+            required_permissions: self.required_permissions.clone(),
+            module_format: self.module_format,
+            tauri_api_path: self.tauri_api_path.clone(),
+            type_prefix: self.type_prefix.clone().unwrap_or_else(|| {
+                self.plugin_name
+                    .map(|name| name.to_upper_camel_case().into())
+                    .unwrap_or_default()
+            }),
+            type_suffix: self.type_suffix.clone(),
+            max_recursion_depth: self.max_recursion_depth,
+            type_aliases: self.type_aliases.clone(),
+            type_overrides: self.type_overrides.clone(),
+            type_imports: self.type_imports.clone(),
+            enum_variants: self.enum_variants.clone(),
+            internal_types: self.internal_types.clone(),
+            plugin_name: self.plugin_name,
+        })
+    }
+
+    /// Freeze the builder into an immutable [`BuiltBuilder`].
+    ///
+    /// No further commands, events, types or constants can be registered after this point.
+    /// The result is cheap to clone so it can be shared between [`tauri::Builder::invoke_handler`], [`tauri::Builder::setup`] and your export logic.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_specta::{Builder, collect_commands};
+    /// use specta_typescript::Typescript;
+    ///
+    /// let builder = Builder::<tauri::Wry>::new()
+    ///     .commands(collect_commands![])
+    ///     .build();
+    ///
+    /// #[cfg(debug_assertions)]
+    /// builder
+    ///     .export(Typescript::default(), "../src/bindings.ts")
+    ///     .expect("Failed to export typescript bindings");
+    ///
+    /// tauri::Builder::default()
+    ///     .invoke_handler(builder.invoke_handler())
+    ///     .setup({
+    ///         let builder = builder.clone();
+    ///         move |app| {
+    ///             builder.mount_events(app);
+    ///
+    ///             Ok(())
+    ///         }
+    ///     })
+    ///     // on an actual app, remove the string argument
+    ///     .run(tauri::generate_context!("tests/tauri.conf.json"))
+    ///     .expect("error while running tauri application");
+    /// ```
+    pub fn build(self) -> BuiltBuilder<R> {
+        BuiltBuilder(Arc::new(self))
+    }
+}
+
+/// An immutable [`Builder`] created by [`Builder::build`].
+///
+/// This is cheap to clone as the underlying builder is stored behind an [`Arc`].
+/// Every method delegates to the builder, so exports are validated exactly like [`Builder::export`].
+pub struct BuiltBuilder<R: Runtime = tauri::Wry>(Arc<Builder<R>>);
+
+impl<R: Runtime> Clone for BuiltBuilder<R> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<R: Runtime> fmt::Debug for BuiltBuilder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BuiltBuilder").field(&self.0).finish()
+    }
+}
+
+impl<R: Runtime> BuiltBuilder<R> {
+    /// The Tauri invoke handler to trigger commands registered with the builder.
+    ///
+    /// Refer to [`Builder::invoke_handler`] for more information.
+    pub fn invoke_handler(&self) -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+        self.0.invoke_handler()
+    }
+
+    /// Mount all of the events in the builder onto a Tauri app.
+    ///
+    /// Refer to [`Builder::mount_events`] for more information.
+    pub fn mount_events(&self, handle: &impl Manager<R>) {
+        self.0.mount_events(handle)
+    }
+
+    /// Mount additional events onto a Tauri app after [`Self::mount_events`].
+    ///
+    /// Refer to [`Builder::mount_additional_events`] for more information.
+    pub fn mount_additional_events(&self, handle: &impl Manager<R>, events: Events) {
+        self.0.mount_additional_events(handle, events)
+    }
+
+    /// Find the cycles of named types which reference each other.
+    ///
+    /// Refer to [`Builder::check_type_cycles`] for more information.
+    pub fn check_type_cycles(&self) -> Result<(), Vec<TypeCycle>> {
+        self.0.check_type_cycles()
+    }
+
+    /// The name of every registered command.
+    ///
+    /// Refer to [`Builder::command_names`] for more information.
+    pub fn command_names(&self) -> Vec<String> {
+        self.0.command_names()
+    }
+
+    /// The name of every registered event.
+    ///
+    /// Refer to [`Builder::event_names`] for more information.
+    pub fn event_names(&self) -> Vec<String> {
+        self.0.event_names()
+    }
+
+    /// The key of every registered constant.
+    ///
+    /// Refer to [`Builder::constant_keys`] for more information.
+    pub fn constant_keys(&self) -> Vec<Cow<'static, str>> {
+        self.0.constant_keys()
+    }
+
+    /// Check the builder for configuration problems.
+    ///
+    /// Refer to [`Builder::validate`] for more information.
+    pub fn validate(&self) -> Result<(), Vec<BuilderValidationError>> {
+        self.0.validate()
+    }
+
+    /// Report which registered types are used by the commands, events and constants.
+    ///
+    /// Refer to [`Builder::type_coverage_report`] for more information.
+    pub fn type_coverage_report(&self) -> TypeCoverageReport {
+        self.0.type_coverage_report()
+    }
+
+    /// Export the bindings to a string.
+    ///
+    /// Refer to [`Builder::export_str`] for more information.
+    pub fn export_str<L>(&self, language: L) -> Result<String, Error>
+    where
+        L: LanguageExt,
+        L::Error: Send + Sync + 'static,
+    {
+        self.0.export_str(language)
+    }
+
+    /// Export the bindings to a file.
+    ///
+    /// Refer to [`Builder::export`] for more information.
+    pub fn export<L>(&self, language: L, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        L: LanguageExt,
+        L::Error: Send + Sync + 'static,
+    {
+        self.0.export(language, path)
+    }
+
+    /// Export every type as a JSON Schema document.
+    ///
+    /// Refer to [`Builder::export_json_schema`] for more information.
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_json_schema(&self, dir: impl AsRef<Path>) -> Result<(), Error> {
+        self.0.export_json_schema(dir)
+    }
+
+    /// Export the Tauri v2 permission files for the commands.
+    ///
+    /// Refer to [`Builder::export_permissions`] for more information.
+    pub fn export_permissions(&self, dir: impl AsRef<Path>) -> Result<(), Error> {
+        self.0.export_permissions(dir)
+    }
+
+    /// Export the graph of the exported types.
+    ///
+    /// Refer to [`Builder::export_type_graph`] for more information.
+    pub fn export_type_graph(
+        &self,
+        format: GraphFormat,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.0.export_type_graph(format, path)
+    }
+
+    /// Export a Typescript file mapping each command to its required permission.
+    ///
+    /// Refer to [`Builder::export_permissions_manifest`] for more information.
+    pub fn export_permissions_manifest(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        self.0.export_permissions_manifest(path)
+    }
+
+    /// Export a JSON manifest of the commands and events.
+    ///
+    /// Refer to [`Builder::export_manifest`] for more information.
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_manifest(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        self.0.export_manifest(path)
+    }
+
+    /// Export an OpenAPI 3.0 YAML document describing the commands.
+    ///
+    /// Refer to [`Builder::export_openapi`] for more information.
+    #[cfg(feature = "json-schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+    pub fn export_openapi(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        self.0.export_openapi(path)
+    }
+
+    /// Export the Typescript bindings into a directory, split across multiple files.
+    ///
+    /// Refer to [`Builder::export_ts_dir`] for more information.
+    #[cfg(feature = "typescript")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+    pub fn export_ts_dir(
+        &self,
+        language: specta_typescript::Typescript,
+        dir: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.0.export_ts_dir(language, dir)
+    }
+
+    /// Export the Typescript bindings into a directory using the given [`DirConfig`].
+    ///
+    /// Refer to [`Builder::export_ts_dir_with_config`] for more information.
+    #[cfg(feature = "typescript")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+    pub fn export_ts_dir_with_config(
+        &self,
+        language: specta_typescript::Typescript,
+        dir: impl AsRef<Path>,
+        config: DirConfig,
+    ) -> Result<(), Error> {
+        self.0.export_ts_dir_with_config(language, dir, config)
+    }
+
+    /// Export a TanStack Query hook for every command.
+    ///
+    /// Refer to [`Builder::export_react_query`] for more information.
+    #[cfg(feature = "react-query")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "react-query")))]
+    pub fn export_react_query(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.0.export_react_query(language, path)
+    }
+
+    /// Export a Vue 3 composable for every command and event.
+    ///
+    /// Refer to [`Builder::export_vue_composables`] for more information.
+    #[cfg(feature = "vue")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vue")))]
+    pub fn export_vue_composables(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.0.export_vue_composables(language, path)
+    }
+
+    /// Export a Svelte store for every command and event.
+    ///
+    /// Refer to [`Builder::export_svelte`] for more information.
+    #[cfg(feature = "svelte")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "svelte")))]
+    pub fn export_svelte(
+        &self,
+        language: specta_typescript::Typescript,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        self.0.export_svelte(language, path)
+    }
+}
+
+/// Configuration for [`Builder::export_ts_dir_with_config`].
+#[cfg(feature = "typescript")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DirConfig {
+    pub(crate) index_file: bool,
+}
+
+#[cfg(feature = "typescript")]
+impl Default for DirConfig {
+    fn default() -> Self {
+        Self { index_file: true }
+    }
+}
+
+#[cfg(feature = "typescript")]
+impl DirConfig {
+    /// Construct a new configuration with the default options configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to generate an `index.ts` which re-exports every public symbol. Defaults to `true`.
+    ///
+    /// Disable this if you maintain your own barrel file.
+    pub fn index_file(mut self, index_file: bool) -> Self {
+        self.index_file = index_file;
+        self
+    }
+}
+
+/// Write `contents` to `path` and format it.
+///
+/// If `skip_unchanged` is set the contents are formatted in a temporary file alongside `path` first, so an existing file is only replaced when the formatted output differs.
+fn write_formatted<L>(
+    language: &L,
+    path: &Path,
+    contents: &str,
+    skip_unchanged: bool,
+) -> Result<(), Error>
+where
+    L: specta::Language,
+    L::Error: Send + Sync + 'static,
+{
+    let format = |path: &Path| {
+        language.format(path).map_err(|err| Error::Format {
+            path: path.to_path_buf(),
+            source: Box::new(err),
+        })
+    };
+
+    let existing = match skip_unchanged {
+        true => fs::read_to_string(path).ok(),
+        false => None,
+    };
+    let Some(existing) = existing else {
+        let mut file = File::create(path).map_err(Error::io(path))?;
+        write!(file, "{contents}").map_err(Error::io(path))?;
+        return format(path);
+    };
+
+    let mut tmp_name = OsString::from(".");
+    tmp_name.push(path.file_name().unwrap_or_default());
+    let tmp = path.with_file_name(tmp_name);
+
+    let formatted = fs::write(&tmp, contents)
+        .map_err(Error::io(&tmp))
+        .and_then(|_| format(&tmp))
+        .and_then(|_| fs::read_to_string(&tmp).map_err(Error::io(&tmp)));
+    match formatted {
+        Ok(formatted) if formatted == existing => fs::remove_file(&tmp).map_err(Error::io(&tmp)),
+        Ok(_) => fs::rename(&tmp, path).map_err(Error::io(path)),
+        Err(err) => {
+            let _ = fs::remove_file(&tmp);
+            Err(err)
+        }
+    }
+}
+
+/// Resolve [`ModuleFormat::Auto`] using the nearest `deno.json` or `package.json` to `path`.
+fn detect_module_format(path: &Path) -> ModuleFormat {
+    let Some(dir) = path.ancestors().skip(1).find(|dir| {
+        ["deno.json", "deno.jsonc", "package.json"]
+            .iter()
+            .any(|file| dir.join(file).is_file())
+    }) else {
+        return ModuleFormat::Esm;
+    };
+    if dir.join("deno.json").is_file() || dir.join("deno.jsonc").is_file() {
+        return ModuleFormat::Deno;
+    }
+
+    let package = fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|package| serde_json::from_str::<serde_json::Value>(&package).ok());
+    // Like Node, a package without a `"type"` is CommonJS.
+    match package
+        .as_ref()
+        .and_then(|package| package.get("type")?.as_str())
+    {
+        Some("module") => ModuleFormat::Esm,
+        _ => ModuleFormat::CommonJs,
+    }
+}
+
+/// A hash of the commands, events and types for [`Builder::schema_version_constant`].
+///
+/// This uses FNV-1a as [`std::hash::DefaultHasher`] isn't guaranteed to be stable across Rust releases.
+fn schema_version(
+    commands: &[Function],
+    events: &BTreeMap<&'static str, DataType>,
+    types: &TypeMap,
+) -> String {
+    use std::fmt::Write;
+
+    let mut schema = String::new();
+
+    let mut commands = commands.iter().collect::<Vec<_>>();
+    commands.sort_by_key(|function| function.name());
+    for function in commands {
+        write!(schema, "command {}(", function.name()).ok();
+        for (name, ty) in function.args() {
+            write!(schema, "{name}: {ty:?}, ").ok();
+        }
+        writeln!(schema, ") -> {:?}", function.result()).ok();
+    }
+    for (name, ty) in events {
+        writeln!(schema, "event {name}: {ty:?}").ok();
+    }
+    for (_, ndt) in types.iter() {
+        writeln!(schema, "type {} = {:?}", ndt.name(), ndt.inner).ok();
+    }
+
+    let hash = schema.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:08x}", (hash ^ (hash >> 32)) as u32)
+}
+
+/// Group the types which are exported with the same name, ignoring names which are only used once.
+fn duplicate_type_names(types: &TypeMap) -> Vec<(&Cow<'static, str>, Vec<SpectaID>)> {
+    let mut names = BTreeMap::<_, Vec<_>>::new();
+    for (sid, ndt) in types.iter() {
+        names.entry(ndt.name()).or_default().push(sid);
+    }
+    names
+        .into_iter()
+        .filter(|(_, sids)| sids.len() > 1)
+        .collect()
+}
+
+/// The Rust type's name along with where it's defined, Eg. `Settings (src/user.rs:4:10)`.
+///
+/// Specta doesn't record module paths, so the definition site is what tells apart types with the same name.
+fn describe_type(types: &TypeMap, sid: SpectaID) -> Cow<'static, str> {
+    match types.get(sid).and_then(|ndt| ndt.ext()) {
+        Some(ext) => format!("{} ({})", sid.type_name(), ext.impl_location().as_str()).into(),
+        None => sid.type_name(),
+    }
+}
+
+/// Whether the plugin name can be converted into a TypeScript identifier, Eg. `fs-watch` to `fsWatch`.
+fn is_valid_plugin_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// A cycle of named types which reference each other, returned by [`Builder::check_type_cycles`].
+///
+/// It's displayed as the chain of type names, Eg. `UserProfile → UserPosts → PostAuthor → UserProfile`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeCycle {
+    types: Vec<String>,
+}
+
+impl TypeCycle {
+    /// The names of the types in the cycle, starting and ending with the same type.
+    pub fn types(&self) -> &[String] {
+        &self.types
+    }
+}
+
+impl fmt::Display for TypeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.types.join(" → "))
+    }
+}
+
+/// The file format of [`Builder::export_type_graph`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// A [Graphviz](https://graphviz.org) DOT file, with a node for each type and an edge to every type it contains.
+    Dot,
+    /// A JSON object mapping the name of each type to the names of the types it contains.
+    Json,
+}
+
+/// How a registered type is used, reported by [`Builder::type_coverage_report`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TypeCoverage {
+    /// The type is used by a command, event or constant, directly or through another type.
+    Reachable,
+    /// The type was registered with [`Builder::typ`] or [`Builder::types`] but isn't used by any command, event or constant.
+    Unreachable,
+    /// The type is only registered because an unreachable type references it.
+    Transitive,
+}
+
+impl fmt::Display for TypeCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Reachable => "reachable",
+            Self::Unreachable => "unreachable",
+            Self::Transitive => "transitive",
+        })
+    }
+}
+
+/// The coverage of every registered type, returned by [`Builder::type_coverage_report`].
+///
+/// It's displayed as a summary followed by a table of every type and a list of the orphaned types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeCoverageReport {
+    types: Vec<(String, TypeCoverage)>,
+}
+
+impl TypeCoverageReport {
+    /// The name and coverage of every registered type, sorted by name.
+    pub fn types(&self) -> &[(String, TypeCoverage)] {
+        &self.types
+    }
+
+    /// The names of the types which were registered explicitly but aren't used by any command, event or constant.
+    pub fn orphaned(&self) -> impl Iterator<Item = &str> {
+        self.types
+            .iter()
+            .filter(|(_, coverage)| *coverage == TypeCoverage::Unreachable)
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn count(&self, coverage: TypeCoverage) -> usize {
+        self.types.iter().filter(|(_, c)| *c == coverage).count()
+    }
+}
+
+impl fmt::Display for TypeCoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} types: {} reachable, {} unreachable, {} transitive",
+            self.types.len(),
+            self.count(TypeCoverage::Reachable),
+            self.count(TypeCoverage::Unreachable),
+            self.count(TypeCoverage::Transitive),
+        )?;
+
+        let width = self
+            .types
+            .iter()
+            .map(|(name, _)| name.len())
+            .chain(["Type".len()])
+            .max()
+            .unwrap_or_default();
+        writeln!(f, "\n{:width$}  Coverage", "Type")?;
+        for (name, coverage) in &self.types {
+            writeln!(f, "{name:width$}  {coverage}")?;
+        }
+
+        let mut orphaned = self.orphaned().peekable();
+        if orphaned.peek().is_some() {
+            writeln!(f, "\nOrphaned types:")?;
+            for name in orphaned {
+                writeln!(f, "  - {name}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The named types reachable from `roots`, including the roots themselves.
+fn reachable_types(
+    types: &TypeMap,
+    roots: impl IntoIterator<Item = SpectaID>,
+) -> BTreeSet<SpectaID> {
+    let mut reachable = BTreeSet::new();
+    let mut queue = roots.into_iter().collect::<VecDeque<_>>();
+
+    while let Some(sid) = queue.pop_front() {
+        if !reachable.insert(sid) {
+            continue;
+        }
+
+        if let Some(ndt) = types.get(sid) {
+            queue.extend(
+                type_graph::references(&ndt.inner)
+                    .into_iter()
+                    .map(|(sid, _)| sid),
+            );
+        }
+    }
+
+    reachable
+}
+
+/// Find a cycle through every type which is part of one, using the shortest cycle through each type.
+///
+/// A type which is part of several cycles is only reported once, so overlapping cycles may be skipped.
+fn type_cycles(types: &TypeMap) -> Vec<TypeCycle> {
+    let mut reported = BTreeSet::new();
+    let mut cycles = Vec::new();
+    for (sid, _) in types.iter() {
+        if reported.contains(&sid) {
+            continue;
+        }
+
+        if let Some(cycle) = type_graph::shortest_cycle(types, sid) {
+            reported.extend(cycle.iter().copied());
+            cycles.push(TypeCycle {
+                types: type_names(types, cycle.iter()),
+            });
+        }
+    }
+    cycles
+}
+
+fn type_names<'a>(types: &TypeMap, sids: impl Iterator<Item = &'a SpectaID>) -> Vec<String> {
+    sids.filter_map(|sid| types.get(*sid))
+        .map(|ndt| ndt.name().to_string())
+        .collect()
+}
+
+/// How collisions are resolved when merging into a [`Builder`].
+///
+/// Used by [`Builder::merge_with_policy`] and [`Builder::merge_type_collection`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// The incoming item replaces the existing one.
+    #[default]
+    Override,
+    /// The existing item is kept and the incoming one is discarded.
+    Keep,
+    /// The existing item is kept and an error is returned when exporting.
+    Error,
+    /// Panic when a collision is detected.
+    Panic,
+}
+
+impl MergePolicy {
+    /// Handle a collision, returning whether the incoming item should replace the existing one.
+    #[track_caller]
+    fn collision(
+        &self,
+        conflicts: &mut Vec<(&'static str, String)>,
+        kind: &'static str,
+        name: &str,
+    ) -> bool {
+        match self {
+            Self::Override => true,
+            Self::Keep => false,
+            Self::Error => {
+                conflicts.push((kind, name.to_string()));
+                false
+            }
+            Self::Panic => {
+                panic!("Tauri Specta failed to merge: {kind} '{name}' has already been registered")
+            }
+        }
+    }
+}
+
+fn deprecated_type(note: Option<&'static str>) -> DeprecatedType {
+    match note {
+        Some(note) => DeprecatedType::DeprecatedWithSince {
+            since: None,
+            note: note.into(),
+        },
+        None => DeprecatedType::Deprecated,
+    }
+}
+
+type InvokeHandler<R> = Arc<dyn Fn(Invoke<R>) -> bool + Send + Sync + 'static>;
+
+type Middleware<R> =
+    Arc<dyn Fn(Invoke<R>, &dyn Fn(Invoke<R>) -> bool) -> bool + Send + Sync + 'static>;
+
+/// Route invokes for the `primary` commands to the `primary` handler and everything else to `fallback`.
+fn dispatch<R: Runtime>(
+    primary_commands: BTreeSet<Cow<'static, str>>,
+    primary: InvokeHandler<R>,
+    fallback: InvokeHandler<R>,
+) -> InvokeHandler<R> {
+    Arc::new(move |invoke| {
+        if primary_commands.contains(invoke.message.command()) {
+            primary(invoke)
+        } else {
+            fallback(invoke)
+        }
+    })
 }
@@ -0,0 +1,149 @@
+use std::{borrow::Cow, error, io, path::PathBuf};
+
+/// The error type returned when exporting bindings.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing a file failed.
+    #[error("Tauri Specta failed to write '{}': {source}", path.display())]
+    Io {
+        /// The file or directory which couldn't be written.
+        path: PathBuf,
+        /// The underlying error.
+        source: io::Error,
+    },
+    /// The language exporter failed to render the bindings.
+    #[error("Tauri Specta failed to export bindings: {0}")]
+    Export(#[source] Box<dyn error::Error + Send + Sync>),
+    /// A constant could not be serialized.
+    #[error("Tauri Specta failed to serialize constant '{name}': {source}")]
+    Constant {
+        /// The name of the constant.
+        name: Cow<'static, str>,
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// Multiple Rust types would be exported with the same name.
+    #[error(
+        "Tauri Specta found duplicate type name '{name}' used by {}. Use `#[specta(rename = \"...\")]` to give each type a unique name",
+        types.iter().map(|t| format!("'{t}'")).collect::<Vec<_>>().join(", ")
+    )]
+    DuplicateTypeName {
+        /// The exported name of the types.
+        name: Cow<'static, str>,
+        /// Every type which uses the name, along with where it's defined.
+        types: Vec<Cow<'static, str>>,
+    },
+    /// Multiple events were collected with the same name.
+    #[error(
+        "Tauri Specta found duplicate event name '{name}' used by {}. Use `#[tauri_specta(event_name = \"...\")]` or `collect_events![MyEvent as \"...\"]` to give each event a unique name",
+        types.iter().map(|t| format!("'{t}'")).collect::<Vec<_>>().join(", ")
+    )]
+    DuplicateEventName {
+        /// The name of the events.
+        name: Cow<'static, str>,
+        /// Every event which uses the name, along with where it's defined.
+        types: Vec<Cow<'static, str>>,
+    },
+    /// A type overridden with [`Builder::override_type_with_import`](crate::Builder::override_type_with_import) has the same name as an exported type.
+    #[error("Tauri Specta found the type '{name}' imported to override '{overridden}' conflicts with the exported type '{conflict}'. Rename the import")]
+    TypeOverrideCollision {
+        /// The name of the imported type.
+        name: Cow<'static, str>,
+        /// The Rust path of the overridden type.
+        overridden: Cow<'static, str>,
+        /// The Rust path of the exported type with the same name.
+        conflict: Cow<'static, str>,
+    },
+    /// A [`TraitObject`](crate::TraitObject) was exported without being allowed by [`Builder::allow_trait_objects`](crate::Builder::allow_trait_objects).
+    #[error("Tauri Specta can't type trait objects as their concrete type is only known at runtime. Use `Builder::allow_trait_objects` to export them as `unknown`")]
+    TraitObject,
+    /// Merging builders produced conflicting definitions with [`MergePolicy::Error`](crate::MergePolicy::Error).
+    #[error("Tauri Specta found conflicting definitions for {}", .0.join(", "))]
+    Conflict(Vec<String>),
+    /// The formatter failed to format the exported file.
+    #[error("Tauri Specta failed to format '{}': {source}", path.display())]
+    Format {
+        /// The file which couldn't be formatted.
+        path: PathBuf,
+        /// The underlying error.
+        source: Box<dyn error::Error + Send + Sync>,
+    },
+}
+
+/// A configuration problem found by [`Builder::validate`](crate::Builder::validate).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum BuilderValidationError {
+    /// Multiple commands were registered with the same name.
+    #[error("command '{name}' is registered more than once")]
+    DuplicateCommandName {
+        /// The name of the commands.
+        name: Cow<'static, str>,
+    },
+    /// Multiple events were registered with the same name.
+    #[error(
+        "event name '{name}' is used by {}",
+        types.iter().map(|t| format!("'{t}'")).collect::<Vec<_>>().join(", ")
+    )]
+    DuplicateEventName {
+        /// The name of the events.
+        name: Cow<'static, str>,
+        /// Every event which uses the name, along with where it's defined.
+        types: Vec<Cow<'static, str>>,
+    },
+    /// A constant has the same name as a command.
+    #[error("constant '{name}' shadows the command '{command}'")]
+    ConstantShadowsCommand {
+        /// The name of the constant.
+        name: Cow<'static, str>,
+        /// The name of the command.
+        command: Cow<'static, str>,
+    },
+    /// A constant group was registered inside another constant group, which isn't supported.
+    #[error("constant group '{name}' can't be nested in the constant group '{group}'")]
+    NestedConstantGroup {
+        /// The name of the outer group.
+        group: Cow<'static, str>,
+        /// The name of the nested group.
+        name: Cow<'static, str>,
+    },
+    /// A constant group is exported with the same name as a top-level constant.
+    #[error("constant group '{name}' has the same name as a constant")]
+    ConstantGroupShadowsConstant {
+        /// The name of the group.
+        name: Cow<'static, str>,
+    },
+    /// Multiple Rust types would be exported with the same name.
+    #[error(
+        "type name '{name}' is used by {}",
+        types.iter().map(|t| format!("'{t}'")).collect::<Vec<_>>().join(", ")
+    )]
+    DuplicateTypeName {
+        /// The exported name of the types.
+        name: Cow<'static, str>,
+        /// Every type which uses the name, along with where it's defined.
+        types: Vec<Cow<'static, str>>,
+    },
+    /// The plugin name contains characters which can't be used in a TypeScript identifier.
+    #[error("plugin name '{name}' must start with a letter and only contain letters, digits, '-' or '_'")]
+    InvalidPluginName {
+        /// The plugin name.
+        name: &'static str,
+    },
+    /// Merging builders with [`MergePolicy::Error`](crate::MergePolicy::Error) produced conflicting definitions.
+    #[error("{kind} '{name}' has conflicting definitions")]
+    Conflict {
+        /// The kind of item, Eg. `constant` or `type`.
+        kind: &'static str,
+        /// The name of the item.
+        name: Cow<'static, str>,
+    },
+}
+
+impl Error {
+    pub(crate) fn io(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| Self::Io { path, source }
+    }
+}
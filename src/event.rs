@@ -8,7 +8,9 @@ use crate::{apply_as_prefix, ItemType};
 
 #[derive(Default)]
 pub(crate) struct EventRegistryMeta {
+    pub name: &'static str,
     pub plugin_name: Option<&'static str>,
+    pub window: Option<&'static str>,
 }
 
 /// A struct for managing events that is put into Tauri's state.
@@ -32,9 +34,20 @@ impl EventRegistry {
             .get(&sid)
             .unwrap_or_else(|| panic!("Event {name} not found in registry!"));
 
+        // The event may have been renamed when it was collected.
         meta.plugin_name
-            .map(|n| apply_as_prefix(n, name, ItemType::Event).into())
-            .unwrap_or_else(|| name.into())
+            .map(|n| apply_as_prefix(n, meta.name, ItemType::Event).into())
+            .unwrap_or_else(|| meta.name.into())
+    }
+
+    /// gets the label of the window the event is targeted at, if it was registered with one.
+    pub fn get_event_window<E: Event, R: Runtime>(
+        handle: &impl Manager<R>,
+    ) -> Option<&'static str> {
+        let this = handle.try_state::<EventRegistry>()?.inner();
+        let map = this.0.read().expect("Failed to read EventRegistry");
+
+        map.get(&E::sid()).and_then(|meta| meta.window)
     }
 
     pub fn get_or_manage<R: Runtime>(handle: &impl Manager<R>) -> tauri::State<'_, Self> {
@@ -56,12 +69,20 @@ pub struct TypedEvent<T: Event> {
 
 macro_rules! make_handler {
     ($handler:ident) => {
-        move |event| {
-            $handler(TypedEvent {
+        move |event| match serde_json::from_str(event.payload()) {
+            Ok(payload) => $handler(TypedEvent {
                 id: event.id(),
-                payload: serde_json::from_str(event.payload())
-                    .expect("Failed to deserialize event payload"),
-            });
+                payload,
+            }),
+            // The event was emitted with a different payload, Eg. by the frontend or with `Emitter::emit`.
+            #[cfg_attr(not(feature = "log"), allow(unused_variables))]
+            Err(err) => {
+                #[cfg(feature = "log")]
+                log::warn!(
+                    "Tauri Specta skipped the event '{}' as its payload couldn't be deserialized: {err}",
+                    std::any::type_name::<Self>()
+                );
+            }
         }
     };
 }
@@ -97,10 +118,19 @@ macro_rules! make_handler {
 /// }
 /// ```
 pub trait Event: NamedType {
-    /// The unique name for this event. Derived from the struct's name via the [`Event`](macro@crate::Event) derive macro.
+    /// The unique name for this event. Derived from the struct's name via the [`Event`](macro@crate::Event) derive macro, or set with `#[tauri_specta(event_name = "...")]`.
+    ///
+    /// It can be overridden when collecting the event with `collect_events![MyEvent as "my-event"]`.
     const NAME: &'static str;
 
+    /// The label of the window this event is targeted at, if any. Set with `#[tauri_specta(window = "label")]` on the [`Event`](macro@crate::Event) derive macro.
+    ///
+    /// [`Self::emit`] sends the event to this window only and the generated bindings listen on it by default.
+    const WINDOW: Option<&'static str> = None;
+
     /// Listen to an emitted event on this manager.
+    ///
+    /// Events with a payload which can't be deserialized into `Self` are skipped, and logged when the `log` feature is enabled.
     fn listen<F, R: Runtime, H: Listener<R> + Manager<R>>(handle: &H, handler: F) -> EventId
     where
         F: Fn(TypedEvent<Self>) + Send + 'static,
@@ -113,6 +143,8 @@ pub trait Event: NamedType {
     }
 
     /// Listen to an emitted event to any [target](EventTarget).
+    ///
+    /// See [`Self::listen`] for more information.
     fn listen_any<F, R: Runtime, H: Listener<R> + Manager<R>>(handle: &H, handler: F) -> EventId
     where
         F: Fn(TypedEvent<Self>) + Send + 'static,
@@ -125,9 +157,11 @@ pub trait Event: NamedType {
     }
 
     /// Listen to an event on this manager only once.
+    ///
+    /// The handler is removed after the first event is delivered, even if its payload couldn't be deserialized.
     fn once<F, R: Runtime, H: Listener<R> + Manager<R>>(handle: &H, handler: F) -> EventId
     where
-        F: Fn(TypedEvent<Self>) + Send + 'static,
+        F: FnOnce(TypedEvent<Self>) + Send + 'static,
         Self: DeserializeOwned,
     {
         handle.once(
@@ -150,28 +184,32 @@ pub trait Event: NamedType {
         )
     }
 
-    /// Emits an event to all [targets](EventTarget) matching the given target.
+    /// Emits an event to all [targets](EventTarget), or only to [`Self::WINDOW`] if it's set.
     fn emit<R: Runtime, H: Emitter<R> + Manager<R>>(&self, handle: &H) -> tauri::Result<()>
     where
         Self: Serialize + Clone,
     {
-        handle.emit(
-            &EventRegistry::get_event_name::<Self, _>(handle, Self::NAME),
-            self,
-        )
+        let name = EventRegistry::get_event_name::<Self, _>(handle, Self::NAME);
+
+        match EventRegistry::get_event_window::<Self, _>(handle) {
+            Some(window) => handle.emit_to(window, &name, self),
+            None => handle.emit(&name, self),
+        }
     }
 
     /// Emits an event to all [targets](EventTarget) matching the given target.
+    ///
+    /// The target can be a window label (`"settings"`) or any [`EventTarget`].
     fn emit_to<R: Runtime, H: Emitter<R> + Manager<R>>(
         &self,
         handle: &H,
-        label: &str,
+        target: impl Into<EventTarget>,
     ) -> tauri::Result<()>
     where
         Self: Serialize + Clone,
     {
         handle.emit_to(
-            label,
+            target,
             &EventRegistry::get_event_name::<Self, _>(handle, Self::NAME),
             self,
         )
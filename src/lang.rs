@@ -1,8 +1,63 @@
+#[allow(unused)]
+/// The comment at the top of every generated file.
+pub(crate) const DO_NOT_EDIT: &str = "// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.";
+
 #[cfg(feature = "javascript")]
 mod js;
 
 #[cfg(feature = "typescript")]
-mod ts;
+pub(crate) mod ts;
+
+#[cfg(feature = "typescript")]
+mod dts;
+
+#[cfg(feature = "typescript")]
+pub use dts::TypescriptDeclarations;
 
 #[cfg(any(feature = "javascript", feature = "typescript"))]
 pub(crate) mod js_ts;
+
+#[cfg(feature = "json-schema")]
+pub(crate) mod json_schema;
+
+#[cfg(feature = "json-schema")]
+pub use json_schema::JsonSchema;
+
+#[cfg(any(feature = "react-query", feature = "vue", feature = "svelte"))]
+mod framework;
+
+#[cfg(feature = "react-query")]
+pub(crate) mod react_query;
+
+#[cfg(feature = "vue")]
+pub(crate) mod vue;
+
+#[cfg(feature = "svelte")]
+pub(crate) mod svelte;
+
+#[cfg(any(feature = "zod", feature = "valibot"))]
+mod schema;
+
+#[cfg(feature = "zod")]
+mod zod;
+
+#[cfg(feature = "zod")]
+pub use zod::Zod;
+
+#[cfg(feature = "valibot")]
+mod valibot;
+
+#[cfg(feature = "valibot")]
+pub use valibot::Valibot;
+
+#[cfg(feature = "kotlin")]
+mod kotlin;
+
+#[cfg(feature = "kotlin")]
+pub use kotlin::Kotlin;
+
+#[cfg(feature = "swift")]
+mod swift;
+
+#[cfg(feature = "swift")]
+pub use swift::{Swift, SwiftAccessLevel};
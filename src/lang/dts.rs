@@ -0,0 +1,242 @@
+//! Typescript declaration file (`.d.ts`) exporter.
+
+use std::{borrow::Cow, path::Path};
+
+use heck::ToLowerCamelCase;
+use specta::{Language, TypeMap};
+use specta_typescript::{ExportError, Typescript};
+
+use crate::{
+    lang::{js_ts, ts::command_signature},
+    ExportContext, LanguageExt,
+};
+
+const GLOBALS: &str = include_str!("./globals.d.ts");
+
+/// Typescript declaration file (`.d.ts`) exporter.
+///
+/// Generates only the type declarations of the bindings, without the implementation which invokes the commands.
+/// This is useful when the implementation is shipped separately, Eg. by a plugin published to npm.
+///
+/// When used with [`Builder::export`](crate::Builder::export) the file extension is changed to `.d.ts`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, TypescriptDeclarations};
+/// use specta_typescript::Typescript;
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(TypescriptDeclarations::new(Typescript::default()), "../src/bindings.d.ts")
+///     .expect("Failed to export typescript declarations");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypescriptDeclarations(pub Typescript);
+
+impl TypescriptDeclarations {
+    /// Construct a new declaration exporter from a Typescript configuration.
+    pub fn new(ts: Typescript) -> Self {
+        Self(ts)
+    }
+}
+
+impl From<Typescript> for TypescriptDeclarations {
+    fn from(ts: Typescript) -> Self {
+        Self(ts)
+    }
+}
+
+impl Language for TypescriptDeclarations {
+    type Error = ExportError;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        self.0.export(type_map)
+    }
+
+    fn format(&self, path: &Path) -> Result<(), Self::Error> {
+        self.0.format(path)
+    }
+}
+
+impl LanguageExt for TypescriptDeclarations {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        let cfg = &*js_ts::affix_types(cfg);
+        let ts = &self.0;
+        let header = &ts.header;
+        let do_not_edit = js_ts::DO_NOT_EDIT;
+
+        let dependant_types = js_ts::type_imports(cfg)
+            .map(Ok)
+            .chain(
+                cfg.type_map
+                    .iter()
+                    .map(|(sid, ndt)| js_ts::named_datatype(ts, cfg, sid, ndt)),
+            )
+            .chain(js_ts::type_aliases(cfg).map(Ok))
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n");
+
+        let members = render_members(ts, cfg, &[])?;
+        let namespaces = render_namespaces(ts, cfg, &[])?;
+        let type_maps = js_ts::commands_type(ts, cfg)? + &js_ts::events_type(ts, cfg)?;
+
+        let constants = cfg
+            .constants
+            .iter()
+            .map(|(name, constant)| {
+                Ok(format!(
+                    "export declare const {name}: {};",
+                    js_ts::datatype(ts, &constant.ty, &cfg.type_map)?
+                ))
+            })
+            .chain(cfg.constant_groups.iter().map(|(group, constants)| {
+                let members = constants
+                    .iter()
+                    .map(|(name, constant)| {
+                        Ok(format!(
+                            "readonly {}: {};",
+                            name.to_lower_camel_case(),
+                            js_ts::datatype(ts, &constant.ty, &cfg.type_map)?
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, ExportError>>()?
+                    .join(" ");
+
+                Ok(format!(
+                    "export declare const {}: {{ {members} }};",
+                    group.to_lower_camel_case()
+                ))
+            }))
+            .chain(
+                js_ts::enum_variants(cfg)
+                    .into_iter()
+                    .map(|(name, variants)| {
+                        Ok(format!(
+                            "export declare const {name}: readonly [{}];",
+                            variants.join(", ")
+                        ))
+                    }),
+            )
+            .collect::<Result<Vec<_>, ExportError>>()?
+            .join("\n");
+
+        Ok(format! {
+            r#"{header}
+{do_not_edit}
+
+/** user-defined commands and events **/
+
+{members}{namespaces}
+{type_maps}
+
+/** user-defined constants **/
+
+{constants}
+
+/** user-defined types **/
+
+{dependant_types}
+
+/** tauri-specta globals **/
+
+{globals}"#,
+            globals = js_ts::globals(GLOBALS, cfg)
+        })
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        Some("d.ts")
+    }
+}
+
+/// Declare the `commands` and `events` within the namespace at `path`.
+///
+/// At the top level these are exported declarations, within a namespace they are implicitly ambient.
+fn render_members(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    let declare = match path.is_empty() {
+        true => "export declare",
+        false => "export",
+    };
+    let mut members = Vec::new();
+
+    // The root `commands` object is always exported to match the Typescript bindings.
+    let mut commands = js_ts::namespace_commands(cfg, path).peekable();
+    if path.is_empty() || commands.peek().is_some() {
+        let commands = commands
+            .map(|function| {
+                let (docs, mut arg_defs, ret_type) = command_signature(ts, cfg, function)?;
+                let name = cfg.command_ident(function.name());
+
+                let mut declaration = format!(
+                    "{docs}{name}({}): Promise<{ret_type}>;",
+                    arg_defs.join(", ")
+                );
+                if let Some((_, item)) = js_ts::stream_channel(cfg, function) {
+                    let item = js_ts::datatype(ts, item, &cfg.type_map)?;
+                    arg_defs.remove(function.args().count() - 1);
+                    declaration += &format!(
+                        "\n{docs}{name}Stream({}): AsyncIterable<{item}>;",
+                        arg_defs.join(", ")
+                    );
+                }
+
+                Ok(declaration)
+            })
+            .collect::<Result<Vec<_>, ExportError>>()?
+            .join("\n");
+
+        members.push(format!("{declare} const commands: {{\n{commands}\n}};"));
+    }
+
+    let events = js_ts::namespace_events(cfg, path);
+    if !events.is_empty() {
+        let events = events
+            .iter()
+            .map(|(name, typ)| {
+                Ok(format!(
+                    "{}{}: __Event__<{}>;",
+                    js_ts::event_docs(name, typ, cfg),
+                    cfg.event_ident(name),
+                    js_ts::datatype(ts, typ, &cfg.type_map)?
+                ))
+            })
+            .collect::<Result<Vec<_>, ExportError>>()?
+            .join("\n");
+
+        members.push(format!("{declare} const events: {{\n{events}\n}};"));
+    }
+
+    Ok(members.join("\n\n"))
+}
+
+fn render_namespaces(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    let declare = match path.is_empty() {
+        true => "export declare namespace",
+        false => "export namespace",
+    };
+
+    js_ts::namespace_children(cfg, path)
+        .into_iter()
+        .map(|name| {
+            let path = [path, std::slice::from_ref(name)].concat();
+
+            Ok(format!(
+                "\n\n{declare} {} {{\n{}{}\n}}",
+                name.to_lower_camel_case(),
+                render_members(ts, cfg, &path)?,
+                render_namespaces(ts, cfg, &path)?
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,179 @@
+//! Core utilities for the frontend framework exporters.
+//!
+//! They wrap the commands and events in the framework's primitives, so they share everything but the wrappers themselves.
+
+use heck::ToLowerCamelCase;
+use specta::datatype::{DataType, Function, FunctionResultVariant};
+use specta_typescript::{ExportError, Typescript};
+
+use crate::{type_graph, ExportContext};
+
+use super::js_ts;
+
+/// A command, with its types rendered.
+pub(crate) struct Command {
+    /// The name the command is invoked with.
+    pub name: String,
+    /// The name of the function generated for the command, Eg. `getUser`.
+    pub ident: String,
+    /// The name and type of every argument.
+    pub args: Vec<(String, String)>,
+    /// The type the command resolves with.
+    pub data: String,
+    /// The type the command rejects with, which is only known for commands returning a `Result`.
+    pub error: Option<String>,
+    pub docs: String,
+}
+
+impl Command {
+    pub fn new(
+        ts: &Typescript,
+        cfg: &ExportContext,
+        function: &Function,
+    ) -> Result<Self, ExportError> {
+        let args = function
+            .args()
+            .map(|(name, typ)| Ok((name.to_lower_camel_case(), datatype(ts, cfg, typ)?)))
+            .collect::<Result<Vec<_>, ExportError>>()?;
+        let (data, error) = match function.result() {
+            Some(FunctionResultVariant::Result(t, e)) => {
+                (datatype(ts, cfg, t)?, Some(datatype(ts, cfg, e)?))
+            }
+            Some(FunctionResultVariant::Value(t)) => (datatype(ts, cfg, t)?, None),
+            None => ("null".to_string(), None),
+        };
+
+        let docs = {
+            let mut builder = specta_typescript::js_doc::Builder::default();
+
+            if let Some(d) = js_ts::command_deprecated(cfg, function) {
+                builder.push_deprecated(d);
+            }
+
+            if !function.docs().is_empty() {
+                builder.extend(function.docs().split("\n"));
+            }
+
+            builder.build()
+        };
+
+        Ok(Self {
+            name: cfg.command_name(function.name()),
+            ident: cfg.command_ident(function.name()),
+            args,
+            data,
+            error,
+            docs,
+        })
+    }
+
+    #[allow(unused)]
+    pub fn arg_names(&self) -> Vec<String> {
+        self.args.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// The arguments as parameters of the generated function, Eg. `id: string, limit: number`.
+    #[allow(unused)]
+    pub fn params(&self) -> String {
+        self.args
+            .iter()
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Invoke the command with `args`, Eg. `{ id }`.
+    pub fn invoke(&self, args: Option<String>) -> String {
+        let args = args.map(|args| format!(", {args}")).unwrap_or_default();
+
+        format!(r#"TAURI_INVOKE<{}>("{}"{args})"#, self.data, self.name)
+    }
+}
+
+/// An event, with its payload rendered.
+#[allow(unused)]
+pub(crate) struct Event {
+    /// The name the event is emitted with.
+    pub name: String,
+    /// The name of the item generated for the event, Eg. `themeChanged`.
+    pub ident: String,
+    pub payload: String,
+    pub docs: String,
+}
+
+#[allow(unused)]
+impl Event {
+    pub fn new(
+        ts: &Typescript,
+        cfg: &ExportContext,
+        name: &str,
+        typ: &DataType,
+    ) -> Result<Self, ExportError> {
+        Ok(Self {
+            name: cfg.event_name(name),
+            ident: cfg.event_ident(name),
+            payload: datatype(ts, cfg, typ)?,
+            docs: js_ts::event_docs(name, typ, cfg),
+        })
+    }
+}
+
+/// Render the file, with every section under its heading followed by the types.
+pub(crate) fn render(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    imports: &[String],
+    sections: &[(&str, String)],
+) -> Result<String, ExportError> {
+    let types = js_ts::type_imports(cfg)
+        .map(Ok)
+        .chain(
+            cfg.type_map
+                .iter()
+                .map(|(sid, ndt)| js_ts::named_datatype(ts, cfg, sid, ndt)),
+        )
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n");
+    let sections = sections
+        .iter()
+        .map(|(heading, section)| format!("/** user-defined {heading} **/\n\n{section}\n\n"))
+        .collect::<String>();
+
+    Ok(format! {
+        r#"{header}
+{DO_NOT_EDIT}
+
+{imports}
+
+{sections}/** user-defined types **/
+
+{types}
+"#,
+        header = ts.header,
+        DO_NOT_EDIT = js_ts::DO_NOT_EDIT,
+        imports = imports.join("\n"),
+    })
+}
+
+/// Import `invoke` from `@tauri-apps/api/core`, along with the `Channel` type if a command takes one.
+pub(crate) fn core_import(cfg: &ExportContext) -> String {
+    let channel = cfg
+        .commands
+        .iter()
+        .flat_map(|function| function.args())
+        .flat_map(|(_, typ)| type_graph::references(typ))
+        .any(|(sid, _)| sid == crate::channel_sid());
+
+    format!(
+        r#"import {{ invoke as TAURI_INVOKE{} }} from "{}/core";"#,
+        match channel {
+            true => ", type Channel as TAURI_CHANNEL",
+            false => "",
+        },
+        js_ts::tauri_api(cfg)
+    )
+}
+
+fn datatype(ts: &Typescript, cfg: &ExportContext, typ: &DataType) -> Result<String, ExportError> {
+    js_ts::datatype(ts, typ, &cfg.type_map)
+}
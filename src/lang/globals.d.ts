@@ -0,0 +1,55 @@
+import type {
+	invoke as __TAURI_INVOKE__,
+	Channel as TAURI_CHANNEL,
+	InvokeOptions as TAURI_INVOKE_OPTIONS,
+} from "@tauri-apps/api/core";
+import type * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import type { WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __ListenOptions__ = {
+	/** Unlisten once the signal is aborted, even if it's aborted before the listener is registered. */
+	signal?: AbortSignal;
+	/** Only receive the event when it's emitted to the window with this label. */
+	window?: string;
+	/** Only receive the event when it's emitted to this target. Takes precedence over `window`. */
+	target?: string | TAURI_API_EVENT.EventTarget;
+};
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+		options?: __ListenOptions__,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+		options?: __ListenOptions__,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: T extends null
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+	emitTo: T extends null
+		? (
+				target: string | TAURI_API_EVENT.EventTarget,
+				payload?: T,
+			) => ReturnType<typeof TAURI_API_EVENT.emitTo>
+		: (
+				target: string | TAURI_API_EVENT.EventTarget,
+				payload: T,
+			) => ReturnType<typeof TAURI_API_EVENT.emitTo>;
+};
+
+type __Event__<T> = __EventObj__<T> & {
+	(handle: __WebviewWindow__): __EventObj__<T>;
+};
+
+type __EventHandlers__ = Pick<typeof TAURI_API_EVENT, "listen" | "once" | "emit" | "emitTo">;
+
+/** Replace the `invoke` used by every command, Eg. with a mock in tests. Pass `null` to restore the default. */
+export declare function __setInvokeHandler(handler: typeof __TAURI_INVOKE__ | null): void;
+
+/** Replace the `listen`, `once`, `emit` and `emitTo` used by every event, Eg. with mocks in tests. Pass `null` to restore the defaults. */
+export declare function __setEventHandlers(handlers: Partial<__EventHandlers__> | null): void;
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
@@ -1,28 +1,145 @@
 import {
-	invoke as TAURI_INVOKE,
+	invoke as __TAURI_INVOKE__,
 	Channel as TAURI_CHANNEL,
+	type InvokeOptions as TAURI_INVOKE_OPTIONS,
 } from "@tauri-apps/api/core";
 import * as TAURI_API_EVENT from "@tauri-apps/api/event";
 import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
 
+type __ListenOptions__ = {
+	/** Unlisten once the signal is aborted, even if it's aborted before the listener is registered. */
+	signal?: AbortSignal;
+	/** Only receive the event when it's emitted to the window with this label. */
+	window?: string;
+	/** Only receive the event when it's emitted to this target. Takes precedence over `window`. */
+	target?: string | TAURI_API_EVENT.EventTarget;
+};
+
 type __EventObj__<T> = {
 	listen: (
 		cb: TAURI_API_EVENT.EventCallback<T>,
+		options?: __ListenOptions__,
 	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
 	once: (
 		cb: TAURI_API_EVENT.EventCallback<T>,
+		options?: __ListenOptions__,
 	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
 	emit: T extends null
 		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
 		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+	emitTo: T extends null
+		? (
+				target: string | TAURI_API_EVENT.EventTarget,
+				payload?: T,
+			) => ReturnType<typeof TAURI_API_EVENT.emitTo>
+		: (
+				target: string | TAURI_API_EVENT.EventTarget,
+				payload: T,
+			) => ReturnType<typeof TAURI_API_EVENT.emitTo>;
 };
 
+type __EventHandlers__ = Pick<typeof TAURI_API_EVENT, "listen" | "once" | "emit" | "emitTo">;
+
+let TAURI_INVOKE = __TAURI_INVOKE__;
+let TAURI_EVENT: __EventHandlers__ = TAURI_API_EVENT;
+
+/** Replace the `invoke` used by every command, Eg. with a mock in tests. Pass `null` to restore the default. */
+export function __setInvokeHandler(handler: typeof __TAURI_INVOKE__ | null) {
+	TAURI_INVOKE = handler ?? __TAURI_INVOKE__;
+}
+
+/** Replace the `listen`, `once`, `emit` and `emitTo` used by every event, Eg. with mocks in tests. Pass `null` to restore the defaults. */
+export function __setEventHandlers(handlers: Partial<__EventHandlers__> | null) {
+	TAURI_EVENT = {
+		listen: handlers?.listen ?? TAURI_API_EVENT.listen,
+		once: handlers?.once ?? TAURI_API_EVENT.once,
+		emit: handlers?.emit ?? TAURI_API_EVENT.emit,
+		emitTo: handlers?.emitTo ?? TAURI_API_EVENT.emitTo,
+	};
+}
+
+function __listen__(
+	register: () => Promise<TAURI_API_EVENT.UnlistenFn>,
+	options?: __ListenOptions__,
+): Promise<TAURI_API_EVENT.UnlistenFn> {
+	const signal = options?.signal;
+	if (signal?.aborted) return Promise.resolve(() => {});
+
+	const unlisten = register();
+	// Waiting on the registration handles the signal aborting while it's still in flight.
+	signal?.addEventListener("abort", () => unlisten.then((f) => f()), { once: true });
+	return unlisten;
+}
+
+/** The options to listen with, targeting `options.target`, `options.window` or else the window the event is registered for. */
+function __listenTarget__(options?: __ListenOptions__, label?: string) {
+	const target = options?.target ?? options?.window ?? label;
+	return target === undefined ? undefined : { target };
+}
+
+/**
+ * Invoke a command with a new channel and iterate over its messages, ending once the command returns or throwing if it fails.
+ *
+ * Channels can't apply backpressure, so messages are buffered until they're consumed.
+ * Returning early drops the channel and any buffered messages.
+ */
+function __channelStream__<T>(
+	invoke: (channel: TAURI_CHANNEL<T>) => Promise<unknown>,
+): AsyncIterable<T> {
+	return {
+		[Symbol.asyncIterator]() {
+			const channel = new TAURI_CHANNEL<T>();
+			const buffer: T[] = [];
+			let state: { done: boolean; error?: { value: unknown } } = { done: false };
+			let wake = () => {};
+
+			channel.onmessage = (message) => {
+				buffer.push(message);
+				wake();
+			};
+			invoke(channel).then(
+				() => {
+					if (!state.done) state = { done: true };
+					wake();
+				},
+				(value) => {
+					if (!state.done) state = { done: true, error: { value } };
+					wake();
+				},
+			);
+
+			return {
+				async next(): Promise<IteratorResult<T, undefined>> {
+					while (buffer.length === 0 && !state.done)
+						await new Promise<void>((resolve) => (wake = resolve));
+
+					if (buffer.length > 0) return { done: false, value: buffer.shift() as T };
+					if (state.error) {
+						const { value } = state.error;
+						state = { done: true };
+						throw value;
+					}
+					return { done: true, value: undefined };
+				},
+				async return(): Promise<IteratorResult<T, undefined>> {
+					channel.onmessage = () => {};
+					buffer.length = 0;
+					state = { done: true };
+					wake();
+					return { done: true, value: undefined };
+				},
+			};
+		},
+	};
+}
+
 export type Result<T, E> =
 	| { status: "ok"; data: T }
 	| { status: "error"; error: E };
 
 function __makeEvents__<T extends Record<string, any>>(
 	mappings: Record<keyof T, string>,
+	windows?: Partial<Record<keyof T, string>>,
 ) {
 	return new Proxy(
 		{} as unknown as {
@@ -33,21 +150,35 @@ function __makeEvents__<T extends Record<string, any>>(
 		{
 			get: (_, event) => {
 				const name = mappings[event as keyof T];
+				const label = windows?.[event as keyof T];
 
 				return new Proxy((() => {}) as any, {
 					apply: (_, __, [window]: [__WebviewWindow__]) => ({
-						listen: (arg: any) => window.listen(name, arg),
-						once: (arg: any) => window.once(name, arg),
+						listen: (arg: any, options?: __ListenOptions__) =>
+							__listen__(() => window.listen(name, arg), options),
+						once: (arg: any, options?: __ListenOptions__) =>
+							__listen__(() => window.once(name, arg), options),
 						emit: (arg: any) => window.emit(name, arg),
+						emitTo: (target: any, arg: any) => window.emitTo(target, name, arg),
 					}),
 					get: (_, command: keyof __EventObj__<any>) => {
 						switch (command) {
 							case "listen":
-								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+								return (arg: any, options?: __ListenOptions__) =>
+									__listen__(
+										() => TAURI_EVENT.listen(name, arg, __listenTarget__(options, label)),
+										options,
+									);
 							case "once":
-								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+								return (arg: any, options?: __ListenOptions__) =>
+									__listen__(
+										() => TAURI_EVENT.once(name, arg, __listenTarget__(options, label)),
+										options,
+									);
 							case "emit":
-								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+								return (arg: any) => TAURI_EVENT.emit(name, arg);
+							case "emitTo":
+								return (target: any, arg: any) => TAURI_EVENT.emitTo(target, name, arg);
 						}
 					},
 				});
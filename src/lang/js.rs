@@ -1,8 +1,10 @@
+use std::borrow::Cow;
+
 use heck::ToLowerCamelCase;
-use specta::datatype::FunctionResultVariant;
+use specta::datatype::DataType;
 use specta_typescript::{js_doc, ExportError, Typescript};
 
-use crate::{ExportContext, LanguageExt};
+use crate::{ExportContext, LanguageExt, ModuleFormat};
 
 use super::js_ts;
 
@@ -10,37 +12,124 @@ const GLOBALS: &str = include_str!("./globals.js");
 
 impl LanguageExt for specta_jsdoc::JSDoc {
     fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        let cfg = &*js_ts::affix_types(cfg);
         let dependant_types = cfg
             .type_map
             .iter()
-            .map(|(_sid, ndt)| js_doc::typedef_named_datatype(&self.0, ndt, &cfg.type_map))
+            .map(|(sid, ndt)| match cfg.type_overrides.get(&sid) {
+                Some(ty) => Ok(format!("/** @typedef {{{ty}}} {} */", ndt.name())),
+                None => js_doc::typedef_named_datatype(&self.0, ndt, &cfg.type_map),
+            })
+            .chain(
+                cfg.type_aliases
+                    .iter()
+                    .map(|(name, ty)| Ok(format!("/** @typedef {{{ty}}} {name} */"))),
+            )
             .collect::<Result<Vec<_>, _>>()
             .map(|v| v.join("\n"))?;
 
-        js_ts::render_all_parts::<Self>(
-            cfg,
+        js_ts::render_all_parts(
             &dependant_types,
-            GLOBALS,
+            &format!(
+                "{}\n\n{}\n{}",
+                imports(cfg),
+                js_ts::globals(GLOBALS, cfg),
+                exports(cfg)
+            ),
             &self.0.header,
             render_commands(&self.0, cfg)?,
-            render_events(&self.0, cfg)?,
-            false,
+            render_events(&self.0, cfg)? + &render_namespaces(&self.0, cfg)?,
+            js_ts::constants(cfg, &self.0, false)?,
         )
     }
 }
 
+/// The `export` keyword of top-level declarations, which CommonJS exports with `module.exports` instead.
+fn export(cfg: &ExportContext) -> &'static str {
+    match cfg.module_format {
+        ModuleFormat::CommonJs => "",
+        _ => "export ",
+    }
+}
+
+/// Import `invoke`, `Channel` and the event API used by the globals.
+fn imports(cfg: &ExportContext) -> String {
+    let api = js_ts::tauri_api(cfg);
+
+    match cfg.module_format {
+        ModuleFormat::CommonJs => format!(
+            r#"const {{
+	invoke: __TAURI_INVOKE__,
+	Channel: TAURI_CHANNEL,
+}} = require("{api}/core");
+const TAURI_API_EVENT = require("{api}/event");"#
+        ),
+        _ => format!(
+            r#"import {{
+	invoke as __TAURI_INVOKE__,
+	Channel as TAURI_CHANNEL,
+}} from "{api}/core";
+import * as TAURI_API_EVENT from "{api}/event";"#
+        ),
+    }
+}
+
+/// Export the globals' setters, along with every other top-level declaration for CommonJS.
+fn exports(cfg: &ExportContext) -> String {
+    let setters = ["__setInvokeHandler", "__setEventHandlers"].map(String::from);
+
+    match cfg.module_format {
+        ModuleFormat::CommonJs => {
+            let names = std::iter::once("commands".to_string())
+                .chain((!js_ts::namespace_events(cfg, &[]).is_empty()).then(|| "events".into()))
+                .chain(
+                    js_ts::namespace_children(cfg, &[])
+                        .into_iter()
+                        .map(|name| name.to_lower_camel_case()),
+                )
+                .chain(js_ts::constant_names(cfg))
+                .chain(setters)
+                .collect::<Vec<_>>();
+
+            format!("module.exports = {{ {} }};\n", names.join(", "))
+        }
+        _ => format!("export {{ {} }};\n", setters.join(", ")),
+    }
+}
+
 fn render_commands(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
-    let commands = cfg
-        .commands
-        .iter()
+    let commands = command_methods(ts, cfg, &[])?;
+
+    Ok(format!(
+        r#"{}const commands = {{
+        {commands}
+    }}"#,
+        export(cfg)
+    ))
+}
+
+fn command_methods(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    Ok(js_ts::namespace_commands(cfg, path)
         .map(|function| {
-            let jsdoc = {
-                let ret_type =
-                    js_ts::handle_result(function, &cfg.type_map, ts, cfg.error_handling)?;
+            let datatype = |typ: &DataType| js_ts::datatype(ts, typ, &cfg.type_map);
+            let stream = js_ts::stream_channel(cfg, function);
+            // The stream variant takes every argument except the channel, which it creates itself.
+            let jsdoc = |stream_item: Option<&str>| -> Result<String, ExportError> {
+                let ret_type = match stream_item {
+                    Some(item) => format!("AsyncIterable<{item}>"),
+                    None => format!(
+                        "Promise<{}>",
+                        js_ts::handle_result(function, &cfg.type_map, ts, cfg.error_handling)?
+                    ),
+                };
 
                 let mut builder = js_doc::Builder::default();
 
-                if let Some(d) = function.deprecated() {
+                if let Some(d) = js_ts::command_deprecated(cfg, function) {
                     builder.push_deprecated(d);
                 }
 
@@ -48,49 +137,73 @@ fn render_commands(ts: &Typescript, cfg: &ExportContext) -> Result<String, Expor
                     builder.extend(function.docs().split("\n"));
                 }
 
-                builder.extend(function.args().flat_map(|(name, typ)| {
-                    specta_typescript::datatype(
-                        ts,
-                        &FunctionResultVariant::Value(typ.clone()),
-                        &cfg.type_map,
-                    )
-                    .map(|typ| {
+                let args = function.args().count() - usize::from(stream_item.is_some());
+                builder.extend(function.args().take(args).flat_map(|(name, typ)| {
+                    datatype(typ).map(|typ| {
                         let name = name.to_lower_camel_case();
 
                         format!("@param {{ {typ} }} {name}")
                     })
                 }));
-                builder.push(&format!("@returns {{ Promise<{ret_type}> }}"));
+                if cfg.invoke_options {
+                    builder.push(&format!(
+                        r#"@param {{ import("{}/core").InvokeOptions }} [options]"#,
+                        js_ts::tauri_api(cfg)
+                    ));
+                }
+                builder.push(&format!("@returns {{ {ret_type} }}"));
+
+                if let Some(throws) =
+                    js_ts::throws(function, &cfg.type_map, ts, cfg.error_handling)?
+                {
+                    builder.push(&throws);
+                }
+
+                if let Some(permission) = js_ts::required_permission(cfg, function) {
+                    builder.push(&permission);
+                }
 
-                builder.build()
+                Ok(builder.build())
             };
 
-            Ok(js_ts::function(
-                &jsdoc,
-                &function.name().to_lower_camel_case(),
-                // TODO: Don't `collect` the whole thing
-                &js_ts::arg_names(&function.args().cloned().collect::<Vec<_>>()),
+            // TODO: Don't `collect` the whole thing
+            let mut arg_names = js_ts::arg_names(&function.args().cloned().collect::<Vec<_>>())
+                .into_iter()
+                .chain(cfg.invoke_options.then(|| "options".to_string()))
+                .collect::<Vec<_>>();
+
+            let mut methods = vec![js_ts::function(
+                &jsdoc(None)?,
+                &cfg.command_ident(function.name()),
+                &arg_names,
                 None,
-                &js_ts::command_body(&cfg.plugin_name, &function, false, cfg.error_handling),
-            ))
+                &js_ts::command_body(cfg, function, false),
+            )];
+            if let Some((_, item)) = stream {
+                arg_names.remove(function.args().count() - 1);
+                methods.push(js_ts::stream_function(
+                    cfg,
+                    &jsdoc(Some(&datatype(item)?))?,
+                    function,
+                    &arg_names,
+                    None,
+                ));
+            }
+
+            Ok(methods.join(",\n"))
         })
         .collect::<Result<Vec<_>, ExportError>>()?
-        .join(",\n");
-
-    Ok(format!(
-        r#"export const commands = {{
-        {commands}
-    }}"#
-    ))
+        .join(",\n"))
 }
 
 fn render_events(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
-    if cfg.events.is_empty() {
+    let events = js_ts::namespace_events(cfg, &[]);
+    if events.is_empty() {
         return Ok(Default::default());
     }
 
-    let (events_types, events_map) =
-        js_ts::events_data(&cfg.events, ts, &cfg.plugin_name, &cfg.type_map)?;
+    let (events_types, events_map) = js_ts::events_data(&events, ts, cfg)?;
+    let windows = js_ts::event_windows_arg(cfg, &events);
 
     let events = {
         let mut builder = js_doc::Builder::default();
@@ -107,8 +220,62 @@ fn render_events(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportE
     {events}
     const __typedMakeEvents__ = __makeEvents__;
 
-    export const events = __typedMakeEvents__({{
+    {export}const events = __typedMakeEvents__({{
     {events_map}
-    }})"#
+    }}{windows})"#,
+        export = export(cfg)
     })
 }
+
+fn render_namespaces(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    js_ts::namespace_children(cfg, &[])
+        .into_iter()
+        .map(|name| {
+            Ok(format!(
+                "\n\n{}const {} = {};",
+                export(cfg),
+                name.to_lower_camel_case(),
+                namespace_object(ts, cfg, std::slice::from_ref(name))?
+            ))
+        })
+        .collect()
+}
+
+/// Javascript has no namespaces so they are exported as an object containing their commands, events and nested namespaces.
+fn namespace_object(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    let mut properties = Vec::new();
+
+    if js_ts::namespace_commands(cfg, path).next().is_some() {
+        properties.push(format!(
+            "commands: {{\n{}\n}}",
+            command_methods(ts, cfg, path)?
+        ));
+    }
+
+    let events = js_ts::namespace_events(cfg, path);
+    if !events.is_empty() {
+        let (events_types, events_map) = js_ts::events_data(&events, ts, cfg)?;
+
+        properties.push(format!(
+            "events: /** @type {{typeof __makeEvents__<{{ {} }}>}} */ (__makeEvents__)({{\n{events_map}\n}}{})",
+            events_types.join(", "),
+            js_ts::event_windows_arg(cfg, &events)
+        ));
+    }
+
+    for name in js_ts::namespace_children(cfg, path) {
+        let path = [path, std::slice::from_ref(name)].concat();
+
+        properties.push(format!(
+            "{}: {}",
+            name.to_lower_camel_case(),
+            namespace_object(ts, cfg, &path)?
+        ));
+    }
+
+    Ok(format!("{{\n{}\n}}", properties.join(",\n")))
+}
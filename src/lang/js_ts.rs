@@ -2,35 +2,394 @@
 //!
 //! Typescript is a superset of Javascript so they share a lot of logic.
 
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
 
 use heck::ToLowerCamelCase;
 use specta::{
-    datatype::{self, DataType, FunctionResultVariant},
-    TypeMap,
+    datatype::{
+        self, DataType, DeprecatedType, EnumRepr, EnumType, EnumVariants, FunctionResultVariant,
+        LiteralType, NamedDataType, PrimitiveType, StructFields,
+    },
+    internal::construct,
+    SpectaID, TypeMap,
 };
 use specta_typescript::{self as ts};
-use specta_typescript::{ExportError, Typescript};
+use specta_typescript::{js_doc, BigIntExportBehavior, ExportError, ExportPath, Typescript};
 
-use crate::{apply_as_prefix, ErrorHandlingMode, ExportContext, ItemType, LanguageExt};
+use crate::{
+    apply_as_prefix,
+    type_graph::{self, Position},
+    Constant, ErrorHandlingMode, ExportContext, ItemType, ModuleFormat,
+};
 
-const DO_NOT_EDIT: &str = "// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.";
+pub(crate) use super::DO_NOT_EDIT;
 
-pub fn render_all_parts<L: LanguageExt>(
-    cfg: &ExportContext,
+pub fn render_all_parts(
     dependant_types: &str,
     globals: &str,
     header: &str,
     commands: String,
     events: String,
-    as_const: bool,
-) -> Result<String, L::Error> {
+    constants: String,
+) -> Result<String, ExportError> {
+    Ok(format! {
+        r#"{header}
+{DO_NOT_EDIT}
+
+/** user-defined commands **/
+
+{commands}
+
+/** user-defined events **/
+
+{events}
+
+/** user-defined constants **/
+
+{constants}
+
+/** user-defined types **/
+
+{dependant_types}
+
+/** tauri-specta globals **/
+
+{globals}"#
+    })
+}
+
+/// Export a named type, replacing its definition if it was overridden with [`Builder::override_type`](crate::Builder::override_type).
+pub fn named_datatype(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    sid: SpectaID,
+    ndt: &NamedDataType,
+) -> Result<String, ExportError> {
+    match (cfg.type_overrides.get(&sid), &ndt.inner) {
+        (Some(ty), _) => Ok(format!("export type {} = {ty};", ndt.name())),
+        (None, DataType::Enum(e)) if matches!(e.repr(), EnumRepr::Internal { .. }) => {
+            let mut ndt = ndt.clone();
+            ndt.inner = discriminated_union(sid, e);
+            export_named_datatype(ts, cfg, &ndt)
+        }
+        (None, _) => export_named_datatype(ts, cfg, ndt),
+    }
+}
+
+/// Export a named type with `specta_typescript`, which validates it first.
+///
+/// `specta_typescript` validates the type behind an `Option` with a fresh set of visited types,
+/// so it would recurse forever on a type which reaches itself through an `Option` (Eg. `parent: Option<Box<TreeNode>>`).
+/// The types it would reach deeper than [`Builder::max_recursion_depth`](crate::Builder::max_recursion_depth) are validated as `unknown` instead.
+/// They're still exported by reference, so the output is the same.
+fn export_named_datatype(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    ndt: &NamedDataType,
+) -> Result<String, ExportError> {
+    let truncated = truncated_types(&ndt.inner, &cfg.type_map, cfg.max_recursion_depth);
+    if truncated.is_empty() {
+        return ts::export_named_datatype(ts, ndt, &cfg.type_map);
+    }
+
+    #[cfg(feature = "log")]
+    log::warn!(
+        "Tauri Specta didn't validate {} within `{}` as they're nested deeper than `Builder::max_recursion_depth` ({})",
+        truncated
+            .iter()
+            .filter_map(|sid| cfg.type_map.get(*sid))
+            .map(|ndt| format!("`{}`", ndt.name()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        ndt.name(),
+        cfg.max_recursion_depth
+    );
+
+    let mut type_map = cfg.type_map.clone();
+    for sid in truncated {
+        if let Some(mut ndt) = cfg.type_map.get(sid).cloned() {
+            ndt.inner = DataType::Unknown;
+            type_map.insert(sid, ndt);
+        }
+    }
+    ts::export_named_datatype(ts, ndt, &type_map)
+}
+
+/// The named types `specta_typescript` would reach deeper than `max_depth` named types while validating `ty`.
+fn truncated_types(ty: &DataType, type_map: &TypeMap, max_depth: u32) -> BTreeSet<SpectaID> {
+    let mut truncated = BTreeSet::new();
+    // Truncating a type changes which types are visited, so validate again until nothing else is truncated.
+    loop {
+        let len = truncated.len();
+        validate_references(
+            type_map,
+            type_graph::references(ty),
+            &mut BTreeSet::new(),
+            0,
+            max_depth,
+            &mut truncated,
+        );
+        if truncated.len() == len {
+            return truncated;
+        }
+    }
+}
+
+/// Follow the references within a type like `specta_typescript` validates them,
+/// which skips lists and forgets the types it has visited behind an `Option`.
+fn validate_references(
+    type_map: &TypeMap,
+    references: Vec<(SpectaID, Position)>,
+    visited: &mut BTreeSet<SpectaID>,
+    depth: u32,
+    max_depth: u32,
+    truncated: &mut BTreeSet<SpectaID>,
+) {
+    for (sid, position) in references {
+        if position.in_list || truncated.contains(&sid) {
+            continue;
+        }
+
+        let mut forgotten = BTreeSet::new();
+        let visited = match position.nullable {
+            true => &mut forgotten,
+            false => &mut *visited,
+        };
+        if !visited.insert(sid) {
+            continue;
+        }
+        if depth >= max_depth {
+            truncated.insert(sid);
+            continue;
+        }
+
+        if let Some(ndt) = type_map.get(sid) {
+            validate_references(
+                type_map,
+                type_graph::references(&ndt.inner),
+                visited,
+                depth + 1,
+                max_depth,
+                truncated,
+            );
+        }
+    }
+}
+
+/// Apply [`Builder::type_prefix`](crate::Builder::type_prefix) and [`Builder::type_suffix`](crate::Builder::type_suffix) to the names of the exported types.
+///
+/// References within the type map are renamed too, while the references held by commands, events and constants are resolved as they're rendered by [`datatype`].
+pub fn affix_types(cfg: &ExportContext) -> Cow<'_, ExportContext> {
+    if cfg.type_prefix.is_empty() && cfg.type_suffix.is_empty() {
+        return Cow::Borrowed(cfg);
+    }
+
+    let mut type_map = TypeMap::default();
+    for (sid, ndt) in cfg.type_map.iter() {
+        let ndt = match ndt.ext() {
+            Some(ext) => construct::named_data_type(
+                format!("{}{}{}", cfg.type_prefix, ndt.name(), cfg.type_suffix).into(),
+                ndt.docs().clone(),
+                ndt.deprecated().cloned(),
+                sid,
+                *ext.impl_location(),
+                ndt.inner.clone(),
+            ),
+            None => ndt.clone(),
+        };
+        type_map.insert(sid, ndt);
+    }
+    // The references are resolved once every type has been renamed.
+    for (sid, ndt) in cfg.type_map.iter() {
+        if let Cow::Owned(inner) = resolve_names(&ndt.inner, &type_map) {
+            if let Some(mut ndt) = type_map.get(sid).cloned() {
+                ndt.inner = inner;
+                type_map.insert(sid, ndt);
+            }
+        }
+    }
+
+    let mut cfg = cfg.clone();
+    cfg.type_map = type_map;
+    Cow::Owned(cfg)
+}
+
+/// Render a type, naming every type it references as it's named in `type_map`.
+pub fn datatype(ts: &Typescript, ty: &DataType, type_map: &TypeMap) -> Result<String, ExportError> {
+    ts::datatype(
+        ts,
+        &FunctionResultVariant::Value(resolve_names(ty, type_map).into_owned()),
+        type_map,
+    )
+}
+
+/// Rename every reference within `ty` to the name of the type it references in `type_map`.
+pub fn resolve_names<'a>(ty: &'a DataType, type_map: &TypeMap) -> Cow<'a, DataType> {
+    let renamed = type_graph::rename_references(ty, &mut |reference| {
+        type_map
+            .get(reference.sid())
+            .map(|ndt| ndt.name())
+            .filter(|name| *name != reference.name())
+            .cloned()
+    });
+
+    match renamed {
+        Some(ty) => Cow::Owned(ty),
+        None => Cow::Borrowed(ty),
+    }
+}
+
+/// Rewrite an internally tagged enum as an untagged union of objects which each hold their tag as a string literal.
+///
+/// Unlike `specta_typescript`, flattened fields are intersected with the variant and newtype variants may hold any type which serde merges the tag into,
+/// Eg. `{ type: "Click"; x: number } | (Position) & { type: "Moved" }`.
+fn discriminated_union(sid: SpectaID, e: &EnumType) -> DataType {
+    let EnumRepr::Internal { tag } = e.repr() else {
+        return DataType::Enum(e.clone());
+    };
+
+    let variants = e
+        .variants()
+        .iter()
+        .map(|(name, variant)| {
+            let tag_field = (
+                tag.clone(),
+                construct::field(
+                    false,
+                    false,
+                    None,
+                    Cow::Borrowed(""),
+                    Some(DataType::Literal(LiteralType::String(name.to_string()))),
+                ),
+            );
+
+            let fields = match variant.inner() {
+                EnumVariants::Unit => vec![tag_field],
+                EnumVariants::Named(fields) => [tag_field]
+                    .into_iter()
+                    .chain(fields.fields().iter().cloned())
+                    .collect(),
+                // serde merges the tag into the newtype, so it's flattened.
+                EnumVariants::Unnamed(fields) => [tag_field]
+                    .into_iter()
+                    .chain(
+                        fields
+                            .fields()
+                            .iter()
+                            .filter(|field| {
+                                !matches!(field.ty(), Some(DataType::Tuple(t)) if t.elements().is_empty())
+                            })
+                            .map(|field| {
+                                (
+                                    Cow::Borrowed("value"),
+                                    construct::field(
+                                        false,
+                                        true,
+                                        field.deprecated().cloned(),
+                                        field.docs().clone(),
+                                        field.ty().cloned(),
+                                    ),
+                                )
+                            }),
+                    )
+                    .collect(),
+            };
+
+            let object = construct::r#struct(
+                name.clone(),
+                None,
+                vec![],
+                construct::struct_named(fields, None),
+            );
+
+            (
+                name.clone(),
+                construct::enum_variant(
+                    variant.skip(),
+                    variant.deprecated().cloned(),
+                    variant.docs().clone(),
+                    construct::enum_variant_unnamed(vec![construct::field(
+                        false,
+                        false,
+                        None,
+                        Cow::Borrowed(""),
+                        Some(DataType::Struct(object)),
+                    )]),
+                ),
+            )
+        })
+        .collect();
+
+    DataType::Enum(construct::r#enum(
+        e.name().clone(),
+        sid,
+        EnumRepr::Untagged,
+        e.skip_bigint_checks(),
+        e.generics().clone(),
+        variants,
+    ))
+}
+
+/// The import statements required by the types overridden with [`Builder::override_type_with_import`](crate::Builder::override_type_with_import).
+pub fn type_imports(cfg: &ExportContext) -> impl Iterator<Item = String> + '_ {
+    cfg.type_imports
+        .values()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|import| import.to_string())
+}
+
+/// The type aliases registered with [`Builder::type_alias`](crate::Builder::type_alias) as Typescript declarations.
+pub fn type_aliases(cfg: &ExportContext) -> impl Iterator<Item = String> + '_ {
+    cfg.type_aliases
+        .iter()
+        .map(|(name, ty)| format!("export type {name} = {ty};"))
+}
+
+/// The module specifier which `@tauri-apps/api` is imported from.
+pub fn tauri_api(cfg: &ExportContext) -> Cow<'static, str> {
+    match (&cfg.tauri_api_path, cfg.module_format) {
+        (Some(path), _) => path.clone(),
+        // Deno requires npm packages to be imported using the `npm:` specifier.
+        (None, ModuleFormat::Deno) => "npm:@tauri-apps/api".into(),
+        (None, ModuleFormat::Auto | ModuleFormat::Esm | ModuleFormat::CommonJs) => {
+            "@tauri-apps/api".into()
+        }
+    }
+}
+
+/// The globals with their imports adjusted for the [`ModuleFormat`] and [`Builder::tauri_api_path`](crate::Builder::tauri_api_path).
+pub fn globals<'a>(globals: &'a str, cfg: &ExportContext) -> Cow<'a, str> {
+    match tauri_api(cfg) {
+        path if path == "@tauri-apps/api" => globals.into(),
+        path => globals
+            .replace("\"@tauri-apps/api/", &format!("\"{path}/"))
+            .into(),
+    }
+}
+
+pub fn constants(
+    cfg: &ExportContext,
+    ts: &Typescript,
+    // `true` when exporting Typescript, which supports `as const` and type annotations.
+    typescript: bool,
+) -> Result<String, ExportError> {
+    // CommonJS exports them with `module.exports` instead.
+    let export = match !typescript && cfg.module_format == ModuleFormat::CommonJs {
+        true => "",
+        false => "export ",
+    };
     let constants = cfg
         .constants
         .iter()
-        .map(|(name, value)| {
+        .map(|(name, constant)| {
+            let value = &constant.value;
+            let annotated = typescript && is_annotated(constant);
             let mut as_constt = None;
-            if as_const {
+            if typescript && !annotated {
                 match &value {
                     serde_json::Value::Null => {}
                     serde_json::Value::Bool(_)
@@ -41,39 +400,244 @@ pub fn render_all_parts<L: LanguageExt>(
                 }
             }
 
-            format!(
-                "export const {name} = {}{};",
-                serde_json::to_string(&value)
-                    .expect("failed to serialize from `serde_json::Value`"),
+            let annotation = match annotated {
+                true => format!(": {}", datatype(ts, &constant.ty, &cfg.type_map)?),
+                _ => String::new(),
+            };
+
+            Ok(format!(
+                "{export}const {name}{annotation} = {}{};",
+                constant_value(ts, &cfg.type_map, name, value, Some(&constant.ty))?,
                 as_constt.unwrap_or("")
-            )
+            ))
+        })
+        .collect::<Result<Vec<_>, ExportError>>()?;
+
+    // The whole group is `as const` so annotated constants are asserted to their type instead.
+    let groups = cfg
+        .constant_groups
+        .iter()
+        .map(|(group, constants)| {
+            let members = constants
+                .iter()
+                .map(|(name, constant)| {
+                    let value = constant_value(
+                        ts,
+                        &cfg.type_map,
+                        &format!("{group}.{name}"),
+                        &constant.value,
+                        Some(&constant.ty),
+                    )?;
+                    let assertion = match typescript && is_annotated(constant) {
+                        true => format!(" as {}", datatype(ts, &constant.ty, &cfg.type_map)?),
+                        false => String::new(),
+                    };
+
+                    Ok(format!(
+                        "{}: {value}{assertion}",
+                        name.to_lower_camel_case()
+                    ))
+                })
+                .collect::<Result<Vec<_>, ExportError>>()?
+                .join(", ");
+
+            Ok(format!(
+                "{export}const {} = {{ {members} }}{};",
+                group.to_lower_camel_case(),
+                if typescript { " as const" } else { "" }
+            ))
         })
+        .collect::<Result<Vec<_>, ExportError>>()?;
+
+    let enum_variants = enum_variants(cfg).into_iter().map(|(name, variants)| {
+        format!(
+            "{export}const {name} = [{}]{};",
+            variants.join(", "),
+            if typescript { " as const" } else { "" }
+        )
+    });
+
+    Ok(constants
+        .into_iter()
+        .chain(groups)
+        .chain(enum_variants)
         .collect::<Vec<_>>()
-        .join("\n");
+        .join("\n"))
+}
 
-    Ok(format! {
-        r#"{header}
-{DO_NOT_EDIT}
+/// The names of the declarations rendered by [`constants`], in the same order.
+#[cfg(feature = "javascript")]
+pub fn constant_names(cfg: &ExportContext) -> impl Iterator<Item = String> + '_ {
+    cfg.constants
+        .keys()
+        .map(|name| name.to_string())
+        .chain(
+            cfg.constant_groups
+                .keys()
+                .map(|group| group.to_lower_camel_case()),
+        )
+        .chain(enum_variants(cfg).into_iter().map(|(name, _)| name))
+}
 
-/** user-defined commands **/
+/// The arrays of variant names for the enums registered with [`Builder::enum_variants`](crate::Builder::enum_variants).
+///
+/// Returns the name of each array and the variant names as string literals.
+pub fn enum_variants(cfg: &ExportContext) -> Vec<(String, Vec<String>)> {
+    cfg.enum_variants
+        .iter()
+        .filter_map(|sid| cfg.type_map.get(*sid))
+        .filter_map(|ndt| match &ndt.inner {
+            DataType::Enum(e) => Some((
+                format!("{}Variants", ndt.name()),
+                e.variants()
+                    .iter()
+                    .filter(|(_, variant)| !variant.skip())
+                    .map(|(name, _)| {
+                        serde_json::to_string(name).expect("failed to serialize string")
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
 
-{commands}
+/// Whether a constant is typed using its Rust type instead of the inferred one.
+///
+/// This is the case for widened constants and for constants of a named type (Eg. `Settings`, `Vec<Settings>` or `Option<Settings>`),
+/// so the constant can't drift from the type it's exported alongside.
+pub fn is_annotated(constant: &Constant) -> bool {
+    fn is_named(typ: &DataType) -> bool {
+        match typ {
+            DataType::Reference(_) => true,
+            DataType::List(list) => is_named(list.ty()),
+            DataType::Nullable(typ) => is_named(typ),
+            _ => false,
+        }
+    }
 
-/** user-defined events **/
+    !constant.as_const || is_named(&constant.ty)
+}
 
-{events}
+/// The largest integer a Javascript `number` can represent without losing precision.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
 
-/** user-defined constants **/
+/// Render the value of a constant as a Javascript expression.
+///
+/// The value is walked alongside its type so 64-bit integers follow the configured [`BigIntExportBehavior`].
+/// Where the type is unknown, only integers which would lose precision as a `number` are treated as BigInt's.
+fn constant_value(
+    ts: &Typescript,
+    type_map: &TypeMap,
+    path: &str,
+    value: &serde_json::Value,
+    typ: Option<&DataType>,
+) -> Result<String, ExportError> {
+    use serde_json::Value;
 
-{constants}
+    let typ = match typ {
+        Some(DataType::Reference(r)) => type_map.get(r.sid()).map(|ndt| &ndt.inner),
+        Some(DataType::Nullable(t)) => Some(&**t),
+        typ => typ,
+    };
 
-/** user-defined types **/
+    Ok(match (value, typ) {
+        (Value::Number(n), Some(DataType::Primitive(p))) => bigint(ts, path, n, is_bigint(p))?,
+        (Value::Number(n), _) => bigint(ts, path, n, false)?,
+        (Value::Array(items), typ) => {
+            let items = items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let ty = match typ {
+                        Some(DataType::List(list)) => Some(list.ty()),
+                        Some(DataType::Tuple(tuple)) => tuple.elements().get(i),
+                        _ => None,
+                    };
 
-{dependant_types}
+                    constant_value(ts, type_map, &format!("{path}[{i}]"), item, ty)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
 
-/** tauri-specta globals **/
+            format!("[{}]", items.join(","))
+        }
+        (Value::Object(map), typ) => {
+            let fields = map
+                .iter()
+                .map(|(key, item)| {
+                    let ty = match typ {
+                        Some(DataType::Map(map)) => Some(map.value_ty()),
+                        Some(DataType::Struct(s)) => match s.fields() {
+                            StructFields::Named(fields) => fields
+                                .fields()
+                                .iter()
+                                .find(|(name, _)| name == key)
+                                .and_then(|(_, field)| field.ty()),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
 
-{globals}"#
+                    Ok(format!(
+                        "{}:{}",
+                        serde_json::to_string(key).expect("failed to serialize string"),
+                        constant_value(ts, type_map, &format!("{path}.{key}"), item, ty)?
+                    ))
+                })
+                .collect::<Result<Vec<_>, ExportError>>()?;
+
+            format!("{{{}}}", fields.join(","))
+        }
+        (value, _) => {
+            serde_json::to_string(value).expect("failed to serialize from `serde_json::Value`")
+        }
+    })
+}
+
+fn is_bigint(p: &PrimitiveType) -> bool {
+    matches!(
+        p,
+        PrimitiveType::usize
+            | PrimitiveType::isize
+            | PrimitiveType::i64
+            | PrimitiveType::u64
+            | PrimitiveType::i128
+            | PrimitiveType::u128
+    )
+}
+
+/// Render a number, following the [`BigIntExportBehavior`] if it's typed as a BigInt or would lose precision as a `number`.
+fn bigint(
+    ts: &Typescript,
+    path: &str,
+    n: &serde_json::Number,
+    typed: bool,
+) -> Result<String, ExportError> {
+    let safe = match (n.as_u64(), n.as_i64()) {
+        (Some(n), _) => n <= MAX_SAFE_INTEGER,
+        (None, Some(n)) => n.unsigned_abs() <= MAX_SAFE_INTEGER,
+        // Floats are always a `number`.
+        (None, None) => return Ok(n.to_string()),
+    };
+    if safe && !typed {
+        return Ok(n.to_string());
+    }
+
+    Ok(match ts.bigint {
+        BigIntExportBehavior::BigInt => format!("{n}n"),
+        BigIntExportBehavior::String => format!("\"{n}\""),
+        BigIntExportBehavior::Number => n.to_string(),
+        BigIntExportBehavior::Fail if safe => n.to_string(),
+        BigIntExportBehavior::Fail => {
+            return Err(ExportError::BigIntForbidden(ExportPath::new_unsafe(path)))
+        }
+        BigIntExportBehavior::FailWithReason(_) if safe => n.to_string(),
+        BigIntExportBehavior::FailWithReason(reason) => {
+            return Err(ExportError::Other(
+                ExportPath::new_unsafe(path),
+                reason.to_string(),
+            ))
+        }
     })
 }
 
@@ -137,8 +701,13 @@ pub fn function(
     )
 }
 
-fn tauri_invoke(name: &str, arg_usages: Option<String>) -> String {
-    let arg_usages = arg_usages.map(|u| format!(", {u}")).unwrap_or_default();
+fn tauri_invoke(name: &str, arg_usages: Option<String>, invoke_options: bool) -> String {
+    let arg_usages = match (arg_usages, invoke_options) {
+        (Some(u), true) => format!(", {u}, options"),
+        (None, true) => ", {}, options".to_string(),
+        (Some(u), false) => format!(", {u}"),
+        (None, false) => String::new(),
+    };
 
     format!(r#"await TAURI_INVOKE("{name}"{arg_usages})"#)
 }
@@ -154,30 +723,38 @@ pub fn handle_result(
             ErrorHandlingMode::Result => {
                 format!(
                     "Result<{}, {}>",
-                    ts::datatype(cfg, &FunctionResultVariant::Value(t.clone()), type_map)?,
-                    ts::datatype(cfg, &FunctionResultVariant::Value(e.clone()), type_map)?
+                    datatype(cfg, t, type_map)?,
+                    datatype(cfg, e, type_map)?
                 )
             }
-            ErrorHandlingMode::Throw => {
-                ts::datatype(cfg, &FunctionResultVariant::Value(t.clone()), type_map)?
-            }
+            ErrorHandlingMode::Throw => datatype(cfg, t, type_map)?,
         },
-        Some(FunctionResultVariant::Value(t)) => {
-            ts::datatype(cfg, &FunctionResultVariant::Value(t.clone()), type_map)?
-        }
+        Some(FunctionResultVariant::Value(t)) => datatype(cfg, t, type_map)?,
         None => "void".to_string(),
     })
 }
 
-pub fn command_body(
-    plugin_name: &Option<&'static str>,
+/// The `@throws` JSDoc tag for commands which reject with a typed error.
+///
+/// With [`ErrorHandlingMode::Result`] the error is part of the return type instead.
+pub fn throws(
     function: &datatype::Function,
-    as_any: bool,
+    type_map: &TypeMap,
+    cfg: &Typescript,
     error_handling: ErrorHandlingMode,
-) -> String {
-    let name = plugin_name
-        .as_ref()
-        .map(|n| apply_as_prefix(&n, &function.name(), ItemType::Command))
+) -> Result<Option<String>, ExportError> {
+    Ok(match (function.result(), error_handling) {
+        (Some(FunctionResultVariant::Result(_, e)), ErrorHandlingMode::Throw) => {
+            Some(format!("@throws {{ {} }}", datatype(cfg, e, type_map)?))
+        }
+        _ => None,
+    })
+}
+
+pub fn command_body(cfg: &ExportContext, function: &datatype::Function, as_any: bool) -> String {
+    let name = cfg
+        .plugin_name
+        .map(|n| apply_as_prefix(n, function.name(), ItemType::Command))
         .unwrap_or_else(|| function.name().to_string());
 
     maybe_return_as_result_tuple(
@@ -187,25 +764,69 @@ pub fn command_body(
                 // TODO: Don't collect
                 &function.args().cloned().collect::<Vec<_>>(),
             )),
+            cfg.invoke_options,
         ),
         function.result(),
         as_any,
-        error_handling,
+        cfg.error_handling,
     )
 }
 
-pub fn events_map(
-    events: &BTreeMap<&'static str, DataType>,
-    plugin_name: &Option<&'static str>,
+/// The name and message type of a command's trailing `Channel` argument, if it gets a stream variant with [`Builder::channel_streams`](crate::Builder::channel_streams).
+pub fn stream_channel<'a>(
+    cfg: &ExportContext,
+    function: &'a datatype::Function,
+) -> Option<(String, &'a DataType)> {
+    if !cfg.channel_streams {
+        return None;
+    }
+
+    match function.args().last()? {
+        (name, DataType::Reference(r)) if r.sid() == crate::channel_sid() => r
+            .generics()
+            .first()
+            .map(|(_, ty)| (name.to_lower_camel_case(), ty)),
+        _ => None,
+    }
+}
+
+/// The stream variant of a command, which invokes it with a new channel and iterates over the channel's messages.
+///
+/// `args` excludes the channel, which is created by `__channelStream__`.
+pub fn stream_function(
+    cfg: &ExportContext,
+    docs: &str,
+    function: &datatype::Function,
+    args: &[String],
+    item_type: Option<&str>,
 ) -> String {
+    let name = cfg
+        .plugin_name
+        .map(|n| apply_as_prefix(n, function.name(), ItemType::Command))
+        .unwrap_or_else(|| function.name().to_string());
+    let arg_names = arg_names(&function.args().cloned().collect::<Vec<_>>());
+    let channel = arg_names.last().map(String::as_str).unwrap_or_default();
+    let invoke = tauri_invoke(&name, arg_usages(&arg_names), cfg.invoke_options);
+
+    let args = args.join(", ");
+    let return_type = item_type
+        .map(|t| format!(": AsyncIterable<{t}>"))
+        .unwrap_or_default();
+
+    format!(
+        r#"{docs}{}Stream({args}){return_type} {{
+    return __channelStream__(async ({channel}) => {invoke});
+}}"#,
+        cfg.command_ident(function.name())
+    )
+}
+
+pub fn events_map(events: &BTreeMap<&'static str, DataType>, cfg: &ExportContext) -> String {
     events
         .iter()
         .map(|(name, _)| {
-            let name_str = plugin_name
-                .as_ref()
-                .map(|n| apply_as_prefix(n, name, ItemType::Event))
-                .unwrap_or_else(|| name.to_string());
-            let name_camel = name.to_lower_camel_case();
+            let name_str = cfg.event_name(name);
+            let name_camel = cfg.event_ident(name);
 
             format!(r#"{name_camel}: "{name_str}""#)
         })
@@ -213,31 +834,288 @@ pub fn events_map(
         .join(",\n")
 }
 
+/// The `Commands` type, mapping the name each command is invoked with to its arguments, result and error.
+pub fn commands_type(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    let datatype = |typ: &DataType| datatype(ts, typ, &cfg.type_map);
+
+    let commands = cfg
+        .commands
+        .iter()
+        .map(|function| {
+            let name = cfg
+                .plugin_name
+                .map(|n| apply_as_prefix(n, function.name(), ItemType::Command))
+                .unwrap_or_else(|| function.name().to_string());
+            let args = function
+                .args()
+                .map(|(name, typ)| {
+                    Ok(format!(
+                        "{}: {}",
+                        name.to_lower_camel_case(),
+                        datatype(typ)?
+                    ))
+                })
+                .collect::<Result<Vec<_>, ExportError>>()?
+                .join(", ");
+            let (result, error) = match function.result() {
+                Some(FunctionResultVariant::Result(t, e)) => (datatype(t)?, datatype(e)?),
+                Some(FunctionResultVariant::Value(t)) => (datatype(t)?, "never".to_string()),
+                None => ("null".to_string(), "never".to_string()),
+            };
+
+            Ok(format!(
+                "\t\"{name}\": {{ args: [{args}]; result: {result}; error: {error} }};\n"
+            ))
+        })
+        .collect::<Result<String, ExportError>>()?;
+
+    Ok(format!("\nexport type Commands = {{\n{commands}}};\n"))
+}
+
+/// The `Events` type, mapping the name of every event to its payload.
+pub fn events_type(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    if cfg.events.is_empty() {
+        return Ok(Default::default());
+    }
+
+    let events = cfg
+        .events
+        .iter()
+        .map(|(name, typ)| {
+            let name = cfg.event_name(name);
+            let payload = datatype(ts, typ, &cfg.type_map)?;
+
+            Ok(format!("\t\"{name}\": {payload};\n"))
+        })
+        .collect::<Result<String, ExportError>>()?;
+
+    Ok(format!("\nexport type Events = {{\n{events}}};\n"))
+}
+
+/// The name of every event, keyed by the property it's accessed through on `events`.
+///
+/// Every `events` object maps its properties to these with [`event_name_refs`] so each name is only written once.
+pub fn event_names(cfg: &ExportContext) -> String {
+    if cfg.events.is_empty() {
+        return Default::default();
+    }
+
+    format! {
+        r#"
+export const EVENT_NAMES = {{
+{}
+}} as const;
+
+export type EventName = keyof typeof EVENT_NAMES;
+"#,
+        events_map(&cfg.events, cfg)
+    }
+}
+
+/// The argument passing the window each of `events` is targeted at to `__makeEvents__`, if any are.
+pub fn event_windows_arg(cfg: &ExportContext, events: &BTreeMap<&'static str, DataType>) -> String {
+    let windows = events
+        .keys()
+        .filter_map(|name| {
+            cfg.event_windows
+                .get(name)
+                .map(|window| format!("{}: {window:?}", cfg.event_ident(name)))
+        })
+        .collect::<Vec<_>>();
+
+    match windows.is_empty() {
+        true => String::new(),
+        false => format!(", {{\n{}\n}}", windows.join(",\n")),
+    }
+}
+
+/// The mappings of `events` to their names in `EVENT_NAMES`.
+pub fn event_name_refs(cfg: &ExportContext, events: &BTreeMap<&'static str, DataType>) -> String {
+    events
+        .keys()
+        .map(|name| {
+            let name_camel = cfg.event_ident(name);
+            format!("{name_camel}: EVENT_NAMES.{name_camel}")
+        })
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
 pub fn events_types(
     events: &BTreeMap<&'static str, DataType>,
-    cfg: &Typescript,
-    type_map: &TypeMap,
+    ts: &Typescript,
+    cfg: &ExportContext,
 ) -> Result<Vec<String>, ExportError> {
     events
         .iter()
         .map(|(name, typ)| {
-            let name_camel = name.to_lower_camel_case();
+            let name_camel = cfg.event_ident(name);
 
-            let typ = ts::datatype(cfg, &FunctionResultVariant::Value(typ.clone()), type_map)?;
+            let typ = datatype(ts, typ, &cfg.type_map)?;
 
             Ok(format!(r#"{name_camel}: {typ}"#))
         })
         .collect()
 }
 
+/// The deprecation of a command, either from its `#[deprecated]` attribute or [`Builder::deprecate_command`](crate::Builder::deprecate_command).
+pub fn command_deprecated<'a>(
+    cfg: &'a ExportContext,
+    function: &'a datatype::Function,
+) -> Option<&'a DeprecatedType> {
+    function
+        .deprecated()
+        .or_else(|| cfg.deprecated_commands.get(function.name()))
+}
+
+/// The `@requires-permission` JSDoc tag of a command registered with [`Builder::required_permission`](crate::Builder::required_permission).
+pub fn required_permission(cfg: &ExportContext, function: &datatype::Function) -> Option<String> {
+    cfg.required_permissions
+        .get(function.name())
+        .map(|permission| format!("@requires-permission {permission}"))
+}
+
+/// The doc comment of an event's type, rendered as JSDoc.
+pub fn event_docs(name: &str, typ: &DataType, cfg: &ExportContext) -> String {
+    let ndt = match typ {
+        DataType::Reference(r) => cfg.type_map.get(r.sid()),
+        _ => None,
+    };
+
+    let mut builder = js_doc::Builder::default();
+
+    if let Some(d) = ndt
+        .and_then(|ndt| ndt.deprecated())
+        .or_else(|| cfg.deprecated_events.get(name))
+    {
+        builder.push_deprecated(d);
+    }
+
+    if let Some(docs) = ndt.map(|ndt| ndt.docs()).filter(|docs| !docs.is_empty()) {
+        builder.extend(docs.split("\n"));
+    }
+
+    builder.build()
+}
+
+/// The commands registered directly within the namespace at `path`.
+pub fn namespace_commands<'a>(
+    cfg: &'a ExportContext,
+    path: &'a [Cow<'static, str>],
+) -> impl Iterator<Item = &'a datatype::Function> {
+    cfg.commands.iter().filter(move |function| {
+        cfg.command_namespaces
+            .get(function.name())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            == path
+    })
+}
+
+/// The events registered directly within the namespace at `path`.
+pub fn namespace_events(
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> BTreeMap<&'static str, DataType> {
+    cfg.events
+        .iter()
+        .filter(|(name, _)| {
+            cfg.event_namespaces
+                .get(*name)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+                == path
+        })
+        .map(|(name, typ)| (*name, typ.clone()))
+        .collect()
+}
+
+/// The namespaces nested directly within the namespace at `path`.
+pub fn namespace_children<'a>(
+    cfg: &'a ExportContext,
+    path: &[Cow<'static, str>],
+) -> BTreeSet<&'a Cow<'static, str>> {
+    cfg.command_namespaces
+        .values()
+        .chain(cfg.event_namespaces.values())
+        .filter(|namespace| namespace.len() > path.len() && namespace.starts_with(path))
+        .map(|namespace| &namespace[path.len()])
+        .collect()
+}
+
 pub fn events_data(
     events: &BTreeMap<&'static str, DataType>,
-    cfg: &Typescript,
-    plugin_name: &Option<&'static str>,
-    type_map: &TypeMap,
+    ts: &Typescript,
+    cfg: &ExportContext,
 ) -> Result<(Vec<String>, String), ExportError> {
-    Ok((
-        events_types(events, cfg, type_map)?,
-        events_map(events, plugin_name),
-    ))
+    Ok((events_types(events, ts, cfg)?, events_map(events, cfg)))
+}
+
+/// Collect the names of the named types referenced by `typ`, as they're named in `type_map`.
+pub fn referenced_types(
+    typ: &DataType,
+    type_map: &TypeMap,
+    types: &mut BTreeSet<Cow<'static, str>>,
+) {
+    match typ {
+        DataType::Any
+        | DataType::Unknown
+        | DataType::Primitive(_)
+        | DataType::Literal(_)
+        | DataType::Generic(_) => {}
+        DataType::List(list) => referenced_types(list.ty(), type_map, types),
+        DataType::Map(map) => {
+            referenced_types(map.key_ty(), type_map, types);
+            referenced_types(map.value_ty(), type_map, types);
+        }
+        DataType::Nullable(t) => referenced_types(t, type_map, types),
+        DataType::Struct(s) => match s.fields() {
+            StructFields::Unit => {}
+            StructFields::Unnamed(fields) => fields
+                .fields()
+                .iter()
+                .filter_map(|field| field.ty())
+                .for_each(|ty| referenced_types(ty, type_map, types)),
+            StructFields::Named(fields) => fields
+                .fields()
+                .iter()
+                .filter_map(|(_, field)| field.ty())
+                .for_each(|ty| referenced_types(ty, type_map, types)),
+        },
+        DataType::Enum(e) => {
+            for (_, variant) in e.variants() {
+                match variant.inner() {
+                    EnumVariants::Unit => {}
+                    EnumVariants::Unnamed(fields) => fields
+                        .fields()
+                        .iter()
+                        .filter_map(|field| field.ty())
+                        .for_each(|ty| referenced_types(ty, type_map, types)),
+                    EnumVariants::Named(fields) => fields
+                        .fields()
+                        .iter()
+                        .filter_map(|(_, field)| field.ty())
+                        .for_each(|ty| referenced_types(ty, type_map, types)),
+                }
+            }
+        }
+        DataType::Tuple(t) => t
+            .elements()
+            .iter()
+            .for_each(|ty| referenced_types(ty, type_map, types)),
+        DataType::Reference(r) => {
+            // `Channel` comes from `@tauri-apps/api` instead of the exported types.
+            if r.sid() != crate::channel_sid() {
+                types.insert(
+                    type_map
+                        .get(r.sid())
+                        .map(|ndt| ndt.name().clone())
+                        .unwrap_or_else(|| r.name().clone()),
+                );
+            }
+            r.generics()
+                .iter()
+                .for_each(|(_, ty)| referenced_types(ty, type_map, types));
+        }
+    }
 }
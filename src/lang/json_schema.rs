@@ -0,0 +1,839 @@
+//! [JSON Schema](https://json-schema.org) exporter.
+
+use std::{borrow::Cow, collections::BTreeMap, fs, io, path::Path};
+
+use heck::ToLowerCamelCase;
+use serde_json::{json, Map, Value};
+use specta::{
+    datatype::{
+        DataType, EnumRepr, EnumType, EnumVariants, Field, Function, FunctionResultVariant,
+        GenericType, LiteralType, NamedDataType, PrimitiveType, StructFields, StructType,
+    },
+    Language, SpectaID, TypeMap,
+};
+
+use crate::{ExportContext, LanguageExt};
+
+/// [JSON Schema](https://json-schema.org) (draft 2020-12) exporter.
+///
+/// Generates a single document with a `$defs` entry for every type,
+/// and a `commands` and `events` index describing the payloads which cross the IPC boundary.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, JsonSchema};
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(JsonSchema::default(), "../schemas/ipc.json")
+///     .expect("Failed to export json schema");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct JsonSchema {}
+
+impl JsonSchema {
+    /// Construct a new JSON Schema exporter with the default options configured.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Language for JsonSchema {
+    type Error = io::Error;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        let mut exporter = Exporter::new(Draft::Draft2020_12, &type_map);
+        for (_, ndt) in type_map.iter() {
+            exporter.definition(ndt);
+        }
+
+        to_string(&json!({
+            "$schema": Draft::Draft2020_12.uri(),
+            "$defs": exporter.definitions,
+        }))
+    }
+
+    fn format(&self, _: &Path) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl LanguageExt for JsonSchema {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        let mut exporter = Exporter::new(Draft::Draft2020_12, &cfg.type_map);
+        for (_, ndt) in cfg.type_map.iter() {
+            exporter.definition(ndt);
+        }
+
+        let commands = cfg
+            .commands
+            .iter()
+            .map(|function| {
+                let name = cfg.command_name(function.name());
+
+                let mut schema = Map::new();
+                schema.insert("args".into(), exporter.arguments(function));
+
+                match function.result() {
+                    Some(FunctionResultVariant::Value(t)) => {
+                        schema.insert("result".into(), exporter.datatype(t, &[]));
+                    }
+                    Some(FunctionResultVariant::Result(t, e)) => {
+                        schema.insert("result".into(), exporter.datatype(t, &[]));
+                        schema.insert("error".into(), exporter.datatype(e, &[]));
+                    }
+                    None => {
+                        schema.insert("result".into(), json!({ "type": "null" }));
+                    }
+                }
+
+                (name, Value::Object(schema))
+            })
+            .collect::<Map<_, _>>();
+
+        let events = cfg
+            .events
+            .iter()
+            .map(|(name, typ)| (cfg.event_name(name), exporter.datatype(typ, &[])))
+            .collect::<Map<_, _>>();
+
+        to_string(&json!({
+            "$schema": Draft::Draft2020_12.uri(),
+            "$defs": exporter.definitions,
+            "commands": commands,
+            "events": events,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Draft {
+    Draft07,
+    Draft2020_12,
+    /// The subset of JSON Schema used by OpenAPI 3.0, which is converted from draft 07 by [`openapi_schema`].
+    OpenApi3_0,
+}
+
+impl Draft {
+    fn uri(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "http://json-schema.org/draft-07/schema#",
+            Self::Draft2020_12 => "https://json-schema.org/draft/2020-12/schema",
+            Self::OpenApi3_0 => "https://spec.openapis.org/oas/3.0/schema/2021-09-28",
+        }
+    }
+
+    /// The keyword which holds shared definitions.
+    fn definitions(&self) -> &'static str {
+        match self {
+            Self::Draft07 => "definitions",
+            Self::Draft2020_12 => "$defs",
+            Self::OpenApi3_0 => "components/schemas",
+        }
+    }
+}
+
+/// Write a `<TypeName>.json` schema for every type and a `constants.json` schema for the constants into `dir`.
+pub(crate) fn export(cfg: &ExportContext, dir: &Path) -> Result<(), crate::Error> {
+    fs::create_dir_all(dir).map_err(crate::Error::io(dir))?;
+
+    for (_, ndt) in cfg.type_map.iter() {
+        write(
+            &dir.join(format!("{}.json", ndt.name())),
+            &named_schema(ndt, &cfg.type_map),
+        )?;
+    }
+
+    if !cfg.constants.is_empty() || !cfg.constant_groups.is_empty() {
+        write(&dir.join("constants.json"), &constants_schema(cfg))?;
+    }
+
+    Ok(())
+}
+
+/// Write a manifest of the commands and events, using JSON Schema for their types, to `path`.
+///
+/// Everything is sorted by name so the manifest only changes when the IPC surface does.
+pub(crate) fn export_manifest(cfg: &ExportContext, path: &Path) -> Result<(), crate::Error> {
+    let mut exporter = Exporter::new(Draft::Draft2020_12, &cfg.type_map);
+
+    let mut commands = cfg.commands.iter().collect::<Vec<_>>();
+    commands.sort_by_key(|function| function.name());
+    let commands = commands
+        .into_iter()
+        .map(|function| {
+            let args = function
+                .args()
+                .map(|(name, typ)| {
+                    json!({
+                        "name": name.to_lower_camel_case(),
+                        "type": exporter.datatype(typ, &[]),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let (result, error) = match function.result() {
+                Some(FunctionResultVariant::Value(t)) => (exporter.datatype(t, &[]), Value::Null),
+                Some(FunctionResultVariant::Result(t, e)) => {
+                    (exporter.datatype(t, &[]), exporter.datatype(e, &[]))
+                }
+                None => (json!({ "type": "null" }), Value::Null),
+            };
+
+            json!({
+                "name": cfg.command_name(function.name()),
+                "ident": cfg.command_ident(function.name()),
+                "args": args,
+                "result": result,
+                "error": error,
+                "docs": description(function.docs()),
+                "deprecated": function.deprecated().is_some()
+                    || cfg.deprecated_commands.contains_key(function.name()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let events = cfg
+        .events
+        .iter()
+        .map(|(name, typ)| {
+            json!({
+                "name": cfg.event_name(name),
+                "ident": cfg.event_ident(name),
+                "payload": exporter.datatype(typ, &[]),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(crate::Error::io(dir))?;
+    }
+
+    write(
+        path,
+        &json!({
+            "commands": commands,
+            "events": events,
+            "$defs": exporter.definitions,
+        }),
+    )
+}
+
+/// Write an [OpenAPI 3.0](https://spec.openapis.org/oas/v3.0.3) document to `path`, describing each command as a `POST /commands/{name}` endpoint.
+///
+/// The events are described by an [AsyncAPI 2.6](https://www.asyncapi.com/docs/reference/specification/v2.6.0) document alongside it,
+/// Eg. `openapi.asyncapi.yaml` for `openapi.yaml`, as OpenAPI 3.0 has no way to describe them.
+pub(crate) fn export_openapi(
+    cfg: &ExportContext,
+    version: &str,
+    path: &Path,
+) -> Result<(), crate::Error> {
+    let title = cfg.plugin_name.unwrap_or("Tauri commands");
+    let mut exporter = Exporter::new(Draft::OpenApi3_0, &cfg.type_map);
+
+    let mut commands = cfg.commands.iter().collect::<Vec<_>>();
+    commands.sort_by_key(|function| function.name());
+    let paths = commands
+        .into_iter()
+        .map(|function| {
+            let name = cfg.command_name(function.name());
+            let content = |schema: Value| json!({ "application/json": { "schema": schema } });
+
+            let mut operation = Map::new();
+            operation.insert(
+                "operationId".into(),
+                cfg.command_ident(function.name()).into(),
+            );
+            if !function.docs().is_empty() {
+                operation.insert("description".into(), description(function.docs()).into());
+            }
+            if function.deprecated().is_some()
+                || cfg.deprecated_commands.contains_key(function.name())
+            {
+                operation.insert("deprecated".into(), true.into());
+            }
+
+            if function.args().next().is_some() {
+                operation.insert(
+                    "requestBody".into(),
+                    json!({ "required": true, "content": content(exporter.arguments(function)) }),
+                );
+            }
+
+            let (result, error) = match function.result() {
+                Some(FunctionResultVariant::Value(t)) => (exporter.datatype(t, &[]), None),
+                Some(FunctionResultVariant::Result(t, e)) => {
+                    (exporter.datatype(t, &[]), Some(exporter.datatype(e, &[])))
+                }
+                None => (json!({ "type": "null" }), None),
+            };
+            let mut responses = Map::new();
+            responses.insert(
+                "200".into(),
+                json!({ "description": "The result of the command.", "content": content(result) }),
+            );
+            if let Some(error) = error {
+                responses.insert(
+                    "default".into(),
+                    json!({ "description": "The error returned by the command.", "content": content(error) }),
+                );
+            }
+            operation.insert("responses".into(), responses.into());
+
+            (format!("/commands/{name}"), json!({ "post": operation }))
+        })
+        .collect::<Map<_, _>>();
+
+    let mut document = json!({
+        "openapi": "3.0.3",
+        "info": { "title": title, "version": version },
+        "servers": [{ "url": "ipc://localhost" }],
+        "paths": paths,
+        "components": { "schemas": exporter.definitions },
+    });
+    openapi_schema(&mut document);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(crate::Error::io(dir))?;
+    }
+    fs::write(path, to_yaml(&document)).map_err(crate::Error::io(path))?;
+
+    if cfg.events.is_empty() {
+        return Ok(());
+    }
+
+    let mut exporter = Exporter::new(Draft::OpenApi3_0, &cfg.type_map);
+    let channels = cfg
+        .events
+        .iter()
+        .map(|(name, typ)| {
+            let message = json!({
+                "name": cfg.event_ident(name),
+                "schemaFormat": "application/vnd.oai.openapi;version=3.0.0",
+                "payload": exporter.datatype(typ, &[]),
+            });
+
+            (
+                cfg.event_name(name),
+                json!({ "subscribe": { "message": message } }),
+            )
+        })
+        .collect::<Map<_, _>>();
+
+    let mut document = json!({
+        "asyncapi": "2.6.0",
+        "info": { "title": title, "version": version },
+        "channels": channels,
+        "components": { "schemas": exporter.definitions },
+    });
+    openapi_schema(&mut document);
+
+    let extension = path
+        .extension()
+        .map(|ext| format!("asyncapi.{}", ext.to_string_lossy()))
+        .unwrap_or_else(|| "asyncapi".into());
+    let path = path.with_extension(extension);
+    fs::write(&path, to_yaml(&document)).map_err(crate::Error::io(&path))
+}
+
+/// Convert the draft 07 keywords OpenAPI 3.0 doesn't support, Eg. `const` and the `null` type.
+///
+/// `document` may be any part of an OpenAPI document, as only the values of `properties` are treated as schemas.
+fn openapi_schema(document: &mut Value) {
+    match document {
+        Value::Object(object) => {
+            if let Some(Value::Array(variants)) = object.get_mut("anyOf") {
+                let null = json!({ "type": "null" });
+                if variants.contains(&null) {
+                    variants.retain(|variant| variant != &null);
+                    match variants.len() {
+                        0 => {
+                            object.remove("anyOf");
+                            object.insert("enum".into(), json!([null]));
+                        }
+                        // Keywords alongside a `$ref` are ignored, so it's wrapped in an `allOf` instead.
+                        1 => {
+                            let variants = object.remove("anyOf");
+                            object.insert("allOf".into(), variants.unwrap_or_default());
+                        }
+                        _ => {}
+                    }
+                    object.insert("nullable".into(), true.into());
+                }
+            }
+
+            if object.get("type") == Some(&json!("null")) {
+                object.remove("type");
+                object.insert("enum".into(), json!([null]));
+                object.insert("nullable".into(), true.into());
+            }
+            if let Some(value) = object.remove("const") {
+                object.insert("enum".into(), json!([value]));
+            }
+
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), value) {
+                    // The keys of these maps are names rather than keywords.
+                    ("properties" | "schemas", Value::Object(schemas)) => {
+                        schemas.values_mut().for_each(openapi_schema)
+                    }
+                    (_, value) => openapi_schema(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(openapi_schema),
+        _ => {}
+    }
+}
+
+/// Render `document` as YAML.
+///
+/// Every scalar is written as JSON, which YAML accepts as a flow scalar, so strings never need to be escaped differently.
+fn to_yaml(document: &Value) -> String {
+    fn node(value: &Value, indent: usize, out: &mut String) {
+        let pad = "  ".repeat(indent);
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    out.push_str(&format!("{pad}{}:", Value::from(key.as_str())));
+                    entry(value, indent, out);
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    out.push_str(&format!("{pad}-"));
+                    entry(value, indent, out);
+                }
+            }
+            value => out.push_str(&format!("{pad}{value}\n")),
+        }
+    }
+
+    fn entry(value: &Value, indent: usize, out: &mut String) {
+        match value {
+            Value::Object(object) if !object.is_empty() => {
+                out.push('\n');
+                node(value, indent + 1, out);
+            }
+            Value::Array(values) if !values.is_empty() => {
+                out.push('\n');
+                node(value, indent + 1, out);
+            }
+            value => out.push_str(&format!(" {value}\n")),
+        }
+    }
+
+    let mut out = String::new();
+    node(document, 0, &mut out);
+    out
+}
+
+fn write(path: &Path, schema: &Value) -> Result<(), crate::Error> {
+    to_string(schema)
+        .and_then(|contents| fs::write(path, contents))
+        .map_err(crate::Error::io(path))
+}
+
+fn to_string(schema: &Value) -> io::Result<String> {
+    let mut contents = serde_json::to_string_pretty(schema).map_err(io::Error::other)?;
+    contents.push('\n');
+    Ok(contents)
+}
+
+/// The root schema for a named type.
+///
+/// The type itself lives in `definitions` alongside every type it references so recursive types resolve.
+fn named_schema(ndt: &NamedDataType, type_map: &TypeMap) -> Value {
+    let draft = Draft::Draft07;
+    let mut exporter = Exporter::new(draft, type_map);
+    exporter.definition(ndt);
+
+    json!({
+        "$schema": draft.uri(),
+        "title": ndt.name(),
+        "$ref": exporter.reference(ndt.name()),
+        (draft.definitions()): exporter.definitions,
+    })
+}
+
+fn constants_schema(cfg: &ExportContext) -> Value {
+    let constants = cfg
+        .constants
+        .iter()
+        .map(|(name, constant)| (name.to_string(), json!({ "const": constant.value })))
+        .chain(cfg.constant_groups.iter().map(|(group, constants)| {
+            let constants = constants
+                .iter()
+                .map(|(name, constant)| (name.to_lower_camel_case(), constant.value.clone()))
+                .collect::<Map<_, _>>();
+
+            (group.to_lower_camel_case(), json!({ "const": constants }))
+        }))
+        .collect::<BTreeMap<_, _>>();
+
+    json!({
+        "$schema": Draft::Draft07.uri(),
+        "title": "Constants",
+        "type": "object",
+        "properties": constants,
+        "required": constants.keys().collect::<Vec<_>>(),
+    })
+}
+
+struct Exporter<'a> {
+    draft: Draft,
+    type_map: &'a TypeMap,
+    definitions: BTreeMap<Cow<'static, str>, Value>,
+    /// Generic types currently being inlined, used to break recursion.
+    inlining: Vec<SpectaID>,
+}
+
+impl<'a> Exporter<'a> {
+    fn new(draft: Draft, type_map: &'a TypeMap) -> Self {
+        Self {
+            draft,
+            type_map,
+            definitions: BTreeMap::new(),
+            inlining: Vec::new(),
+        }
+    }
+
+    fn reference(&self, name: &str) -> String {
+        format!("#/{}/{name}", self.draft.definitions())
+    }
+
+    /// The object passed to a command, where `Option` arguments may be omitted as Tauri deserializes them as `None`.
+    fn arguments(&mut self, function: &Function) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for (name, typ) in function.args() {
+            let name = name.to_lower_camel_case();
+            if !matches!(typ, DataType::Nullable(_)) {
+                required.push(name.clone());
+            }
+            properties.insert(name, self.datatype(typ, &[]));
+        }
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        })
+    }
+
+    fn definition(&mut self, ndt: &NamedDataType) {
+        if self.definitions.contains_key(ndt.name()) {
+            return;
+        }
+
+        // Reserve the slot first so self-referential types don't recurse forever.
+        self.definitions.insert(ndt.name().clone(), Value::Null);
+
+        let mut schema = self.datatype(&ndt.inner, &[]);
+        if !ndt.docs().is_empty() {
+            if let Value::Object(schema) = &mut schema {
+                schema.insert("description".into(), description(ndt.docs()).into());
+            }
+        }
+
+        self.definitions.insert(ndt.name().clone(), schema);
+    }
+
+    fn datatype(&mut self, typ: &DataType, generics: &[(GenericType, Value)]) -> Value {
+        match typ {
+            DataType::Any | DataType::Unknown => json!({}),
+            DataType::Primitive(p) => match p {
+                PrimitiveType::i8
+                | PrimitiveType::i16
+                | PrimitiveType::i32
+                | PrimitiveType::i64
+                | PrimitiveType::i128
+                | PrimitiveType::isize => json!({ "type": "integer" }),
+                PrimitiveType::u8
+                | PrimitiveType::u16
+                | PrimitiveType::u32
+                | PrimitiveType::u64
+                | PrimitiveType::u128
+                | PrimitiveType::usize => json!({ "type": "integer", "minimum": 0 }),
+                PrimitiveType::f32 | PrimitiveType::f64 => json!({ "type": "number" }),
+                PrimitiveType::bool => json!({ "type": "boolean" }),
+                PrimitiveType::char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+                PrimitiveType::String => json!({ "type": "string" }),
+            },
+            DataType::Literal(l) => match l {
+                LiteralType::i8(v) => json!({ "const": v }),
+                LiteralType::i16(v) => json!({ "const": v }),
+                LiteralType::i32(v) => json!({ "const": v }),
+                LiteralType::u8(v) => json!({ "const": v }),
+                LiteralType::u16(v) => json!({ "const": v }),
+                LiteralType::u32(v) => json!({ "const": v }),
+                LiteralType::f32(v) => json!({ "const": v }),
+                LiteralType::f64(v) => json!({ "const": v }),
+                LiteralType::bool(v) => json!({ "const": v }),
+                LiteralType::String(v) => json!({ "const": v }),
+                LiteralType::char(v) => json!({ "const": v }),
+                LiteralType::None => json!({ "type": "null" }),
+                _ => json!({}),
+            },
+            DataType::List(list) => {
+                let mut schema = Map::new();
+                schema.insert("type".into(), "array".into());
+                schema.insert("items".into(), self.datatype(list.ty(), generics));
+                if let Some(length) = list.length() {
+                    schema.insert("minItems".into(), length.into());
+                    schema.insert("maxItems".into(), length.into());
+                }
+                if list.unique() {
+                    schema.insert("uniqueItems".into(), true.into());
+                }
+                Value::Object(schema)
+            }
+            // JSON object keys are always strings so the key type can't be expressed.
+            DataType::Map(map) => json!({
+                "type": "object",
+                "additionalProperties": self.datatype(map.value_ty(), generics),
+            }),
+            DataType::Nullable(t) => json!({
+                "anyOf": [self.datatype(t, generics), { "type": "null" }],
+            }),
+            DataType::Struct(s) => self.struct_datatype(s, generics),
+            DataType::Enum(e) => self.enum_datatype(e, generics),
+            DataType::Tuple(t) => match &t.elements()[..] {
+                [] => json!({ "type": "null" }),
+                elements => {
+                    let elements = elements
+                        .iter()
+                        .map(|t| self.datatype(t, generics))
+                        .collect::<Vec<_>>();
+                    self.tuple(elements)
+                }
+            },
+            // Channels are sent over IPC as a string containing their ID.
+            DataType::Reference(r) if r.sid() == crate::channel_sid() => {
+                json!({ "type": "string" })
+            }
+            DataType::Reference(r) => match &r.generics()[..] {
+                [] => {
+                    let type_map = self.type_map;
+                    if let Some(ndt) = type_map.get(r.sid()) {
+                        self.definition(ndt);
+                    }
+
+                    json!({ "$ref": self.reference(r.name()) })
+                }
+                // JSON Schema has no notion of generics so generic types are inlined with their arguments substituted.
+                args => {
+                    let type_map = self.type_map;
+                    let Some(ndt) = type_map.get(r.sid()) else {
+                        return json!({});
+                    };
+                    if self.inlining.contains(&r.sid()) {
+                        return json!({});
+                    }
+
+                    let args = args
+                        .iter()
+                        .map(|(generic, t)| (generic.clone(), self.datatype(t, generics)))
+                        .collect::<Vec<_>>();
+
+                    self.inlining.push(r.sid());
+                    let schema = self.datatype(&ndt.inner, &args);
+                    self.inlining.pop();
+
+                    schema
+                }
+            },
+            DataType::Generic(g) => generics
+                .iter()
+                .find(|(generic, _)| generic == g)
+                .map(|(_, schema)| schema.clone())
+                .unwrap_or_else(|| json!({})),
+        }
+    }
+
+    fn struct_datatype(&mut self, s: &StructType, generics: &[(GenericType, Value)]) -> Value {
+        match s.fields() {
+            StructFields::Unit => json!({ "type": "null" }),
+            StructFields::Unnamed(fields) => self.unnamed_fields(fields.fields(), generics),
+            StructFields::Named(fields) => {
+                let tag = fields
+                    .tag()
+                    .as_ref()
+                    .map(|tag| (tag.to_string(), json!({ "const": s.name() })));
+
+                self.object(tag, fields.fields(), generics)
+            }
+        }
+    }
+
+    fn enum_datatype(&mut self, e: &EnumType, generics: &[(GenericType, Value)]) -> Value {
+        let variants = e
+            .variants()
+            .iter()
+            .filter(|(_, variant)| !variant.skip())
+            .map(|(name, variant)| {
+                let literal = json!({ "const": name });
+
+                match (e.repr(), variant.inner()) {
+                    (EnumRepr::Untagged, EnumVariants::Unit) => json!({ "type": "null" }),
+                    (EnumRepr::Untagged, EnumVariants::Named(fields)) => {
+                        self.object(None, fields.fields(), generics)
+                    }
+                    (EnumRepr::Untagged, EnumVariants::Unnamed(fields)) => {
+                        self.unnamed_fields(fields.fields(), generics)
+                    }
+                    (EnumRepr::External, EnumVariants::Unit) => literal,
+                    (EnumRepr::External, EnumVariants::Named(fields)) => {
+                        let inner = self.object(None, fields.fields(), generics);
+                        properties([(name.to_string(), inner)])
+                    }
+                    (EnumRepr::External, EnumVariants::Unnamed(fields)) => {
+                        let inner = self.unnamed_fields(fields.fields(), generics);
+                        properties([(name.to_string(), inner)])
+                    }
+                    (EnumRepr::Internal { tag }, EnumVariants::Unit) => {
+                        properties([(tag.to_string(), literal)])
+                    }
+                    (EnumRepr::Internal { tag }, EnumVariants::Named(fields)) => {
+                        self.object(Some((tag.to_string(), literal)), fields.fields(), generics)
+                    }
+                    (EnumRepr::Internal { tag }, EnumVariants::Unnamed(fields)) => json!({
+                        "allOf": [
+                            properties([(tag.to_string(), literal)]),
+                            self.unnamed_fields(fields.fields(), generics),
+                        ],
+                    }),
+                    (EnumRepr::Adjacent { tag, .. }, EnumVariants::Unit) => {
+                        properties([(tag.to_string(), literal)])
+                    }
+                    (EnumRepr::Adjacent { tag, content }, EnumVariants::Named(fields)) => {
+                        let inner = self.object(None, fields.fields(), generics);
+                        properties([(tag.to_string(), literal), (content.to_string(), inner)])
+                    }
+                    (EnumRepr::Adjacent { tag, content }, EnumVariants::Unnamed(fields)) => {
+                        let inner = self.unnamed_fields(fields.fields(), generics);
+                        properties([(tag.to_string(), literal), (content.to_string(), inner)])
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match <[Value; 1]>::try_from(variants) {
+            Ok([variant]) => variant,
+            Err(variants) if variants.is_empty() => json!({ "not": {} }),
+            Err(variants) => json!({ "anyOf": variants }),
+        }
+    }
+
+    fn object(
+        &mut self,
+        tag: Option<(String, Value)>,
+        fields: &[(Cow<'static, str>, Field)],
+        generics: &[(GenericType, Value)],
+    ) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        let mut flattened = Vec::new();
+
+        if let Some((tag, schema)) = tag {
+            properties.insert(tag.clone(), schema);
+            required.push(tag);
+        }
+
+        for (name, field) in fields {
+            let Some(ty) = field.ty() else {
+                continue;
+            };
+            let schema = self.datatype(ty, generics);
+
+            if field.flatten() {
+                flattened.push(schema);
+            } else {
+                properties.insert(name.to_string(), schema);
+                if !field.optional() {
+                    required.push(name.to_string());
+                }
+            }
+        }
+
+        let object = json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        });
+
+        match flattened.is_empty() {
+            true => object,
+            false => {
+                let all_of = [object].into_iter().chain(flattened).collect::<Vec<_>>();
+                json!({ "allOf": all_of })
+            }
+        }
+    }
+
+    fn unnamed_fields(&mut self, fields: &[Field], generics: &[(GenericType, Value)]) -> Value {
+        let fields = fields
+            .iter()
+            .filter_map(|field| field.ty())
+            .map(|ty| self.datatype(ty, generics))
+            .collect::<Vec<_>>();
+
+        match <[Value; 1]>::try_from(fields) {
+            Ok([field]) => field,
+            Err(fields) => self.tuple(fields),
+        }
+    }
+
+    fn tuple(&self, elements: Vec<Value>) -> Value {
+        let len = elements.len();
+
+        match self.draft {
+            Draft::Draft07 => json!({
+                "type": "array",
+                "items": elements,
+                "minItems": len,
+                "maxItems": len,
+            }),
+            Draft::Draft2020_12 => json!({
+                "type": "array",
+                "prefixItems": elements,
+                "items": false,
+                "minItems": len,
+                "maxItems": len,
+            }),
+            // OpenAPI 3.0 has no tuples, so only the types of the elements can be described.
+            Draft::OpenApi3_0 => json!({
+                "type": "array",
+                "items": { "anyOf": elements },
+                "minItems": len,
+                "maxItems": len,
+            }),
+        }
+    }
+}
+
+/// Doc comments without the space which follows each `///`.
+fn description(docs: &str) -> String {
+    docs.lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn properties(fields: impl IntoIterator<Item = (String, Value)>) -> Value {
+    let fields = fields.into_iter().collect::<Vec<_>>();
+    let required = fields
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    json!({
+        "type": "object",
+        "properties": fields.into_iter().collect::<Map<_, _>>(),
+        "required": required,
+    })
+}
@@ -0,0 +1,565 @@
+//! [Kotlin](https://kotlinlang.org) exporter.
+
+use std::{borrow::Cow, io, path::Path};
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use specta::{
+    datatype::{
+        DataType, EnumRepr, EnumType, EnumVariant, EnumVariants, GenericType, LiteralType,
+        NamedDataType, NamedFields, PrimitiveType, StructFields, StructType,
+    },
+    Language, TypeMap,
+};
+
+use crate::{ExportContext, LanguageExt};
+
+use super::DO_NOT_EDIT;
+
+/// [Kotlin](https://kotlinlang.org) exporter.
+///
+/// Generates a [kotlinx.serialization](https://github.com/Kotlin/kotlinx.serialization) class for every type so the payloads of your commands and events can be decoded on Android, Eg. in a Tauri mobile plugin.
+/// Commands and events themselves aren't exported as they're only called from the webview.
+///
+/// Structs become data classes, enums with only unit variants become enum classes and enums with data become sealed interfaces.
+/// Types which kotlinx.serialization can't represent, such as untagged enums or flattened fields, are exported as a `JsonElement` alias.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, Kotlin};
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(
+///         Kotlin::new().package("com.example.plugin"),
+///         "../android/src/main/java/com/example/plugin/Bindings.kt",
+///     )
+///     .expect("Failed to export kotlin bindings");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Kotlin {
+    /// The file's header
+    pub header: Cow<'static, str>,
+    /// The package the types are declared in.
+    pub package: Option<Cow<'static, str>>,
+}
+
+impl Kotlin {
+    /// Construct a new Kotlin exporter with the default options configured.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure a header for the file.
+    ///
+    /// This is perfect for configuring lint ignore rules or other file-level comments.
+    pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Configure the package the types are declared in, Eg. `com.example.plugin`.
+    pub fn package(mut self, package: impl Into<Cow<'static, str>>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+}
+
+impl Language for Kotlin {
+    type Error = io::Error;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        Ok(render_types(self, &type_map))
+    }
+
+    fn format(&self, _: &Path) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl LanguageExt for Kotlin {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        Ok(render_types(self, &cfg.type_map))
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        Some("kt")
+    }
+}
+
+fn render_types(kotlin: &Kotlin, type_map: &TypeMap) -> String {
+    let header = &kotlin.header;
+    let package = kotlin
+        .package
+        .as_ref()
+        .map(|package| format!("package {package}\n\n"))
+        .unwrap_or_default();
+    let types = type_map
+        .iter()
+        .map(|(_, ndt)| named_datatype(kotlin, ndt))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format! {
+        r#"{header}
+{DO_NOT_EDIT}
+@file:OptIn(ExperimentalSerializationApi::class)
+
+{package}import kotlinx.serialization.*
+import kotlinx.serialization.json.*
+
+{types}
+"#
+    }
+}
+
+fn named_datatype(kotlin: &Kotlin, ndt: &NamedDataType) -> String {
+    let name = ndt.name();
+    let generics = generics(ndt.inner.generics().map(|g| &g[..]).unwrap_or_default());
+
+    let class = match &ndt.inner {
+        DataType::Struct(s) => struct_class(kotlin, name, &generics, s),
+        // Sealed interfaces can't be generic without registering a serializer for every argument.
+        DataType::Enum(e) if e.generics().is_empty() => enum_class(kotlin, name, e),
+        _ => None,
+    }
+    .unwrap_or_else(|| {
+        format!(
+            "typealias {name}{generics} = {}",
+            datatype(kotlin, &ndt.inner, &[])
+        )
+    });
+
+    format!("{}{class}", docs(ndt.docs(), ""))
+}
+
+fn struct_class(kotlin: &Kotlin, name: &str, generics: &str, s: &StructType) -> Option<String> {
+    match s.fields() {
+        // Unit structs are serialized as `null` which a class can't represent.
+        StructFields::Unit => None,
+        StructFields::Unnamed(fields) => match &fields.fields()[..] {
+            [field] => Some(format!(
+                "typealias {name}{generics} = {}",
+                datatype(kotlin, field.ty()?, &[])
+            )),
+            _ => None,
+        },
+        StructFields::Named(fields) => {
+            let tag = fields.tag().as_ref().map(|tag| {
+                format!(
+                    "@EncodeDefault @SerialName({}) val {}: String = {}",
+                    string(tag),
+                    ident(&tag.to_lower_camel_case()),
+                    string(name)
+                )
+            });
+            let properties = tag
+                .into_iter()
+                .chain(properties(kotlin, fields, &[])?)
+                .collect::<Vec<_>>();
+
+            Some(format!(
+                "@Serializable\n{}",
+                class(&format!("{name}{generics}"), &properties, None, "")
+            ))
+        }
+    }
+}
+
+fn enum_class(kotlin: &Kotlin, name: &str, e: &EnumType) -> Option<String> {
+    let variants = e
+        .variants()
+        .iter()
+        .filter(|(_, variant)| !variant.skip())
+        .map(|(serial_name, variant)| {
+            let ident = ident(&serial_name.to_upper_camel_case());
+            (&**serial_name, ident, variant)
+        })
+        .collect::<Vec<_>>();
+
+    if let EnumRepr::External = e.repr() {
+        if variants
+            .iter()
+            .all(|(_, _, variant)| matches!(variant.inner(), EnumVariants::Unit))
+        {
+            let entries = variants
+                .iter()
+                .map(|(serial_name, ident, variant)| {
+                    let serial_name = match serial_name == ident {
+                        true => String::new(),
+                        false => format!("    @SerialName({})\n", string(serial_name)),
+                    };
+
+                    format!("{}{serial_name}    {ident},", docs(variant.docs(), "    "))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            return Some(format!(
+                "@Serializable\nenum class {name} {{\n{entries}\n}}"
+            ));
+        }
+    }
+
+    // The variant classes are nested in the sealed interface so they shadow any top-level type with the same name.
+    let shadowed = variants
+        .iter()
+        .map(|(_, ident, _)| ident.clone())
+        .chain(["Fields".to_string()])
+        .collect::<Vec<_>>();
+    let supertype = Some(name);
+
+    let (annotations, classes) = match e.repr() {
+        // Unit variants are serialized as a string which can't be told apart from the other variants.
+        EnumRepr::External => (
+            format!("@Serializable(with = {name}Serializer::class)\n"),
+            variants
+                .iter()
+                .map(|(serial_name, ident, variant)| {
+                    let class =
+                        variant_wrapper(kotlin, ident, serial_name, variant, supertype, &shadowed)?;
+                    Some((String::new(), class))
+                })
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        EnumRepr::Internal { tag } => (
+            format!("@Serializable\n@JsonClassDiscriminator({})\n", string(tag)),
+            variants
+                .iter()
+                .map(|(serial_name, ident, variant)| {
+                    let properties = match variant.inner() {
+                        EnumVariants::Unit => Vec::new(),
+                        EnumVariants::Named(fields) => properties(kotlin, fields, &shadowed)?,
+                        // The fields of the newtype are merged with the tag, which kotlinx.serialization doesn't support.
+                        EnumVariants::Unnamed(_) => return None,
+                    };
+
+                    Some((
+                        format!("    @SerialName({})\n", string(serial_name)),
+                        class(ident, &properties, supertype, "    "),
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        EnumRepr::Adjacent { tag, content } => (
+            format!("@Serializable\n@JsonClassDiscriminator({})\n", string(tag)),
+            variants
+                .iter()
+                .map(|(serial_name, ident, variant)| {
+                    let class = match variant.inner() {
+                        EnumVariants::Unit => class(ident, &[], supertype, "    "),
+                        _ => {
+                            variant_wrapper(kotlin, ident, content, variant, supertype, &shadowed)?
+                        }
+                    };
+
+                    Some((format!("    @SerialName({})\n", string(serial_name)), class))
+                })
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        EnumRepr::Untagged => return None,
+    };
+
+    let classes = variants
+        .iter()
+        .zip(classes)
+        .map(|((_, _, variant), (serial_name, class))| {
+            format!(
+                "{}    @Serializable\n{serial_name}{class}",
+                docs(variant.docs(), "    ")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let mut output = format!("{annotations}sealed interface {name} {{\n{classes}\n}}");
+
+    // Externally tagged variants are told apart by the key holding their data.
+    if let EnumRepr::External = e.repr() {
+        let selectors = variants
+            .iter()
+            .map(|(serial_name, ident, _)| {
+                format!(
+                    "        {} in element.jsonObject -> {name}.{ident}.serializer()",
+                    string(serial_name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        output.push_str(&format!(
+            r#"
+
+object {name}Serializer : JsonContentPolymorphicSerializer<{name}>({name}::class) {{
+    override fun selectDeserializer(element: JsonElement): DeserializationStrategy<{name}> = when {{
+{selectors}
+        else -> throw SerializationException("Unknown {name} variant")
+    }}
+}}"#
+        ));
+    }
+
+    Some(output)
+}
+
+/// A class holding the data of a variant in a single property with the key `key`.
+///
+/// Variants with named fields store them in a nested `Fields` class.
+fn variant_wrapper(
+    kotlin: &Kotlin,
+    ident: &str,
+    key: &str,
+    variant: &EnumVariant,
+    supertype: Option<&str>,
+    shadowed: &[String],
+) -> Option<String> {
+    let (ty, fields) = match variant.inner() {
+        EnumVariants::Unit => return None,
+        EnumVariants::Unnamed(fields) => match &fields.fields()[..] {
+            [field] => (datatype(kotlin, field.ty()?, shadowed), None),
+            _ => ("JsonArray".to_string(), None),
+        },
+        EnumVariants::Named(fields) => (
+            "Fields".to_string(),
+            Some(class(
+                "Fields",
+                &properties(kotlin, fields, shadowed)?,
+                None,
+                "        ",
+            )),
+        ),
+    };
+
+    let property = format!("@SerialName({}) val value: {ty}", string(key));
+    let class = class(ident, &[property], supertype, "    ");
+
+    Some(match fields {
+        Some(fields) => format!("{class} {{\n        @Serializable\n{fields}\n    }}"),
+        None => class,
+    })
+}
+
+/// The constructor properties for `fields`, or `None` if they can't be represented by kotlinx.serialization.
+fn properties(kotlin: &Kotlin, fields: &NamedFields, shadowed: &[String]) -> Option<Vec<String>> {
+    let mut properties = Vec::new();
+
+    for (name, field) in fields.fields() {
+        let Some(ty) = field.ty() else {
+            continue;
+        };
+        if field.flatten() {
+            return None;
+        }
+
+        let property = name.to_lower_camel_case();
+        let serial_name = match *name == property {
+            true => String::new(),
+            false => format!("@SerialName({}) ", string(name)),
+        };
+        // Nullable properties default to `null` so they can be omitted, like `Option` with serde.
+        let ty = datatype(kotlin, ty, shadowed);
+        let (ty, default) = match field.optional() || ty.ends_with('?') {
+            true => (nullable(ty), " = null"),
+            false => (ty, ""),
+        };
+
+        properties.push(format!(
+            "{}{serial_name}val {}: {ty}{default}",
+            docs(field.docs(), ""),
+            ident(&property)
+        ));
+    }
+
+    Some(properties)
+}
+
+/// A data class with `properties`, or an object if it has none.
+fn class(decl: &str, properties: &[String], supertype: Option<&str>, indent: &str) -> String {
+    let supertype = supertype
+        .map(|supertype| format!(" : {supertype}"))
+        .unwrap_or_default();
+
+    if properties.is_empty() {
+        let keyword = match supertype.is_empty() {
+            true => "class",
+            false => "object",
+        };
+        return format!("{indent}{keyword} {decl}{supertype}");
+    }
+
+    let properties = properties
+        .iter()
+        .map(|property| {
+            property
+                .lines()
+                .map(|line| format!("{indent}    {line}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    format!("{indent}data class {decl}(\n{properties},\n{indent}){supertype}")
+}
+
+fn datatype(kotlin: &Kotlin, typ: &DataType, shadowed: &[String]) -> String {
+    match typ {
+        DataType::Any | DataType::Unknown => "JsonElement".into(),
+        DataType::Primitive(p) => match p {
+            PrimitiveType::i8 => "Byte",
+            PrimitiveType::i16 => "Short",
+            PrimitiveType::i32 => "Int",
+            PrimitiveType::i64 | PrimitiveType::isize => "Long",
+            PrimitiveType::u8 => "UByte",
+            PrimitiveType::u16 => "UShort",
+            PrimitiveType::u32 => "UInt",
+            PrimitiveType::u64 | PrimitiveType::usize => "ULong",
+            // Kotlin has no 128-bit integers so the raw number is kept.
+            PrimitiveType::i128 | PrimitiveType::u128 => "JsonPrimitive",
+            PrimitiveType::f32 => "Float",
+            PrimitiveType::f64 => "Double",
+            PrimitiveType::bool => "Boolean",
+            PrimitiveType::char => "Char",
+            PrimitiveType::String => "String",
+        }
+        .into(),
+        DataType::Literal(l) => match l {
+            LiteralType::i8(_) => "Byte",
+            LiteralType::i16(_) => "Short",
+            LiteralType::i32(_) => "Int",
+            LiteralType::u8(_) => "UByte",
+            LiteralType::u16(_) => "UShort",
+            LiteralType::u32(_) => "UInt",
+            LiteralType::f32(_) => "Float",
+            LiteralType::f64(_) => "Double",
+            LiteralType::bool(_) => "Boolean",
+            LiteralType::String(_) => "String",
+            LiteralType::char(_) => "Char",
+            _ => "JsonElement",
+        }
+        .into(),
+        DataType::List(list) => {
+            let ty = datatype(kotlin, list.ty(), shadowed);
+
+            match list.unique() {
+                true => format!("Set<{ty}>"),
+                false => format!("List<{ty}>"),
+            }
+        }
+        DataType::Map(map) => format!(
+            "Map<{}, {}>",
+            datatype(kotlin, map.key_ty(), shadowed),
+            datatype(kotlin, map.value_ty(), shadowed)
+        ),
+        DataType::Nullable(t) => nullable(datatype(kotlin, t, shadowed)),
+        // Anonymous structs and enums have no class to decode into.
+        DataType::Struct(_) | DataType::Enum(_) => "JsonElement".into(),
+        DataType::Tuple(t) => match &t.elements()[..] {
+            [] => "JsonElement".into(),
+            _ => "JsonArray".into(),
+        },
+        // Channels are sent over IPC as a string containing their ID.
+        DataType::Reference(r) if r.sid() == crate::channel_sid() => "String".into(),
+        DataType::Reference(r) => {
+            let name = match &kotlin.package {
+                Some(package) if shadowed.iter().any(|s| s == r.name()) => {
+                    format!("{package}.{}", r.name())
+                }
+                _ => r.name().to_string(),
+            };
+
+            match &r.generics()[..] {
+                [] => name,
+                generics => format!(
+                    "{name}<{}>",
+                    generics
+                        .iter()
+                        .map(|(_, t)| datatype(kotlin, t, shadowed))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+        DataType::Generic(g) => g.to_string(),
+    }
+}
+
+fn nullable(ty: String) -> String {
+    match ty.ends_with('?') {
+        true => ty,
+        false => format!("{ty}?"),
+    }
+}
+
+fn generics(generics: &[GenericType]) -> String {
+    match generics {
+        [] => String::new(),
+        generics => format!(
+            "<{}>",
+            generics
+                .iter()
+                .map(|g| g.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn docs(docs: &str, indent: &str) -> String {
+    if docs.is_empty() {
+        return String::new();
+    }
+
+    let lines = docs
+        .lines()
+        .map(|line| format!("{indent} * {}\n", line.strip_prefix(' ').unwrap_or(line)))
+        .collect::<String>();
+
+    format!("{indent}/**\n{lines}{indent} */\n")
+}
+
+/// Escape Kotlin's hard keywords with backticks.
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as",
+        "break",
+        "class",
+        "continue",
+        "do",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "in",
+        "interface",
+        "is",
+        "null",
+        "object",
+        "package",
+        "return",
+        "super",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typealias",
+        "typeof",
+        "val",
+        "var",
+        "when",
+        "while",
+    ];
+
+    match KEYWORDS.contains(&name) {
+        true => format!("`{name}`"),
+        false => name.to_string(),
+    }
+}
+
+fn string(s: &str) -> String {
+    serde_json::to_string(s).expect("failed to serialize string")
+}
@@ -0,0 +1,110 @@
+//! [TanStack Query](https://tanstack.com/query) hooks exporter.
+
+use heck::ToUpperCamelCase;
+use specta::datatype::Function;
+use specta_typescript::{ExportError, Typescript};
+
+use crate::{
+    lang::{
+        framework::{self, Command},
+        js_ts,
+    },
+    ExportContext, QueryKind,
+};
+
+/// Command name prefixes which are treated as [`QueryKind::Query`] unless overridden.
+const QUERY_PREFIXES: [&str; 3] = ["get_", "list_", "fetch_"];
+
+/// Render a hook for every command.
+pub(crate) fn render(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    let cfg = &*js_ts::affix_types(cfg);
+    let hooks = cfg
+        .commands
+        .iter()
+        .map(|function| hook(ts, cfg, function))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    framework::render(
+        ts,
+        cfg,
+        &[
+            r#"import { useQuery, useMutation, type UseQueryResult, type UseMutationResult } from "@tanstack/react-query";"#.into(),
+            framework::core_import(cfg),
+        ],
+        &[("hooks", hooks)],
+    )
+}
+
+fn hook(ts: &Typescript, cfg: &ExportContext, function: &Function) -> Result<String, ExportError> {
+    let command = Command::new(ts, cfg, function)?;
+    let Command {
+        name,
+        ident,
+        args,
+        data,
+        error,
+        docs,
+    } = &command;
+    let hook_name = format!("use{}", ident.to_upper_camel_case());
+    let arg_names = command.arg_names();
+    let invoke = command.invoke(js_ts::arg_usages(&arg_names));
+
+    Ok(match query_kind(cfg, function) {
+        QueryKind::Query => {
+            let params = command.params();
+            let generics = match error {
+                Some(error) => format!("{data}, {error}"),
+                None => data.clone(),
+            };
+            let query_key = [format!(r#""{name}""#)]
+                .into_iter()
+                .chain(arg_names.iter().cloned())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!(
+                r#"{docs}export function {hook_name}({params}): UseQueryResult<{generics}> {{
+    return useQuery({{ queryKey: [{query_key}], queryFn: () => {invoke} }});
+}}"#
+            )
+        }
+        QueryKind::Mutation => {
+            let (variables, destructured) = match args.is_empty() {
+                true => ("void".to_string(), "()".to_string()),
+                false => (
+                    format!(
+                        "{{ {} }}",
+                        args.iter()
+                            .map(|(name, ty)| format!("{name}: {ty}"))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    ),
+                    format!("({{ {} }})", arg_names.join(", ")),
+                ),
+            };
+            let error = error.as_deref().unwrap_or("Error");
+
+            format!(
+                r#"{docs}export function {hook_name}(): UseMutationResult<{data}, {error}, {variables}> {{
+    return useMutation({{ mutationFn: {destructured} => {invoke} }});
+}}"#
+            )
+        }
+    })
+}
+
+fn query_kind(cfg: &ExportContext, function: &Function) -> QueryKind {
+    cfg.query_kinds
+        .get(function.name())
+        .copied()
+        .unwrap_or_else(|| {
+            match QUERY_PREFIXES
+                .iter()
+                .any(|prefix| function.name().starts_with(prefix))
+            {
+                true => QueryKind::Query,
+                false => QueryKind::Mutation,
+            }
+        })
+}
@@ -0,0 +1,394 @@
+//! Core utilities for the Zod & Valibot schema exporters.
+//!
+//! Both libraries describe the same types with a different syntax, so they share the walk over the types.
+
+use std::borrow::Cow;
+
+use specta::{
+    datatype::{
+        DataType, EnumRepr, EnumType, EnumVariants, Field, LiteralType, PrimitiveType,
+        StructFields, StructType,
+    },
+    TypeMap,
+};
+use specta_typescript::{BigIntExportBehavior, ExportError, ExportPath, Typescript};
+
+use crate::type_graph;
+
+use super::js_ts;
+
+/// The syntax of a schema library.
+pub(crate) trait Schema {
+    /// The name the library is imported as, Eg. `z`.
+    const NAMESPACE: &'static str;
+    /// The type generic schemas are constrained to.
+    const GENERIC_BOUND: &'static str;
+
+    /// How BigInts should be exported.
+    fn bigint(&self) -> &BigIntExportBehavior;
+
+    /// The type inferred from the schema of the type `name`.
+    fn infer(name: &str) -> String;
+
+    /// The type of the schema of the type `name`, which recursive schemas are annotated with.
+    fn annotation(name: &str) -> String;
+
+    fn nullable(schema: &str) -> String;
+
+    fn optional(schema: &str) -> String;
+
+    fn array_with_length(schema: &str, length: usize) -> String;
+
+    fn intersect(schemas: Vec<String>) -> String;
+
+    /// A union of plain objects which hold their variant in `tag`.
+    fn discriminated_union(tag: &str, variants: &[String]) -> String;
+}
+
+/// Export a schema for every type in `type_map`, named `<TypeName>Schema`.
+pub(crate) fn render_types<S: Schema>(
+    schema: &S,
+    type_map: &TypeMap,
+) -> Result<String, ExportError> {
+    type_map
+        .iter()
+        .map(|(sid, ndt)| {
+            let name = ndt.name();
+            let generics = ndt
+                .inner
+                .generics()
+                .filter(|generics| !generics.is_empty())
+                .map(|generics| {
+                    generics
+                        .iter()
+                        .map(|g| format!("{g}: {g}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                });
+            let ty = datatype(schema, &ndt.inner, type_map, name)?;
+
+            Ok(match generics {
+                Some(args) => {
+                    let params = ndt
+                        .inner
+                        .generics()
+                        .into_iter()
+                        .flatten()
+                        .map(|g| format!("{g} extends {}", S::GENERIC_BOUND))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+
+                    format!("export const {name}Schema = <{params}>({args}) => {ty};")
+                }
+                // A recursive schema's type can't be inferred from its own initializer (TS7022), so the type is exported from the Typescript definition instead.
+                None if type_graph::shortest_cycle(type_map, sid).is_some() => {
+                    let definition = js_ts::datatype(
+                        &Typescript::default().bigint(schema.bigint().clone()),
+                        &ndt.inner,
+                        type_map,
+                    )?;
+
+                    format!(
+                        "export type {name} = {definition};\nexport const {name}Schema: {} = {ty};",
+                        S::annotation(name)
+                    )
+                }
+                // Generic schemas are functions, so their type can't be inferred without the arguments.
+                None => format!(
+                    "export const {name}Schema = {ty};\nexport type {name} = {};",
+                    S::infer(name)
+                ),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|v| v.join("\n\n"))
+}
+
+/// Render the schema of a type.
+pub(crate) fn datatype<S: Schema>(
+    schema: &S,
+    typ: &DataType,
+    type_map: &TypeMap,
+    path: &str,
+) -> Result<String, ExportError> {
+    let ns = S::NAMESPACE;
+
+    Ok(match typ {
+        DataType::Any => format!("{ns}.any()"),
+        DataType::Unknown => format!("{ns}.unknown()"),
+        DataType::Primitive(p) => match p {
+            PrimitiveType::i8
+            | PrimitiveType::i16
+            | PrimitiveType::i32
+            | PrimitiveType::u8
+            | PrimitiveType::u16
+            | PrimitiveType::u32
+            | PrimitiveType::f32
+            | PrimitiveType::f64 => format!("{ns}.number()"),
+            PrimitiveType::usize
+            | PrimitiveType::isize
+            | PrimitiveType::i64
+            | PrimitiveType::u64
+            | PrimitiveType::i128
+            | PrimitiveType::u128 => match schema.bigint() {
+                BigIntExportBehavior::String => format!("{ns}.string()"),
+                BigIntExportBehavior::Number => format!("{ns}.number()"),
+                BigIntExportBehavior::BigInt => format!("{ns}.bigint()"),
+                BigIntExportBehavior::Fail | BigIntExportBehavior::FailWithReason(_) => {
+                    return Err(ExportError::BigIntForbidden(ExportPath::new_unsafe(path)))
+                }
+            },
+            PrimitiveType::bool => format!("{ns}.boolean()"),
+            PrimitiveType::char | PrimitiveType::String => format!("{ns}.string()"),
+        },
+        DataType::Literal(l) => match l {
+            LiteralType::i8(v) => format!("{ns}.literal({v})"),
+            LiteralType::i16(v) => format!("{ns}.literal({v})"),
+            LiteralType::i32(v) => format!("{ns}.literal({v})"),
+            LiteralType::u8(v) => format!("{ns}.literal({v})"),
+            LiteralType::u16(v) => format!("{ns}.literal({v})"),
+            LiteralType::u32(v) => format!("{ns}.literal({v})"),
+            LiteralType::f32(v) => format!("{ns}.literal({v})"),
+            LiteralType::f64(v) => format!("{ns}.literal({v})"),
+            LiteralType::bool(v) => format!("{ns}.literal({v})"),
+            LiteralType::String(v) => format!("{ns}.literal({})", string(v)),
+            LiteralType::char(v) => format!("{ns}.literal({})", string(&v.to_string())),
+            LiteralType::None => format!("{ns}.null()"),
+            _ => format!("{ns}.unknown()"),
+        },
+        DataType::List(list) => {
+            let ty = datatype(schema, list.ty(), type_map, path)?;
+
+            match list.length() {
+                Some(length) => S::array_with_length(&ty, length),
+                None => format!("{ns}.array({ty})"),
+            }
+        }
+        DataType::Map(map) => format!(
+            "{ns}.record({}, {})",
+            datatype(schema, map.key_ty(), type_map, path)?,
+            datatype(schema, map.value_ty(), type_map, path)?
+        ),
+        DataType::Nullable(t) => S::nullable(&datatype(schema, t, type_map, path)?),
+        DataType::Struct(s) => struct_datatype(schema, s, type_map, path)?,
+        DataType::Enum(e) => enum_datatype(schema, e, type_map, path)?,
+        DataType::Tuple(t) => match &t.elements()[..] {
+            [] => format!("{ns}.null()"),
+            elements => format!(
+                "{ns}.tuple([{}])",
+                elements
+                    .iter()
+                    .map(|t| datatype(schema, t, type_map, path))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+        },
+        // Channels are instances of `Channel` from `@tauri-apps/api` which a schema can't describe.
+        DataType::Reference(r) if r.sid() == crate::channel_sid() => format!("{ns}.any()"),
+        DataType::Reference(r) => {
+            // Commands hold references to the types as they were named before being affixed.
+            let name = type_map
+                .get(r.sid())
+                .map(|ndt| ndt.name())
+                .unwrap_or_else(|| r.name());
+
+            match &r.generics()[..] {
+                [] => format!("{ns}.lazy(() => {name}Schema)"),
+                generics => format!(
+                    "{ns}.lazy(() => {name}Schema({}))",
+                    generics
+                        .iter()
+                        .map(|(_, t)| datatype(schema, t, type_map, path))
+                        .collect::<Result<Vec<_>, _>>()?
+                        .join(", ")
+                ),
+            }
+        }
+        DataType::Generic(g) => g.to_string(),
+    })
+}
+
+fn struct_datatype<S: Schema>(
+    schema: &S,
+    s: &StructType,
+    type_map: &TypeMap,
+    path: &str,
+) -> Result<String, ExportError> {
+    let ns = S::NAMESPACE;
+
+    Ok(match s.fields() {
+        StructFields::Unit => format!("{ns}.null()"),
+        StructFields::Unnamed(fields) => unnamed_fields(schema, fields.fields(), type_map, path)?,
+        StructFields::Named(fields) => {
+            let tag = fields.tag().as_ref().map(|tag| {
+                (
+                    tag.to_string(),
+                    format!("{ns}.literal({})", string(s.name())),
+                )
+            });
+
+            object(schema, tag, fields.fields(), type_map, path)?
+        }
+    })
+}
+
+fn enum_datatype<S: Schema>(
+    schema: &S,
+    e: &EnumType,
+    type_map: &TypeMap,
+    path: &str,
+) -> Result<String, ExportError> {
+    let ns = S::NAMESPACE;
+    // Whether every variant is a plain object, which discriminated unions are limited to.
+    let mut plain = true;
+    let variants = e
+        .variants()
+        .iter()
+        .filter(|(_, variant)| !variant.skip())
+        .map(|(name, variant)| {
+            let literal = format!("{ns}.literal({})", string(name));
+
+            Ok(match (e.repr(), variant.inner()) {
+                (EnumRepr::Untagged, EnumVariants::Unit) => format!("{ns}.null()"),
+                (EnumRepr::Untagged, EnumVariants::Named(fields)) => {
+                    object(schema, None, fields.fields(), type_map, path)?
+                }
+                (EnumRepr::Untagged, EnumVariants::Unnamed(fields)) => {
+                    unnamed_fields(schema, fields.fields(), type_map, path)?
+                }
+                (EnumRepr::External, EnumVariants::Unit) => literal,
+                (EnumRepr::External, EnumVariants::Named(fields)) => format!(
+                    "{ns}.object({{ {}: {} }})",
+                    key(name),
+                    object(schema, None, fields.fields(), type_map, path)?
+                ),
+                (EnumRepr::External, EnumVariants::Unnamed(fields)) => format!(
+                    "{ns}.object({{ {}: {} }})",
+                    key(name),
+                    unnamed_fields(schema, fields.fields(), type_map, path)?
+                ),
+                (EnumRepr::Internal { tag }, EnumVariants::Unit) => {
+                    format!("{ns}.object({{ {}: {literal} }})", key(tag))
+                }
+                (EnumRepr::Internal { tag }, EnumVariants::Named(fields)) => {
+                    plain &= !fields.fields().iter().any(|(_, field)| field.flatten());
+                    object(
+                        schema,
+                        Some((tag.to_string(), literal)),
+                        fields.fields(),
+                        type_map,
+                        path,
+                    )?
+                }
+                (EnumRepr::Internal { tag }, EnumVariants::Unnamed(fields)) => {
+                    plain = false;
+                    S::intersect(vec![
+                        format!("{ns}.object({{ {}: {literal} }})", key(tag)),
+                        unnamed_fields(schema, fields.fields(), type_map, path)?,
+                    ])
+                }
+                (EnumRepr::Adjacent { tag, .. }, EnumVariants::Unit) => {
+                    format!("{ns}.object({{ {}: {literal} }})", key(tag))
+                }
+                (EnumRepr::Adjacent { tag, content }, EnumVariants::Named(fields)) => format!(
+                    "{ns}.object({{ {}: {literal}, {}: {} }})",
+                    key(tag),
+                    key(content),
+                    object(schema, None, fields.fields(), type_map, path)?
+                ),
+                (EnumRepr::Adjacent { tag, content }, EnumVariants::Unnamed(fields)) => format!(
+                    "{ns}.object({{ {}: {literal}, {}: {} }})",
+                    key(tag),
+                    key(content),
+                    unnamed_fields(schema, fields.fields(), type_map, path)?
+                ),
+            })
+        })
+        .collect::<Result<Vec<_>, ExportError>>()?;
+
+    let discriminator = match e.repr() {
+        EnumRepr::Internal { tag } | EnumRepr::Adjacent { tag, .. } => Some(tag),
+        EnumRepr::Untagged | EnumRepr::External => None,
+    };
+
+    Ok(match &variants[..] {
+        [] => format!("{ns}.never()"),
+        [variant] => variant.clone(),
+        variants => match discriminator {
+            Some(tag) if plain => S::discriminated_union(tag, variants),
+            _ => format!("{ns}.union([{}])", variants.join(", ")),
+        },
+    })
+}
+
+fn object<S: Schema>(
+    schema: &S,
+    tag: Option<(String, String)>,
+    fields: &[(Cow<'static, str>, Field)],
+    type_map: &TypeMap,
+    path: &str,
+) -> Result<String, ExportError> {
+    let mut properties = tag
+        .map(|(tag, schema)| format!("{}: {schema}", key(&tag)))
+        .into_iter()
+        .collect::<Vec<_>>();
+    let mut flattened = Vec::new();
+
+    for (name, field) in fields {
+        let Some(ty) = field.ty() else {
+            continue;
+        };
+        let ty = datatype(schema, ty, type_map, path)?;
+
+        if field.flatten() {
+            flattened.push(ty);
+        } else if field.optional() {
+            properties.push(format!("{}: {}", key(name), S::optional(&ty)));
+        } else {
+            properties.push(format!("{}: {ty}", key(name)));
+        }
+    }
+
+    let object = format!("{}.object({{ {} }})", S::NAMESPACE, properties.join(", "));
+    Ok(match flattened.is_empty() {
+        true => object,
+        false => S::intersect([object].into_iter().chain(flattened).collect()),
+    })
+}
+
+fn unnamed_fields<S: Schema>(
+    schema: &S,
+    fields: &[Field],
+    type_map: &TypeMap,
+    path: &str,
+) -> Result<String, ExportError> {
+    let ns = S::NAMESPACE;
+    let fields = fields
+        .iter()
+        .filter_map(|field| field.ty())
+        .map(|ty| datatype(schema, ty, type_map, path))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match &fields[..] {
+        [] => format!("{ns}.tuple([])"),
+        [field] => field.clone(),
+        fields => format!("{ns}.tuple([{}])", fields.join(", ")),
+    })
+}
+
+/// A property key, quoted unless it's a valid identifier.
+fn key(name: &str) -> String {
+    let is_ident = name.chars().enumerate().all(|(i, c)| {
+        c == '_' || c == '$' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+    });
+
+    match is_ident && !name.is_empty() {
+        true => name.to_string(),
+        false => string(name),
+    }
+}
+
+/// A Javascript string literal.
+pub(crate) fn string(s: &str) -> String {
+    serde_json::to_string(s).expect("failed to serialize string")
+}
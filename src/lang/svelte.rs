@@ -0,0 +1,107 @@
+//! [Svelte](https://svelte.dev) stores exporter.
+
+use specta::datatype::{DataType, Function};
+use specta_typescript::{ExportError, Typescript};
+
+use crate::{
+    lang::{
+        framework::{self, Command, Event},
+        js_ts,
+    },
+    ExportContext,
+};
+
+/// Render a store for every command and event.
+pub(crate) fn render(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    let cfg = &*js_ts::affix_types(cfg);
+    let commands = cfg
+        .commands
+        .iter()
+        .map(|function| command(ts, cfg, function))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    let events = cfg
+        .events
+        .iter()
+        .map(|(name, typ)| event(ts, cfg, name, typ))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    framework::render(
+        ts,
+        cfg,
+        &[
+            r#"import { readable, writable } from "svelte/store";"#.into(),
+            framework::core_import(cfg),
+            format!(
+                r#"import {{ listen as TAURI_LISTEN }} from "{}/event";"#,
+                js_ts::tauri_api(cfg)
+            ),
+            "\nexport type AsyncState<T, E> = { data: T | null; loading: boolean; error: E | null };"
+                .into(),
+        ],
+        &[("commands", commands), ("events", events)],
+    )
+}
+
+fn command(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    function: &Function,
+) -> Result<String, ExportError> {
+    let command = Command::new(ts, cfg, function)?;
+    let Command {
+        ident,
+        data,
+        error,
+        docs,
+        ..
+    } = &command;
+    let params = command.params();
+    let invoke = command.invoke(js_ts::arg_usages(&command.arg_names()));
+    let error = error.as_deref().unwrap_or("Error");
+
+    Ok(format!(
+        r#"{docs}export function {ident}({params}) {{
+    const {{ subscribe, update }} = writable<AsyncState<{data}, {error}>>({{ data: null, loading: true, error: null }});
+
+    async function refetch() {{
+        update((state) => ({{ ...state, loading: true, error: null }}));
+        try {{
+            const data = await {invoke};
+            update(() => ({{ data, loading: false, error: null }}));
+        }} catch (e) {{
+            update((state) => ({{ ...state, loading: false, error: e as {error} }}));
+        }}
+    }}
+
+    refetch();
+
+    return {{ subscribe, refetch }};
+}}"#
+    ))
+}
+
+fn event(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    name: &str,
+    typ: &DataType,
+) -> Result<String, ExportError> {
+    let Event {
+        name,
+        ident,
+        payload,
+        docs,
+    } = Event::new(ts, cfg, name, typ)?;
+
+    Ok(format!(
+        r#"{docs}export const {ident} = readable<{payload} | null>(null, (set) => {{
+    const unlisten = TAURI_LISTEN<{payload}>("{name}", (e) => set(e.payload));
+    return () => {{
+        unlisten.then((f) => f());
+    }};
+}});"#
+    ))
+}
@@ -0,0 +1,851 @@
+//! [Swift](https://www.swift.org) exporter.
+
+use std::{borrow::Cow, io, path::Path};
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use specta::{
+    datatype::{
+        DataType, EnumRepr, EnumType, EnumVariants, GenericType, LiteralType, NamedDataType,
+        NamedFields, PrimitiveType, StructFields, StructType,
+    },
+    Language, SpectaID, TypeMap,
+};
+
+use crate::{ExportContext, LanguageExt};
+
+use super::DO_NOT_EDIT;
+
+/// Holds arbitrary JSON for the types which can't be represented in Swift.
+const JSON_VALUE: &str = r#"enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    {access}init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    {access}func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null: try container.encodeNil()
+        case .bool(let value): try container.encode(value)
+        case .number(let value): try container.encode(value)
+        case .string(let value): try container.encode(value)
+        case .array(let value): try container.encode(value)
+        case .object(let value): try container.encode(value)
+        }
+    }
+}"#;
+
+/// A coding key for the tags and keys of enums, which serde encodes differently to Swift.
+const ANY_CODING_KEY: &str = r#"private struct AnyCodingKey: CodingKey {
+    var stringValue: String
+    var intValue: Int? { nil }
+
+    init(_ stringValue: String) { self.stringValue = stringValue }
+    init?(stringValue: String) { self.stringValue = stringValue }
+    init?(intValue: Int) { return nil }
+}"#;
+
+/// The access level of the types exported by [`Swift`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SwiftAccessLevel {
+    /// The types can be used from other modules, Eg. the app consuming your plugin's Swift package.
+    Public,
+    /// The types can only be used within the module they're exported into.
+    #[default]
+    Internal,
+}
+
+/// [Swift](https://www.swift.org) exporter.
+///
+/// Generates a `Codable` type for every type so the payloads of your commands and events can be decoded on iOS, Eg. in a Tauri mobile plugin.
+/// Commands and events themselves aren't exported as they're only called from the webview.
+///
+/// Structs become structs with `CodingKeys` for any renamed fields and enums become enums, using associated values when their variants hold data.
+/// Types which contain themselves are exported as final classes or indirect enums.
+/// Types which can't be represented in Swift, such as tuple structs or flattened fields, are exported as a `JSONValue` alias.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, Swift, SwiftAccessLevel};
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(
+///         Swift::new().access(SwiftAccessLevel::Public),
+///         "../ios/Sources/Bindings.swift",
+///     )
+///     .expect("Failed to export swift bindings");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Swift {
+    /// The file's header
+    pub header: Cow<'static, str>,
+    /// The access level of the exported types.
+    pub access: SwiftAccessLevel,
+}
+
+impl Swift {
+    /// Construct a new Swift exporter with the default options configured.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure a header for the file.
+    ///
+    /// This is perfect for configuring lint ignore rules or other file-level comments.
+    pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Configure the access level of the exported types.
+    pub fn access(mut self, access: SwiftAccessLevel) -> Self {
+        self.access = access;
+        self
+    }
+
+    fn access_modifier(&self) -> &'static str {
+        match self.access {
+            SwiftAccessLevel::Public => "public ",
+            SwiftAccessLevel::Internal => "",
+        }
+    }
+}
+
+impl Language for Swift {
+    type Error = io::Error;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        Ok(render_types(self, &type_map))
+    }
+
+    fn format(&self, _: &Path) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl LanguageExt for Swift {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        Ok(render_types(self, &cfg.type_map))
+    }
+
+    fn extension(&self) -> Option<&'static str> {
+        Some("swift")
+    }
+}
+
+fn render_types(swift: &Swift, type_map: &TypeMap) -> String {
+    let header = &swift.header;
+    let access = swift.access_modifier();
+    let mut types = type_map
+        .iter()
+        .map(|(sid, ndt)| named_datatype(swift, sid, ndt, type_map))
+        .collect::<Vec<_>>();
+
+    // The helpers are only included when they're used.
+    if types.iter().any(|ty| ty.contains("JSONValue")) {
+        types.push(format!(
+            "/// Arbitrary JSON for types which can't be represented in Swift.\n{access}{}",
+            JSON_VALUE.replace("{access}", access)
+        ));
+    }
+    if types.iter().any(|ty| ty.contains("AnyCodingKey")) {
+        types.push(ANY_CODING_KEY.into());
+    }
+
+    let types = types.join("\n\n");
+
+    format! {
+        r#"{header}
+{DO_NOT_EDIT}
+
+import Foundation
+
+{types}
+"#
+    }
+}
+
+fn named_datatype(swift: &Swift, sid: SpectaID, ndt: &NamedDataType, type_map: &TypeMap) -> String {
+    let name = ndt.name();
+    let generics = generics(ndt.inner.generics().map(|g| &g[..]).unwrap_or_default());
+    let recursive = holds(&ndt.inner, sid, type_map, &mut Vec::new());
+
+    let class = match &ndt.inner {
+        DataType::Struct(s) => struct_class(swift, name, &generics, s, recursive),
+        DataType::Enum(e) => enum_class(swift, name, &generics, e, recursive),
+        _ => None,
+    }
+    .unwrap_or_else(|| {
+        format!(
+            "{}typealias {name}{generics} = {}",
+            swift.access_modifier(),
+            datatype(&ndt.inner)
+        )
+    });
+
+    format!("{}{class}", docs(ndt.docs(), ""))
+}
+
+fn struct_class(
+    swift: &Swift,
+    name: &str,
+    generics: &str,
+    s: &StructType,
+    recursive: bool,
+) -> Option<String> {
+    let access = swift.access_modifier();
+
+    match s.fields() {
+        // Unit structs are serialized as `null` which a struct can't represent.
+        StructFields::Unit => None,
+        StructFields::Unnamed(fields) => match &fields.fields()[..] {
+            [field] => Some(format!(
+                "{access}typealias {name}{generics} = {}",
+                datatype(field.ty()?)
+            )),
+            _ => None,
+        },
+        StructFields::Named(fields) => {
+            let tag = fields.tag().as_ref().map(|tag| (tag, name));
+            // Swift requires types which hold themselves to be boxed.
+            let keyword = match recursive {
+                true => "final class",
+                false => "struct",
+            };
+
+            Some(format!(
+                "{access}{keyword} {name}{generics}: Codable {{\n{}}}",
+                body(swift, tag, fields, "    ")?
+            ))
+        }
+    }
+}
+
+/// The properties, initializer and `CodingKeys` of a type with named fields.
+///
+/// Returns `None` if the fields can't be represented in Swift.
+fn body(
+    swift: &Swift,
+    tag: Option<(&Cow<'static, str>, &str)>,
+    fields: &NamedFields,
+    indent: &str,
+) -> Option<String> {
+    let access = swift.access_modifier();
+    let mut properties = Vec::new();
+    let mut params = Vec::new();
+    let mut assignments = Vec::new();
+    let mut keys = Vec::new();
+
+    if let Some((tag, value)) = tag {
+        let property = ident(&tag.to_lower_camel_case());
+        properties.push(format!(
+            "{indent}{access}var {property}: String = {}",
+            string(value)
+        ));
+        keys.push((property, tag.to_string()));
+    }
+
+    for (name, field) in fields.fields() {
+        let Some(ty) = field.ty() else {
+            continue;
+        };
+        if field.flatten() {
+            return None;
+        }
+
+        let property = ident(&name.to_lower_camel_case());
+        let ty = match field.optional() {
+            true => optional(datatype(ty)),
+            false => datatype(ty),
+        };
+        let default = match ty.ends_with('?') {
+            true => " = nil",
+            false => "",
+        };
+
+        properties.push(format!(
+            "{}{indent}{access}var {property}: {ty}",
+            docs(field.docs(), indent)
+        ));
+        params.push(format!("{property}: {ty}{default}"));
+        assignments.push(format!("{indent}    self.{property} = {property}"));
+        keys.push((property, name.to_string()));
+    }
+
+    let mut body = properties.join("\n");
+    if !body.is_empty() {
+        body.push_str("\n\n");
+    }
+    body.push_str(&format!(
+        "{indent}{access}init({}) {{\n{}",
+        params.join(", "),
+        assignments
+            .iter()
+            .map(|assignment| format!("{assignment}\n"))
+            .collect::<String>()
+    ));
+    body.push_str(&format!("{indent}}}\n"));
+
+    // `CodingKeys` are only required when a property is renamed.
+    if keys
+        .iter()
+        .any(|(property, name)| property.trim_matches('`') != name)
+    {
+        let cases = keys
+            .iter()
+            .map(
+                |(property, name)| match property.trim_matches('`') == name {
+                    true => format!("{indent}    case {property}\n"),
+                    false => format!("{indent}    case {property} = {}\n", string(name)),
+                },
+            )
+            .collect::<String>();
+
+        body.push_str(&format!(
+            "\n{indent}enum CodingKeys: String, CodingKey {{\n{cases}{indent}}}\n"
+        ));
+    }
+
+    Some(body)
+}
+
+/// A case of an enum, holding at most one value.
+struct Case {
+    ident: String,
+    serial_name: String,
+    payload: Option<String>,
+}
+
+fn enum_class(
+    swift: &Swift,
+    name: &str,
+    generics: &str,
+    e: &EnumType,
+    recursive: bool,
+) -> Option<String> {
+    let access = swift.access_modifier();
+    let variants = e
+        .variants()
+        .iter()
+        .filter(|(_, variant)| !variant.skip())
+        .collect::<Vec<_>>();
+
+    if let EnumRepr::External = e.repr() {
+        if variants
+            .iter()
+            .all(|(_, variant)| matches!(variant.inner(), EnumVariants::Unit))
+        {
+            let cases = variants
+                .iter()
+                .map(|(serial_name, variant)| {
+                    format!(
+                        "{}    case {} = {}\n",
+                        docs(variant.docs(), "    "),
+                        ident(&serial_name.to_lower_camel_case()),
+                        string(serial_name)
+                    )
+                })
+                .collect::<String>();
+
+            return Some(format!(
+                "{access}enum {name}{generics}: String, Codable {{\n{cases}}}"
+            ));
+        }
+    }
+
+    // Variants with named fields hold a nested struct so every case has at most one value.
+    let mut nested = Vec::new();
+    let mut cases = Vec::new();
+    let mut declarations = Vec::new();
+
+    for (serial_name, variant) in &variants {
+        let ident = ident(&serial_name.to_lower_camel_case());
+        let payload = match variant.inner() {
+            EnumVariants::Unit => None,
+            EnumVariants::Unnamed(fields) => match &fields.fields()[..] {
+                [field] => Some(datatype(field.ty()?)),
+                _ => Some("[JSONValue]".to_string()),
+            },
+            EnumVariants::Named(fields) => {
+                let fields_name = format!("{}Fields", serial_name.to_upper_camel_case());
+                nested.push(format!(
+                    "    {access}struct {fields_name}: Codable {{\n{}    }}",
+                    body(swift, None, fields, "        ")?
+                ));
+                Some(fields_name)
+            }
+        };
+
+        declarations.push(format!(
+            "{}    case {ident}{}\n",
+            docs(variant.docs(), "    "),
+            payload
+                .as_ref()
+                .map(|payload| format!("({payload})"))
+                .unwrap_or_default()
+        ));
+        cases.push(Case {
+            ident,
+            serial_name: serial_name.to_string(),
+            payload,
+        });
+    }
+
+    let (decode, encode) = coding(name, e.repr(), &cases);
+    let indirect = match recursive {
+        true => "indirect ",
+        false => "",
+    };
+    let nested = nested
+        .into_iter()
+        .map(|nested| format!("\n{nested}\n"))
+        .collect::<String>();
+
+    Some(format!(
+        r#"{access}{indirect}enum {name}{generics}: Codable {{
+{}{nested}
+    {access}init(from decoder: Decoder) throws {{
+{decode}
+    }}
+
+    {access}func encode(to encoder: Encoder) throws {{
+{encode}
+    }}
+}}"#,
+        declarations.concat()
+    ))
+}
+
+/// The bodies of `init(from:)` and `encode(to:)` for an enum, matching how serde represents it.
+fn coding(name: &str, repr: &EnumRepr, cases: &[Case]) -> (String, String) {
+    let unknown = format!(
+        r#"throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Unknown {name} variant"))"#
+    );
+
+    match repr {
+        EnumRepr::External => {
+            let units = cases
+                .iter()
+                .filter(|case| case.payload.is_none())
+                .map(|case| {
+                    format!(
+                        "            case {}: self = .{}; return\n",
+                        string(&case.serial_name),
+                        case.ident
+                    )
+                })
+                .collect::<String>();
+            let values = cases
+                .iter()
+                .filter_map(|case| Some((case, case.payload.as_ref()?)))
+                .map(|(case, payload)| {
+                    let key = format!("AnyCodingKey({})", string(&case.serial_name));
+                    format!(
+                        "        if container.contains({key}) {{\n            self = .{}(try container.decode({payload}.self, forKey: {key}))\n            return\n        }}\n",
+                        case.ident
+                    )
+                })
+                .collect::<String>();
+
+            let mut decode = String::new();
+            if !units.is_empty() {
+                decode.push_str(&format!("        if let value = try? decoder.singleValueContainer().decode(String.self) {{\n            switch value {{\n{units}            default: break\n            }}\n        }}\n"));
+            }
+            decode.push_str(&format!(
+                "        let container = try decoder.container(keyedBy: AnyCodingKey.self)\n{values}        {unknown}"
+            ));
+
+            let encode = cases
+                .iter()
+                .map(|case| match &case.payload {
+                    None => format!(
+                        "        case .{}:\n            var container = encoder.singleValueContainer()\n            try container.encode({})\n",
+                        case.ident,
+                        string(&case.serial_name)
+                    ),
+                    Some(_) => format!(
+                        "        case .{}(let value):\n            var container = encoder.container(keyedBy: AnyCodingKey.self)\n            try container.encode(value, forKey: AnyCodingKey({}))\n",
+                        case.ident,
+                        string(&case.serial_name)
+                    ),
+                })
+                .collect::<String>();
+
+            (decode, switch(&encode))
+        }
+        EnumRepr::Internal { tag } => {
+            let key = format!("AnyCodingKey({})", string(tag));
+            let decode = tagged_decode(&key, &unknown, cases, |payload| {
+                format!("try decoder.singleValueContainer().decode({payload}.self)")
+            });
+
+            // serde merges the tag into the value's fields.
+            let encode = cases
+                .iter()
+                .map(|case| {
+                    let (pattern, value) = match &case.payload {
+                        None => (String::new(), String::new()),
+                        Some(_) => (
+                            "(let value)".to_string(),
+                            "            try value.encode(to: encoder)\n".to_string(),
+                        ),
+                    };
+
+                    format!(
+                        "        case .{}{pattern}:\n{value}            var container = encoder.container(keyedBy: AnyCodingKey.self)\n            try container.encode({}, forKey: {key})\n",
+                        case.ident,
+                        string(&case.serial_name)
+                    )
+                })
+                .collect::<String>();
+
+            (decode, switch(&encode))
+        }
+        EnumRepr::Adjacent { tag, content } => {
+            let tag = format!("AnyCodingKey({})", string(tag));
+            let content = format!("AnyCodingKey({})", string(content));
+            let decode = tagged_decode(&tag, &unknown, cases, |payload| {
+                format!("try container.decode({payload}.self, forKey: {content})")
+            });
+
+            let encode = cases
+                .iter()
+                .map(|case| {
+                    let (pattern, value) = match &case.payload {
+                        None => (String::new(), String::new()),
+                        Some(_) => (
+                            "(let value)".to_string(),
+                            format!("\n            try container.encode(value, forKey: {content})"),
+                        ),
+                    };
+
+                    format!(
+                        "        case .{}{pattern}:\n            try container.encode({}, forKey: {tag}){value}\n",
+                        case.ident,
+                        string(&case.serial_name)
+                    )
+                })
+                .collect::<String>();
+
+            (
+                decode,
+                format!(
+                    "        var container = encoder.container(keyedBy: AnyCodingKey.self)\n{}",
+                    switch(&encode)
+                ),
+            )
+        }
+        // Each variant is attempted in order, like serde.
+        EnumRepr::Untagged => {
+            let decode = cases
+                .iter()
+                .map(|case| match &case.payload {
+                    None => format!(
+                        "        if (try? decoder.singleValueContainer().decodeNil()) == true {{\n            self = .{}\n            return\n        }}\n",
+                        case.ident
+                    ),
+                    Some(payload) => format!(
+                        "        if let value = try? decoder.singleValueContainer().decode({payload}.self) {{\n            self = .{}(value)\n            return\n        }}\n",
+                        case.ident
+                    ),
+                })
+                .collect::<String>()
+                + "        "
+                + &unknown;
+
+            let encode = cases
+                .iter()
+                .map(|case| match &case.payload {
+                    None => format!("        case .{}: try container.encodeNil()\n", case.ident),
+                    Some(_) => format!(
+                        "        case .{}(let value): try container.encode(value)\n",
+                        case.ident
+                    ),
+                })
+                .collect::<String>();
+
+            (
+                decode,
+                format!(
+                    "        var container = encoder.singleValueContainer()\n{}",
+                    switch(&encode)
+                ),
+            )
+        }
+    }
+}
+
+/// Decode the variant named by the tag `key`, decoding any value with `value`.
+fn tagged_decode(
+    key: &str,
+    unknown: &str,
+    cases: &[Case],
+    value: impl Fn(&str) -> String,
+) -> String {
+    let cases = cases
+        .iter()
+        .map(|case| {
+            let value = case
+                .payload
+                .as_ref()
+                .map(|payload| format!("({})", value(payload)))
+                .unwrap_or_default();
+
+            format!(
+                "        case {}: self = .{}{value}\n",
+                string(&case.serial_name),
+                case.ident
+            )
+        })
+        .collect::<String>();
+
+    format!("        let container = try decoder.container(keyedBy: AnyCodingKey.self)\n        switch try container.decode(String.self, forKey: {key}) {{\n{cases}        default: {unknown}\n        }}")
+}
+
+fn switch(cases: &str) -> String {
+    format!("        switch self {{\n{cases}        }}")
+}
+
+/// Whether `ty` holds the type `target` by value, which Swift requires to be boxed.
+///
+/// Arrays and dictionaries already box their elements so they're skipped.
+fn holds(ty: &DataType, target: SpectaID, type_map: &TypeMap, visited: &mut Vec<SpectaID>) -> bool {
+    match ty {
+        DataType::Nullable(t) => holds(t, target, type_map, visited),
+        DataType::Struct(s) => match s.fields() {
+            StructFields::Unit => false,
+            StructFields::Unnamed(fields) => fields
+                .fields()
+                .iter()
+                .filter_map(|field| field.ty())
+                .any(|ty| holds(ty, target, type_map, visited)),
+            StructFields::Named(fields) => fields
+                .fields()
+                .iter()
+                .filter_map(|(_, field)| field.ty())
+                .any(|ty| holds(ty, target, type_map, visited)),
+        },
+        DataType::Enum(e) => e
+            .variants()
+            .iter()
+            .any(|(_, variant)| match variant.inner() {
+                EnumVariants::Unit => false,
+                EnumVariants::Unnamed(fields) => fields
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.ty())
+                    .any(|ty| holds(ty, target, type_map, visited)),
+                EnumVariants::Named(fields) => fields
+                    .fields()
+                    .iter()
+                    .filter_map(|(_, field)| field.ty())
+                    .any(|ty| holds(ty, target, type_map, visited)),
+            }),
+        DataType::Reference(r) => {
+            if r.sid() == target {
+                return true;
+            }
+            if r.generics()
+                .iter()
+                .any(|(_, ty)| holds(ty, target, type_map, visited))
+            {
+                return true;
+            }
+            if visited.contains(&r.sid()) {
+                return false;
+            }
+
+            visited.push(r.sid());
+            type_map
+                .get(r.sid())
+                .is_some_and(|ndt| holds(&ndt.inner, target, type_map, visited))
+        }
+        _ => false,
+    }
+}
+
+fn datatype(typ: &DataType) -> String {
+    match typ {
+        DataType::Any | DataType::Unknown => "JSONValue".into(),
+        DataType::Primitive(p) => match p {
+            PrimitiveType::i8 => "Int8",
+            PrimitiveType::i16 => "Int16",
+            PrimitiveType::i32 => "Int32",
+            PrimitiveType::i64 => "Int64",
+            PrimitiveType::isize => "Int",
+            PrimitiveType::u8 => "UInt8",
+            PrimitiveType::u16 => "UInt16",
+            PrimitiveType::u32 => "UInt32",
+            PrimitiveType::u64 => "UInt64",
+            PrimitiveType::usize => "UInt",
+            // `Int128` isn't `Codable` on older platforms so the raw number is kept.
+            PrimitiveType::i128 | PrimitiveType::u128 => "JSONValue",
+            PrimitiveType::f32 => "Float",
+            PrimitiveType::f64 => "Double",
+            PrimitiveType::bool => "Bool",
+            // `Character` isn't `Codable`.
+            PrimitiveType::char | PrimitiveType::String => "String",
+        }
+        .into(),
+        DataType::Literal(l) => match l {
+            LiteralType::i8(_) => "Int8",
+            LiteralType::i16(_) => "Int16",
+            LiteralType::i32(_) => "Int32",
+            LiteralType::u8(_) => "UInt8",
+            LiteralType::u16(_) => "UInt16",
+            LiteralType::u32(_) => "UInt32",
+            LiteralType::f32(_) => "Float",
+            LiteralType::f64(_) => "Double",
+            LiteralType::bool(_) => "Bool",
+            LiteralType::String(_) | LiteralType::char(_) => "String",
+            _ => "JSONValue",
+        }
+        .into(),
+        DataType::List(list) => format!("[{}]", datatype(list.ty())),
+        DataType::Map(map) => format!("[{}: {}]", datatype(map.key_ty()), datatype(map.value_ty())),
+        DataType::Nullable(t) => optional(datatype(t)),
+        // Anonymous structs and enums have no type to decode into.
+        DataType::Struct(_) | DataType::Enum(_) => "JSONValue".into(),
+        DataType::Tuple(t) => match &t.elements()[..] {
+            [] => "JSONValue".into(),
+            _ => "[JSONValue]".into(),
+        },
+        // Channels are sent over IPC as a string containing their ID.
+        DataType::Reference(r) if r.sid() == crate::channel_sid() => "String".into(),
+        DataType::Reference(r) => match &r.generics()[..] {
+            [] => r.name().to_string(),
+            generics => format!(
+                "{}<{}>",
+                r.name(),
+                generics
+                    .iter()
+                    .map(|(_, t)| datatype(t))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        },
+        DataType::Generic(g) => g.to_string(),
+    }
+}
+
+fn optional(ty: String) -> String {
+    match ty.ends_with('?') {
+        true => ty,
+        false => format!("{ty}?"),
+    }
+}
+
+fn generics(generics: &[GenericType]) -> String {
+    match generics {
+        [] => String::new(),
+        generics => format!(
+            "<{}>",
+            generics
+                .iter()
+                .map(|g| format!("{g}: Codable"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn docs(docs: &str, indent: &str) -> String {
+    docs.lines()
+        .map(|line| format!("{indent}/// {}\n", line.strip_prefix(' ').unwrap_or(line)))
+        .collect()
+}
+
+/// Escape Swift's keywords with backticks.
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "associatedtype",
+        "class",
+        "deinit",
+        "enum",
+        "extension",
+        "fileprivate",
+        "func",
+        "import",
+        "init",
+        "inout",
+        "internal",
+        "let",
+        "open",
+        "operator",
+        "private",
+        "precedencegroup",
+        "protocol",
+        "public",
+        "rethrows",
+        "static",
+        "struct",
+        "subscript",
+        "typealias",
+        "var",
+        "break",
+        "case",
+        "catch",
+        "continue",
+        "default",
+        "defer",
+        "do",
+        "else",
+        "fallthrough",
+        "for",
+        "guard",
+        "if",
+        "in",
+        "repeat",
+        "return",
+        "throw",
+        "switch",
+        "where",
+        "while",
+        "Any",
+        "as",
+        "await",
+        "false",
+        "is",
+        "nil",
+        "self",
+        "Self",
+        "super",
+        "throws",
+        "true",
+        "try",
+    ];
+
+    match KEYWORDS.contains(&name) {
+        true => format!("`{name}`"),
+        false => name.to_string(),
+    }
+}
+
+fn string(s: &str) -> String {
+    serde_json::to_string(s).expect("failed to serialize string")
+}
@@ -1,71 +1,77 @@
-use crate::{lang::js_ts, ExportContext, LanguageExt};
+use std::{borrow::Cow, collections::BTreeSet};
+
+use crate::{lang::js_ts, DirConfig, ExportContext, LanguageExt, ModuleFormat};
 use heck::ToLowerCamelCase;
-use specta::datatype::FunctionResultVariant;
-use specta_typescript::{self as ts, Typescript};
+use specta::{
+    datatype::{DataType, Function, FunctionResultVariant},
+    TypeMap,
+};
+use specta_typescript::Typescript;
 use specta_typescript::{js_doc, ExportError};
 
 const GLOBALS: &str = include_str!("./globals.ts");
 
+/// Shared by every file when exporting to a directory, so the globals aren't duplicated.
+const GLOBALS_EXPORTS: &str =
+    "export { TAURI_INVOKE, TAURI_CHANNEL, type TAURI_INVOKE_OPTIONS, __makeEvents__, __channelStream__ };";
+
 impl LanguageExt for specta_typescript::Typescript {
     fn render(&self, cfg: &ExportContext) -> Result<String, ExportError> {
-        let dependant_types = cfg
-            .type_map
-            .iter()
-            .map(|(_sid, ndt)| ts::export_named_datatype(&self, ndt, &cfg.type_map))
+        let cfg = &*js_ts::affix_types(cfg);
+        let dependant_types = js_ts::type_imports(cfg)
+            .map(Ok)
+            .chain(
+                cfg.type_map
+                    .iter()
+                    .map(|(sid, ndt)| js_ts::named_datatype(self, cfg, sid, ndt)),
+            )
+            .chain(js_ts::type_aliases(cfg).map(Ok))
             .collect::<Result<Vec<_>, _>>()
             .map(|v| v.join("\n"))?;
 
-        js_ts::render_all_parts::<Self>(
-            cfg,
+        js_ts::render_all_parts(
             &dependant_types,
-            GLOBALS,
+            &js_ts::globals(GLOBALS, cfg),
             &self.header,
-            render_commands(self, cfg)?,
-            render_events(self, cfg)?,
-            true,
+            render_commands(self, cfg, &[])? + &js_ts::commands_type(self, cfg)?,
+            js_ts::event_names(cfg)
+                + &js_ts::events_type(self, cfg)?
+                + &render_events(self, cfg, &[])?
+                + &render_namespaces(self, cfg, &[])?,
+            js_ts::constants(cfg, self, true)?,
         )
     }
 }
 
-fn render_commands(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
-    let commands = cfg
-        .commands
-        .iter()
+fn render_commands(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    let commands = js_ts::namespace_commands(cfg, path)
         .map(|function| {
-            let arg_defs = function
-                .args()
-                .map(|(name, typ)| {
-                    ts::datatype(
-                        ts,
-                        &FunctionResultVariant::Value(typ.clone()),
-                        &cfg.type_map,
-                    )
-                    .map(|ty| format!("{}: {}", name.to_lower_camel_case(), ty))
-                })
-                .collect::<Result<Vec<_>, _>>()?;
-
-            let ret_type = js_ts::handle_result(function, &cfg.type_map, ts, cfg.error_handling)?;
-
-            let docs = {
-                let mut builder = js_doc::Builder::default();
+            let (docs, mut arg_defs, ret_type) = command_signature(ts, cfg, function)?;
 
-                if let Some(d) = &function.deprecated() {
-                    builder.push_deprecated(d);
-                }
-
-                if !function.docs().is_empty() {
-                    builder.extend(function.docs().split("\n"));
-                }
-
-                builder.build()
-            };
-            Ok(js_ts::function(
+            let mut methods = vec![js_ts::function(
                 &docs,
-                &function.name().to_lower_camel_case(),
+                &cfg.command_ident(function.name()),
                 &arg_defs,
                 Some(&ret_type),
-                &js_ts::command_body(&cfg.plugin_name, function, true, cfg.error_handling),
-            ))
+                &js_ts::command_body(cfg, function, true),
+            )];
+            if let Some((_, item)) = js_ts::stream_channel(cfg, function) {
+                let item = js_ts::datatype(ts, item, &cfg.type_map)?;
+                arg_defs.remove(function.args().count() - 1);
+                methods.push(js_ts::stream_function(
+                    cfg,
+                    &docs,
+                    function,
+                    &arg_defs,
+                    Some(&item),
+                ));
+            }
+
+            Ok(methods.join(",\n"))
         })
         .collect::<Result<Vec<_>, ExportError>>()?
         .join(",\n");
@@ -78,15 +84,71 @@ export const commands = {{
     })
 }
 
-fn render_events(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
-    if cfg.events.is_empty() {
+/// The docs, arguments and return type of a command.
+pub(crate) fn command_signature(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    function: &Function,
+) -> Result<(String, Vec<String>, String), ExportError> {
+    let mut arg_defs = function
+        .args()
+        .map(|(name, typ)| {
+            js_ts::datatype(ts, typ, &cfg.type_map)
+                .map(|ty| format!("{}: {}", name.to_lower_camel_case(), ty))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if cfg.invoke_options {
+        arg_defs.push("options?: TAURI_INVOKE_OPTIONS".to_string());
+    }
+
+    let ret_type = js_ts::handle_result(function, &cfg.type_map, ts, cfg.error_handling)?;
+
+    let docs = {
+        let mut builder = js_doc::Builder::default();
+
+        if let Some(d) = js_ts::command_deprecated(cfg, function) {
+            builder.push_deprecated(d);
+        }
+
+        if !function.docs().is_empty() {
+            builder.extend(function.docs().split("\n"));
+        }
+
+        if let Some(throws) = js_ts::throws(function, &cfg.type_map, ts, cfg.error_handling)? {
+            builder.push(&throws);
+        }
+
+        if let Some(permission) = js_ts::required_permission(cfg, function) {
+            builder.push(&permission);
+        }
+
+        builder.build()
+    };
+
+    Ok((docs, arg_defs, ret_type))
+}
+
+fn render_events(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    let events = js_ts::namespace_events(cfg, path);
+    if events.is_empty() {
         return Ok(Default::default());
     }
 
-    let (events_types, events_map) =
-        js_ts::events_data(&cfg.events, ts, &cfg.plugin_name, &cfg.type_map)?;
+    let events_types = js_ts::events_types(&events, ts, cfg)?;
+    let events_map = js_ts::event_name_refs(cfg, &events);
+    let windows = js_ts::event_windows_arg(cfg, &events);
 
-    let events_types = events_types.join(",\n");
+    // Docs on the type parameter's properties are preserved by `__makeEvents__`'s mapped type so they show up on hover.
+    let events_types = events
+        .iter()
+        .zip(events_types)
+        .map(|((name, typ), ty)| format!("{}{ty}", js_ts::event_docs(name, typ, cfg)))
+        .collect::<Vec<_>>()
+        .join(",\n");
 
     Ok(format! {
         r#"
@@ -94,6 +156,227 @@ export const events = __makeEvents__<{{
 {events_types}
 }}>({{
 {events_map}
-}})"#
+}}{windows})"#
     })
 }
+
+fn render_namespaces(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    path: &[Cow<'static, str>],
+) -> Result<String, ExportError> {
+    js_ts::namespace_children(cfg, path)
+        .into_iter()
+        .map(|name| {
+            let path = [path, std::slice::from_ref(name)].concat();
+
+            let commands = match js_ts::namespace_commands(cfg, &path).next() {
+                Some(_) => render_commands(ts, cfg, &path)?,
+                None => String::new(),
+            };
+            let events = render_events(ts, cfg, &path)?;
+            let namespaces = render_namespaces(ts, cfg, &path)?;
+
+            Ok(format! {
+                r#"
+
+export namespace {} {{{commands}{events}{namespaces}
+}}"#,
+                name.to_lower_camel_case()
+            })
+        })
+        .collect()
+}
+
+/// Render the bindings as multiple files.
+///
+/// Returns the name and contents of each file.
+pub(crate) fn render_dir(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    config: &DirConfig,
+) -> Result<Vec<(&'static str, String)>, ExportError> {
+    let cfg = &*js_ts::affix_types(cfg);
+    let header = format!("{}\n{}\n", ts.header, js_ts::DO_NOT_EDIT);
+    // Deno requires the extension on relative imports.
+    let ext = match cfg.module_format {
+        ModuleFormat::Deno => ".ts",
+        _ => "",
+    };
+    let mut files = Vec::new();
+    let mut index = Vec::new();
+
+    files.push((
+        "globals.ts",
+        format!(
+            "{header}\n{}\n{GLOBALS_EXPORTS}\n",
+            js_ts::globals(GLOBALS, cfg)
+        ),
+    ));
+    index.push(format!(
+        r#"export {{ __setInvokeHandler, __setEventHandlers, type Result }} from "./globals{ext}";"#
+    ));
+
+    let types = js_ts::type_imports(cfg)
+        .map(Ok)
+        .chain(
+            cfg.type_map
+                .iter()
+                .map(|(sid, ndt)| js_ts::named_datatype(ts, cfg, sid, ndt)),
+        )
+        .chain(js_ts::type_aliases(cfg).map(Ok))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n");
+    files.push((
+        "types.ts",
+        format!("{header}\nimport type {{ TAURI_CHANNEL }} from \"./globals{ext}\";\n\n{types}\n"),
+    ));
+    let type_names = cfg
+        .type_map
+        .iter()
+        .filter(|(sid, _)| !cfg.internal_types.contains(sid))
+        .map(|(_, ndt)| ndt.name().to_string())
+        .chain(cfg.type_aliases.keys().map(|name| name.to_string()))
+        .collect::<Vec<_>>();
+    if !type_names.is_empty() {
+        index.push(format!(
+            "export type {{ {} }} from \"./types{ext}\";",
+            type_names.join(", ")
+        ));
+    }
+
+    let command_types = cfg
+        .commands
+        .iter()
+        .flat_map(|function| {
+            function
+                .args()
+                .map(|(_, typ)| typ)
+                .chain(match function.result() {
+                    Some(FunctionResultVariant::Value(t)) => vec![t],
+                    Some(FunctionResultVariant::Result(t, e)) => vec![t, e],
+                    None => vec![],
+                })
+        })
+        .chain(cfg.events.iter().filter_map(|(name, typ)| {
+            // Namespaced events are rendered alongside the namespaced commands.
+            cfg.event_namespaces.contains_key(name).then_some(typ)
+        }));
+    let namespaces = js_ts::namespace_children(cfg, &[])
+        .into_iter()
+        .map(|name| name.to_lower_camel_case())
+        .collect::<Vec<_>>();
+    // Namespaced events map to the names exported alongside the root events.
+    let import_event_names = match cfg.event_namespaces.is_empty() {
+        true => String::new(),
+        false => format!("import {{ EVENT_NAMES }} from \"./events{ext}\";\n"),
+    };
+    files.push((
+        "commands.ts",
+        format!(
+            "{header}\nimport {{ TAURI_INVOKE, __makeEvents__, __channelStream__, type TAURI_CHANNEL, type TAURI_INVOKE_OPTIONS, type Result }} from \"./globals{ext}\";\n{import_event_names}{}\n{}{}{}\n",
+            import_types(command_types, &cfg.type_map, ext),
+            render_commands(ts, cfg, &[])?,
+            js_ts::commands_type(ts, cfg)?,
+            render_namespaces(ts, cfg, &[])?
+        ),
+    ));
+    index.push(format!(
+        r#"export type {{ Commands }} from "./commands{ext}";"#
+    ));
+    index.push(format!(
+        "export {{ {} }} from \"./commands{ext}\";",
+        ["commands".to_string()]
+            .into_iter()
+            .chain(namespaces)
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+
+    if !cfg.events.is_empty() {
+        let events = render_events(ts, cfg, &[])?;
+        let import_globals = match events.is_empty() {
+            true => String::new(),
+            false => format!("import {{ __makeEvents__ }} from \"./globals{ext}\";\n"),
+        };
+        files.push((
+            "events.ts",
+            format!(
+                "{header}\n{import_globals}{}{}{}{events}\n",
+                // The `Events` type includes the payloads of namespaced events.
+                import_types(cfg.events.values(), &cfg.type_map, ext),
+                js_ts::event_names(cfg),
+                js_ts::events_type(ts, cfg)?
+            ),
+        ));
+        if !events.is_empty() {
+            index.push(format!(r#"export {{ events }} from "./events{ext}";"#));
+        }
+        index.push(format!(
+            r#"export {{ EVENT_NAMES, type EventName }} from "./events{ext}";"#
+        ));
+        index.push(format!(r#"export type {{ Events }} from "./events{ext}";"#));
+    }
+
+    let enum_variants = js_ts::enum_variants(cfg);
+    if !cfg.constants.is_empty() || !cfg.constant_groups.is_empty() || !enum_variants.is_empty() {
+        let constant_types = cfg
+            .constants
+            .values()
+            .chain(
+                cfg.constant_groups
+                    .values()
+                    .flat_map(|group| group.values()),
+            )
+            .filter(|constant| js_ts::is_annotated(constant))
+            .map(|constant| &constant.ty);
+        files.push((
+            "constants.ts",
+            format!(
+                "{header}\n{}\n{}\n",
+                import_types(constant_types, &cfg.type_map, ext),
+                js_ts::constants(cfg, ts, true)?
+            ),
+        ));
+        index.push(format!(
+            "export {{ {} }} from \"./constants{ext}\";",
+            cfg.constants
+                .keys()
+                .map(|name| name.to_string())
+                .chain(
+                    cfg.constant_groups
+                        .keys()
+                        .map(|group| group.to_lower_camel_case())
+                )
+                .chain(enum_variants.into_iter().map(|(name, _)| name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    if config.index_file {
+        files.push(("index.ts", format!("{header}\n{}\n", index.join("\n"))));
+    }
+
+    Ok(files)
+}
+
+/// An `import type` statement for the named types referenced by `types`.
+fn import_types<'a>(
+    types: impl IntoIterator<Item = &'a DataType>,
+    type_map: &TypeMap,
+    ext: &str,
+) -> String {
+    let mut names = BTreeSet::new();
+    for typ in types {
+        js_ts::referenced_types(typ, type_map, &mut names);
+    }
+
+    match names.is_empty() {
+        true => String::new(),
+        false => format!(
+            "import type {{ {} }} from \"./types{ext}\";\n",
+            names.into_iter().collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
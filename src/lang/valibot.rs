@@ -0,0 +1,145 @@
+//! [Valibot](https://valibot.dev) schema exporter.
+
+use std::{borrow::Cow, path::Path};
+
+use specta::{Language, TypeMap};
+use specta_typescript::{BigIntExportBehavior, ExportError, FormatterFn};
+
+use crate::{ExportContext, LanguageExt};
+
+use super::{
+    js_ts,
+    schema::{self, render_types, Schema},
+};
+
+/// [Valibot](https://valibot.dev) schema exporter.
+///
+/// Generates a schema named `<TypeName>Schema` for every type so data can be validated at runtime.
+/// Every type which isn't generic is also exported as a `v.InferOutput` alias with the same name as the Typescript bindings, so they can be used interchangeably.
+/// Recursive types are exported as their Typescript definition instead, which their schema is annotated with.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, Valibot};
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(Valibot::default(), "../src/schemas.ts")
+///     .expect("Failed to export valibot schemas");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Valibot {
+    /// The file's header
+    pub header: Cow<'static, str>,
+    /// How BigInts should be exported.
+    pub bigint: BigIntExportBehavior,
+    /// How the resulting file should be formatted.
+    pub formatter: Option<FormatterFn>,
+}
+
+impl Valibot {
+    /// Construct a new Valibot exporter with the default options configured.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure a header for the file.
+    ///
+    /// This is perfect for configuring lint ignore rules or other file-level comments.
+    pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Configure the BigInt handling behaviour
+    pub fn bigint(mut self, bigint: BigIntExportBehavior) -> Self {
+        self.bigint = bigint;
+        self
+    }
+
+    /// Configure a function which is responsible for formatting the result file.
+    pub fn formatter(mut self, formatter: FormatterFn) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+}
+
+impl Schema for Valibot {
+    const NAMESPACE: &'static str = "v";
+    const GENERIC_BOUND: &'static str = "v.GenericSchema";
+
+    fn bigint(&self) -> &BigIntExportBehavior {
+        &self.bigint
+    }
+
+    fn infer(name: &str) -> String {
+        format!("v.InferOutput<typeof {name}Schema>")
+    }
+
+    fn annotation(name: &str) -> String {
+        format!("v.GenericSchema<{name}>")
+    }
+
+    fn nullable(schema: &str) -> String {
+        format!("v.nullable({schema})")
+    }
+
+    fn optional(schema: &str) -> String {
+        format!("v.optional({schema})")
+    }
+
+    fn array_with_length(schema: &str, length: usize) -> String {
+        format!("v.pipe(v.array({schema}), v.length({length}))")
+    }
+
+    fn intersect(schemas: Vec<String>) -> String {
+        format!("v.intersect([{}])", schemas.join(", "))
+    }
+
+    fn discriminated_union(tag: &str, variants: &[String]) -> String {
+        format!(
+            "v.variant({}, [{}])",
+            schema::string(tag),
+            variants.join(", ")
+        )
+    }
+}
+
+impl Language for Valibot {
+    type Error = ExportError;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        render_types(self, &type_map)
+    }
+
+    fn format(&self, path: &Path) -> Result<(), Self::Error> {
+        if let Some(formatter) = self.formatter {
+            formatter(path)?;
+        }
+        Ok(())
+    }
+}
+
+impl LanguageExt for Valibot {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        let cfg = &*js_ts::affix_types(cfg);
+        let header = &self.header;
+        let do_not_edit = js_ts::DO_NOT_EDIT;
+        let types = render_types(self, &cfg.type_map)?;
+
+        Ok(format! {
+            r#"{header}
+{do_not_edit}
+
+import * as v from "valibot";
+
+/** user-defined types **/
+
+{types}"#
+        })
+    }
+}
@@ -0,0 +1,139 @@
+//! [Vue 3](https://vuejs.org) composables exporter.
+
+use heck::ToUpperCamelCase;
+use specta::datatype::{DataType, Function};
+use specta_typescript::{ExportError, Typescript};
+
+use crate::{
+    lang::{
+        framework::{self, Command, Event},
+        js_ts,
+    },
+    ExportContext,
+};
+
+/// Render a composable for every command and event.
+pub(crate) fn render(ts: &Typescript, cfg: &ExportContext) -> Result<String, ExportError> {
+    let cfg = &*js_ts::affix_types(cfg);
+    let commands = cfg
+        .commands
+        .iter()
+        .map(|function| command(ts, cfg, function))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    let events = cfg
+        .events
+        .iter()
+        .map(|(name, typ)| event(ts, cfg, name, typ))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n\n");
+
+    framework::render(
+        ts,
+        cfg,
+        &[
+            r#"import { ref, unref, watch, onMounted, onUnmounted, type Ref } from "vue";"#.into(),
+            framework::core_import(cfg),
+            format!(
+                r#"import {{ listen as TAURI_LISTEN, type UnlistenFn }} from "{}/event";"#,
+                js_ts::tauri_api(cfg)
+            ),
+        ],
+        &[("commands", commands), ("events", events)],
+    )
+}
+
+fn command(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    function: &Function,
+) -> Result<String, ExportError> {
+    let command = Command::new(ts, cfg, function)?;
+    let Command {
+        ident,
+        args,
+        data,
+        error,
+        docs,
+        ..
+    } = &command;
+    let composable = format!("use{}", ident.to_upper_camel_case());
+
+    let params = args
+        .iter()
+        .map(|(name, ty)| format!("{name}: Ref<{ty}> | {ty}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let invoke = command.invoke((!args.is_empty()).then(|| {
+        format!(
+            "{{ {} }}",
+            args.iter()
+                .map(|(name, _)| format!("{name}: unref({name})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }));
+    // Refetch whenever a reactive argument changes.
+    let watch = match args.is_empty() {
+        true => String::new(),
+        false => format!(
+            "\n    watch(() => [{}], refetch);",
+            args.iter()
+                .map(|(name, _)| format!("unref({name})"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let error = error.as_deref().unwrap_or("Error");
+
+    Ok(format!(
+        r#"{docs}export function {composable}({params}) {{
+    const data = ref<{data} | null>(null);
+    const loading = ref(false);
+    const error = ref<{error} | null>(null);
+
+    async function refetch() {{
+        loading.value = true;
+        error.value = null;
+        try {{
+            data.value = await {invoke};
+        }} catch (e) {{
+            error.value = e as {error};
+        }} finally {{
+            loading.value = false;
+        }}
+    }}
+
+    onMounted(refetch);{watch}
+
+    return {{ data, loading, error, refetch }};
+}}"#
+    ))
+}
+
+fn event(
+    ts: &Typescript,
+    cfg: &ExportContext,
+    name: &str,
+    typ: &DataType,
+) -> Result<String, ExportError> {
+    let Event {
+        name,
+        ident,
+        payload,
+        docs,
+    } = Event::new(ts, cfg, name, typ)?;
+    let composable = format!("use{}", ident.to_upper_camel_case());
+
+    Ok(format!(
+        r#"{docs}export function {composable}(handler: (payload: {payload}) => void) {{
+    let unlisten: UnlistenFn | undefined;
+
+    onMounted(async () => {{
+        unlisten = await TAURI_LISTEN<{payload}>("{name}", (event) => handler(event.payload));
+    }});
+    onUnmounted(() => unlisten?.());
+}}"#
+    ))
+}
@@ -0,0 +1,193 @@
+//! [Zod](https://zod.dev) schema exporter.
+
+use std::{borrow::Cow, path::Path};
+
+use heck::ToUpperCamelCase;
+use specta::{datatype::FunctionResultVariant, Language, TypeMap};
+use specta_typescript::{BigIntExportBehavior, ExportError, FormatterFn};
+
+use crate::{ErrorHandlingMode, ExportContext, LanguageExt};
+
+use super::{
+    js_ts,
+    schema::{self, datatype, render_types, Schema},
+};
+
+/// [Zod](https://zod.dev) schema exporter.
+///
+/// Generates a schema named `<TypeName>Schema` for every type and `<CommandName>Schema` for every command so responses can be validated at runtime.
+/// Every type which isn't generic is also exported as a `z.infer` alias with the same name as the Typescript bindings, so they can be used interchangeably.
+/// Recursive types are exported as their Typescript definition instead, which their schema is annotated with.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_specta::{Builder, Zod};
+///
+/// let builder = Builder::<tauri::Wry>::new();
+///
+/// #[cfg(debug_assertions)]
+/// builder
+///     .export(Zod::default(), "../src/schemas.ts")
+///     .expect("Failed to export zod schemas");
+/// ```
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Zod {
+    /// The file's header
+    pub header: Cow<'static, str>,
+    /// How BigInts should be exported.
+    pub bigint: BigIntExportBehavior,
+    /// How the resulting file should be formatted.
+    pub formatter: Option<FormatterFn>,
+}
+
+impl Zod {
+    /// Construct a new Zod exporter with the default options configured.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Configure a header for the file.
+    ///
+    /// This is perfect for configuring lint ignore rules or other file-level comments.
+    pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
+        self.header = header.into();
+        self
+    }
+
+    /// Configure the BigInt handling behaviour
+    pub fn bigint(mut self, bigint: BigIntExportBehavior) -> Self {
+        self.bigint = bigint;
+        self
+    }
+
+    /// Configure a function which is responsible for formatting the result file.
+    pub fn formatter(mut self, formatter: FormatterFn) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+}
+
+impl Schema for Zod {
+    const NAMESPACE: &'static str = "z";
+    const GENERIC_BOUND: &'static str = "z.ZodTypeAny";
+
+    fn bigint(&self) -> &BigIntExportBehavior {
+        &self.bigint
+    }
+
+    fn infer(name: &str) -> String {
+        format!("z.infer<typeof {name}Schema>")
+    }
+
+    fn annotation(name: &str) -> String {
+        format!("z.ZodType<{name}>")
+    }
+
+    fn nullable(schema: &str) -> String {
+        format!("{schema}.nullable()")
+    }
+
+    fn optional(schema: &str) -> String {
+        format!("{schema}.optional()")
+    }
+
+    fn array_with_length(schema: &str, length: usize) -> String {
+        format!("z.array({schema}).length({length})")
+    }
+
+    fn intersect(schemas: Vec<String>) -> String {
+        schemas
+            .into_iter()
+            .reduce(|schema, other| format!("{schema}.and({other})"))
+            .unwrap_or_else(|| "z.unknown()".into())
+    }
+
+    fn discriminated_union(tag: &str, variants: &[String]) -> String {
+        format!(
+            "z.discriminatedUnion({}, [{}])",
+            schema::string(tag),
+            variants.join(", ")
+        )
+    }
+}
+
+impl Language for Zod {
+    type Error = ExportError;
+
+    fn export(&self, type_map: TypeMap) -> Result<String, Self::Error> {
+        render_types(self, &type_map)
+    }
+
+    fn format(&self, path: &Path) -> Result<(), Self::Error> {
+        if let Some(formatter) = self.formatter {
+            formatter(path)?;
+        }
+        Ok(())
+    }
+}
+
+impl LanguageExt for Zod {
+    fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+        let cfg = &*js_ts::affix_types(cfg);
+        let header = &self.header;
+        let do_not_edit = js_ts::DO_NOT_EDIT;
+        let commands = render_commands(self, cfg)?;
+        let types = render_types(self, &cfg.type_map)?;
+
+        Ok(format! {
+            r#"{header}
+{do_not_edit}
+
+import {{ z }} from "zod";
+
+/** user-defined commands **/
+
+{commands}
+
+/** user-defined types **/
+
+{types}"#
+        })
+    }
+}
+
+fn render_commands(zod: &Zod, cfg: &ExportContext) -> Result<String, ExportError> {
+    cfg.commands
+        .iter()
+        .map(|function| {
+            let name = cfg.command_ident(function.name()).to_upper_camel_case();
+            let args = function
+                .args()
+                .map(|(_, typ)| datatype(zod, typ, &cfg.type_map, function.name()))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(", ");
+            let result = match function.result() {
+                Some(FunctionResultVariant::Value(t)) => {
+                    datatype(zod, t, &cfg.type_map, function.name())?
+                }
+                Some(FunctionResultVariant::Result(t, e)) => {
+                    let t = datatype(zod, t, &cfg.type_map, function.name())?;
+
+                    match cfg.error_handling {
+                        ErrorHandlingMode::Throw => t,
+                        ErrorHandlingMode::Result => {
+                            let e = datatype(zod, e, &cfg.type_map, function.name())?;
+
+                            format!(
+                                r#"z.discriminatedUnion("status", [z.object({{ status: z.literal("ok"), data: {t} }}), z.object({{ status: z.literal("error"), error: {e} }})])"#
+                            )
+                        }
+                    }
+                }
+                None => "z.void()".into(),
+            };
+
+            Ok(format!(
+                "export const {name}Schema = z.function().args({args}).returns(z.promise({result}));"
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|v| v.join("\n"))
+}
@@ -32,6 +32,16 @@
 //! - `derive` - Enables the `Event` derive macro. This is only required if your using events.
 //! - `javascript` - Enables the JSDoc exporter.
 //! - `typescript` - Enables the Typescript exporter.
+//! - `json-schema` - Enables the [JSON Schema](https://json-schema.org) exporter, [`Builder::export_manifest`] and [`Builder::export_openapi`].
+//! - `kotlin` - Enables the [Kotlin](https://kotlinlang.org) exporter for Android plugins.
+//! - `zod` - Enables the [Zod](https://zod.dev) schema exporter.
+//! - `valibot` - Enables the [Valibot](https://valibot.dev) schema exporter.
+//! - `react-query` - Enables generating [TanStack Query](https://tanstack.com/query) hooks.
+//! - `vue` - Enables generating [Vue 3](https://vuejs.org) composables.
+//! - `svelte` - Enables generating [Svelte](https://svelte.dev) stores.
+//! - `swift` - Enables the [Swift](https://www.swift.org) exporter for iOS plugins.
+//! - `test` - Enables [`testing::mock_app`] and [`testing::invoke`] for calling commands on Tauri's mock runtime in tests.
+//! - `log` - Logs warnings, Eg. from [`TraitObjectPolicy::Warn`] or for events whose payload couldn't be deserialized, through the [`log`](https://docs.rs/log) crate.
 //!
 //! ## Setup
 //!
@@ -168,13 +178,59 @@
 //!
 //! // Emit to a window
 //! await events.demoEvent(appWindow).emit("Test")
+//!
+//! // Listen to or emit to a target by its label
+//! events.demoEvent.listen((e) => console.log(e), { target: "mini-player" });
+//! await events.demoEvent.emitTo("mini-player", "Test")
+//! ```
+//!
+//! Listeners can be removed with an [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal) instead of the returned unlisten function.
+//! This is safe even if the signal aborts before the listener has finished registering, Eg. in a React effect:
+//!
+//! ```ts
+//! useEffect(() => {
+//!     const controller = new AbortController();
+//!     events.demoEvent.listen((e) => setMessage(e.payload), { signal: controller.signal });
+//!
+//!     return () => controller.abort();
+//! }, []);
 //! ```
 //!
 //! Refer to [`Event`] for all the possible methods for listening and emitting events.
 //!
 //! # Channel
 //!
-//! [Coming soon...](https://github.com/oscartbeaumont/tauri-specta/issues/111)
+//! Commands can take a [`Channel`](tauri::ipc::Channel) argument to stream data to the frontend.
+//! The argument is typed as a [`Channel`](https://v2.tauri.app/develop/calling-frontend/#channels) from `@tauri-apps/api/core`.
+//!
+//! ```rust
+//! use serde::Serialize;
+//! use specta::Type;
+//! use tauri::ipc::Channel;
+//!
+//! #[derive(Clone, Serialize, Type)]
+//! pub struct Progress {
+//!     percent: u8,
+//! }
+//!
+//! #[tauri::command]
+//! #[specta::specta]
+//! fn download(on_progress: Channel<Progress>) {
+//!     for percent in 0..=100 {
+//!         on_progress.send(Progress { percent }).ok();
+//!     }
+//! }
+//! ```
+//!
+//! ```ts
+//! import { Channel } from "@tauri-apps/api/core";
+//! import { commands, type Progress } from "./bindings";
+//!
+//! const onProgress = new Channel<Progress>();
+//! onProgress.onmessage = (progress) => console.log(progress.percent);
+//!
+//! await commands.download(onProgress);
+//! ```
 //!
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc(
@@ -186,10 +242,11 @@
 use core::fmt;
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet},
     sync::Arc,
 };
 
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
 use specta::{
     datatype::{self, DataType},
     Language, SpectaID, TypeMap,
@@ -200,18 +257,51 @@ use tauri::{ipc::Invoke, Runtime};
 ///
 /// Refer to the [`Event`](trait@crate::Event) trait for more information.
 ///
+/// Use `#[tauri_specta(window = "label")]` to target the event at a single window, which sets [`Event::WINDOW`](trait@crate::Event).
+///
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use tauri_specta_macros::Event;
 
+pub mod build_script;
 mod builder;
+mod error;
 mod event;
 mod lang;
 mod macros;
+mod permissions;
+pub mod testing;
+mod trait_object;
+mod type_graph;
 
-pub use builder::Builder;
+#[cfg(feature = "typescript")]
+#[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+pub use builder::DirConfig;
+pub use builder::{
+    Builder, BuiltBuilder, GraphFormat, MergePolicy, TypeCoverage, TypeCoverageReport, TypeCycle,
+};
+pub use error::{BuilderValidationError, Error};
 pub(crate) use event::EventRegistry;
 pub use event::{Event, TypedEvent};
+#[cfg(feature = "json-schema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json-schema")))]
+pub use lang::JsonSchema;
+#[cfg(feature = "kotlin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "kotlin")))]
+pub use lang::Kotlin;
+#[cfg(feature = "typescript")]
+#[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+pub use lang::TypescriptDeclarations;
+#[cfg(feature = "valibot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "valibot")))]
+pub use lang::Valibot;
+#[cfg(feature = "zod")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zod")))]
+pub use lang::Zod;
+#[cfg(feature = "swift")]
+#[cfg_attr(docsrs, doc(cfg(feature = "swift")))]
+pub use lang::{Swift, SwiftAccessLevel};
+pub use trait_object::TraitObject;
 
 /// A wrapper around the output of the `collect_commands` macro.
 ///
@@ -242,7 +332,14 @@ impl<R: Runtime> Default for Commands<R> {
 ///
 /// This acts to seal the implementation details of the macro.
 #[derive(Default)]
-pub struct Events(BTreeMap<&'static str, fn(&mut TypeMap) -> (SpectaID, DataType)>);
+pub struct Events(
+    BTreeMap<&'static str, (SpectaID, Option<&'static str>, EventRegistration)>,
+    /// The events registered with a name which was already taken, along with the event which took it.
+    Vec<(&'static str, (SpectaID, EventRegistration), SpectaID)>,
+);
+
+/// Registers an event's type and returns it.
+type EventRegistration = fn(&mut TypeMap) -> DataType;
 
 /// The context of what needs to be exported. Used when implementing [`LanguageExt`].
 #[derive(Debug, Clone)]
@@ -252,9 +349,124 @@ pub struct ExportContext {
     pub plugin_name: Option<&'static str>,
     pub commands: Vec<datatype::Function>,
     pub error_handling: ErrorHandlingMode,
+    /// Whether commands take a trailing `options` parameter which is passed to `invoke`, set with [`Builder::invoke_options`].
+    pub invoke_options: bool,
+    /// Whether commands taking a trailing `Channel` get a variant returning an `AsyncIterable`, set with [`Builder::channel_streams`].
+    pub channel_streams: bool,
     pub events: BTreeMap<&'static str, DataType>,
+    /// The label of the window each event is targeted at, keyed by the event name. Set with [`Event::WINDOW`].
+    pub event_windows: BTreeMap<&'static str, &'static str>,
+    /// The plugin each event belongs to, keyed by the event name, for events merged from another plugin with [`Builder::merge`].
+    /// Events which aren't in this map belong to [`Self::plugin_name`].
+    pub event_plugins: BTreeMap<&'static str, Option<&'static str>>,
     pub type_map: TypeMap,
-    pub constants: HashMap<Cow<'static, str>, serde_json::Value>,
+    pub constants: BTreeMap<Cow<'static, str>, Constant>,
+    /// The constants registered with [`Builder::constant_group`], keyed by the group name.
+    pub constant_groups: BTreeMap<Cow<'static, str>, BTreeMap<Cow<'static, str>, Constant>>,
+    pub deprecated_commands: BTreeMap<Cow<'static, str>, datatype::DeprecatedType>,
+    pub deprecated_events: BTreeMap<Cow<'static, str>, datatype::DeprecatedType>,
+    /// The case of the functions generated for commands, set with [`Builder::command_case`].
+    pub command_case: Case,
+    /// The case of the properties generated for events, set with [`Builder::event_case`].
+    pub event_case: Case,
+    /// How acronyms are cased in generated identifiers, set with [`Builder::acronym_style`].
+    pub acronym_style: AcronymStyle,
+    /// The names of the functions generated for commands, keyed by the command name. Set with [`Builder::rename_command`].
+    pub command_renames: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    /// The names of the properties generated for events, keyed by the event name. Set with [`Builder::rename_event`].
+    pub event_renames: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    pub command_namespaces: BTreeMap<Cow<'static, str>, Vec<Cow<'static, str>>>,
+    pub event_namespaces: BTreeMap<&'static str, Vec<Cow<'static, str>>>,
+    pub query_kinds: BTreeMap<Cow<'static, str>, QueryKind>,
+    /// The permissions registered with [`Builder::required_permission`], keyed by the command name.
+    pub required_permissions: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    pub module_format: ModuleFormat,
+    /// The module specifier which replaces `@tauri-apps/api`, set with [`Builder::tauri_api_path`].
+    pub tauri_api_path: Option<Cow<'static, str>>,
+    /// The prefix prepended to the name of every exported type, set with [`Builder::type_prefix`] or derived from [`Self::plugin_name`].
+    pub type_prefix: Cow<'static, str>,
+    /// The suffix appended to the name of every exported type, set with [`Builder::type_suffix`].
+    pub type_suffix: Cow<'static, str>,
+    /// How many named types deep the Typescript and Javascript types are validated, set with [`Builder::max_recursion_depth`].
+    pub max_recursion_depth: u32,
+    /// The type aliases registered with [`Builder::type_alias`], mapping the alias to the Typescript type.
+    pub type_aliases: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    /// The types overridden with [`Builder::override_type`], mapping the type to the Typescript type which replaces it.
+    pub type_overrides: BTreeMap<SpectaID, Cow<'static, str>>,
+    /// The import statements required by overridden types, registered with [`Builder::override_type_with_import`].
+    pub type_imports: BTreeMap<SpectaID, Cow<'static, str>>,
+    /// The enums registered with [`Builder::enum_variants`].
+    pub enum_variants: BTreeSet<SpectaID>,
+    pub internal_types: BTreeSet<SpectaID>,
+}
+
+impl ExportContext {
+    /// The name of the function generated for a command, Eg. `getUser` for `get_user`, unless it was renamed.
+    pub(crate) fn command_ident(&self, name: &str) -> String {
+        ident(
+            &self.command_renames,
+            self.command_case,
+            &self.acronym_style,
+            name,
+        )
+    }
+
+    /// The name a command is invoked with, including the prefix of the plugin it belongs to.
+    #[allow(unused)]
+    pub(crate) fn command_name(&self, name: &str) -> String {
+        self.plugin_name
+            .map(|n| apply_as_prefix(n, name, ItemType::Command))
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// The name of the property generated for an event, Eg. `themeChanged` for `theme-changed`, unless it was renamed.
+    #[allow(unused)]
+    pub(crate) fn event_ident(&self, name: &str) -> String {
+        ident(
+            &self.event_renames,
+            self.event_case,
+            &self.acronym_style,
+            name,
+        )
+    }
+
+    /// The name an event is emitted with, including the prefix of the plugin it belongs to.
+    #[allow(unused)]
+    pub(crate) fn event_name(&self, name: &str) -> String {
+        self.event_plugins
+            .get(name)
+            .copied()
+            .unwrap_or(self.plugin_name)
+            .map(|n| apply_as_prefix(n, name, ItemType::Event))
+            .unwrap_or_else(|| name.to_string())
+    }
+}
+
+/// The identifier generated for a command or event, which is its rename or `name` in the configured case.
+pub(crate) fn ident(
+    renames: &BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    case: Case,
+    acronym_style: &AcronymStyle,
+    name: &str,
+) -> String {
+    renames
+        .get(name)
+        .map(|rename| rename.to_string())
+        .unwrap_or_else(|| case.apply(name, acronym_style))
+}
+
+/// A constant registered with [`Builder::constant`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Constant {
+    /// The serialized value of the constant.
+    pub value: serde_json::Value,
+    /// The type of the constant.
+    ///
+    /// Any named types it references are included in [`ExportContext::type_map`].
+    pub ty: DataType,
+    /// Should the literal type of the constant be preserved (Eg. Typescript's `as const`).
+    pub as_const: bool,
 }
 
 /// Implemented for all languages which Tauri Specta supports exporting to.
@@ -262,15 +474,77 @@ pub struct ExportContext {
 /// Currently implemented for:
 ///  - [`specta_typescript::Typescript`]
 ///  - [`specta_jsdoc::JSDoc`]
+///  - `TypescriptDeclarations` (requires the `typescript` feature)
+///  - `Zod` (requires the `zod` feature)
+///  - `Valibot` (requires the `valibot` feature)
+///  - `JsonSchema` (requires the `json-schema` feature)
+///  - `Kotlin` (requires the `kotlin` feature)
+///  - `Swift` (requires the `swift` feature)
+///
+/// # Implementing a language
+///
+/// This trait can be implemented by other crates to export bindings for languages which are not supported by Tauri Specta.
+/// The [`ExportContext`] contains everything registered with the [`Builder`].
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use specta::{Language, TypeMap};
+/// use tauri_specta::{Builder, ExportContext, LanguageExt};
+///
+/// /// Exports the name of every command on a new line.
+/// struct CommandList;
+///
+/// impl Language for CommandList {
+///     type Error = std::io::Error;
+///
+///     fn export(&self, _: TypeMap) -> Result<String, Self::Error> {
+///         Ok(String::new())
+///     }
+///
+///     fn format(&self, _: &Path) -> Result<(), Self::Error> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl LanguageExt for CommandList {
+///     fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
+///         Ok(cfg
+///             .commands
+///             .iter()
+///             .map(|function| function.name().to_string())
+///             .collect::<Vec<_>>()
+///             .join("\n"))
+///     }
+/// }
+///
+/// let commands = Builder::<tauri::Wry>::new().export_str(CommandList).unwrap();
+/// ```
 pub trait LanguageExt: Language {
     /// render the bindings file
     fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error>;
+
+    /// The file extension required by the bindings, without the leading `.`.
+    ///
+    /// When this is `Some`, [`Builder::export`] replaces the extension of the path if it doesn't already match.
+    fn extension(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 impl<L: LanguageExt> LanguageExt for &L {
     fn render(&self, cfg: &ExportContext) -> Result<String, Self::Error> {
         (*self).render(cfg)
     }
+
+    fn extension(&self) -> Option<&'static str> {
+        (*self).extension()
+    }
+}
+
+/// The [`SpectaID`] of [`tauri::ipc::Channel`], which is provided by `@tauri-apps/api` instead of being exported.
+pub(crate) fn channel_sid() -> SpectaID {
+    <tauri::ipc::Channel<()> as specta::NamedType>::sid()
 }
 
 #[allow(unused)]
@@ -292,15 +566,131 @@ pub(crate) fn apply_as_prefix(plugin_name: &str, s: &str, item_type: ItemType) -
 }
 
 /// The mode which the error handling is done in the bindings.
-#[derive(Debug, Default, Copy, Clone)]
+///
+/// This applies to every command returning a `Result<T, E>`. Commands returning `Result<(), E>` resolve with `null`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum ErrorHandlingMode {
-    /// Errors will be thrown
+    /// Errors will be thrown.
+    ///
+    /// The command returns `Promise<T>` and rejects with the error `E`.
     Throw,
-    /// Errors will be returned as a Result enum
+    /// Errors will be returned as a Result enum.
+    ///
+    /// The command returns `Promise<Result<T, E>>` where `Result` is `{ status: "ok", data: T } | { status: "error", error: E }`.
     #[default]
     Result,
 }
 
+/// How [`TraitObject`]s are exported.
+///
+/// Refer to [`Builder::allow_trait_objects`] for more information.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum TraitObjectPolicy {
+    /// Export trait objects as `unknown`.
+    AsUnknown,
+    /// Export trait objects as `unknown` and log a warning when the `log` feature is enabled.
+    Warn,
+    /// Fail the export with [`Error::TraitObject`].
+    #[default]
+    Error,
+}
+
+/// The module format of the generated Javascript bindings.
+///
+/// Refer to [`Builder::module_format`] for more information.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ModuleFormat {
+    /// Detect the format from the nearest `deno.json` or `package.json` to the exported file.
+    ///
+    /// A `deno.json` or `deno.jsonc` selects [`Self::Deno`].
+    /// A `package.json` with `"type": "module"` selects [`Self::Esm`], otherwise [`Self::CommonJs`] is used as Node treats a package without a `"type"` as CommonJS.
+    /// Without either file [`Self::Esm`] is used as Tauri frontends are typically bundled.
+    /// [`Builder::export_str`] has no file to detect from so it always uses [`Self::Esm`].
+    #[default]
+    Auto,
+    /// ES modules using `import` and `export`.
+    Esm,
+    /// CommonJS modules using `require` and `module.exports`.
+    CommonJs,
+    /// ES modules for [Deno](https://deno.com), which imports npm packages with the `npm:` specifier and requires the extension on relative imports.
+    ///
+    /// Unlike the other formats this also applies to Typescript bindings.
+    Deno,
+}
+
+/// The case of identifiers generated from command and event names.
+///
+/// Refer to [`Builder::command_case`] for more information.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Case {
+    /// Eg. `getUserById`.
+    #[default]
+    CamelCase,
+    /// Eg. `GetUserById`.
+    PascalCase,
+    /// Eg. `get_user_by_id`.
+    SnakeCase,
+    /// Eg. `GET_USER_BY_ID`.
+    ScreamingSnakeCase,
+}
+
+impl Case {
+    /// Convert `name` to this case, applying the casing of any acronyms.
+    pub(crate) fn apply(self, name: &str, acronyms: &AcronymStyle) -> String {
+        match self {
+            Self::SnakeCase => return name.to_snake_case(),
+            Self::ScreamingSnakeCase => return name.to_shouty_snake_case(),
+            Self::CamelCase | Self::PascalCase => {}
+        }
+
+        name.to_snake_case()
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .enumerate()
+            .map(|(i, word)| {
+                let acronym = match acronyms {
+                    AcronymStyle::Lower => None,
+                    AcronymStyle::Preserve(acronyms) => {
+                        acronyms.iter().find(|a| a.eq_ignore_ascii_case(word))
+                    }
+                };
+
+                match (i, self, acronym) {
+                    // The first word of a camelCased identifier is always lowercase.
+                    (0, Self::CamelCase, _) => word.to_string(),
+                    (_, _, Some(acronym)) => acronym.to_string(),
+                    _ => word.to_upper_camel_case(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// How acronyms are cased in camelCased and PascalCased identifiers.
+///
+/// Refer to [`Builder::acronym_style`] for more information.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum AcronymStyle {
+    /// Acronyms are cased like any other word, Eg. `getUserUrl`.
+    #[default]
+    Lower,
+    /// The listed acronyms keep their casing, Eg. `getUserURL` with `["URL"]`.
+    ///
+    /// The first word of a camelCased identifier is still lowercase, Eg. `urlChanged`.
+    Preserve(Vec<Cow<'static, str>>),
+}
+
+/// Whether a command is generated as a query or a mutation hook.
+///
+/// Refer to [`Builder::command_query_kind`] for more information.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QueryKind {
+    /// The command reads data and is wrapped in `useQuery`.
+    Query,
+    /// The command modifies data and is wrapped in `useMutation`.
+    Mutation,
+}
+
 #[doc(hidden)]
 pub mod internal {
     //! Internal logic for Tauri Specta.
@@ -320,15 +710,19 @@ pub mod internal {
         Commands(Arc::new(f), types)
     }
 
-    /// called by `collect_events` to register events to an `Events`
-    pub fn register_event<E: Event>(Events(events): &mut Events) {
-        if events
-            .insert(E::NAME, |type_map| {
-                (E::sid(), E::reference(type_map, &[]).inner)
-            })
-            .is_some()
+    /// called by `collect_events` to register events to an `Events`, optionally with a name overriding [`Event::NAME`]
+    pub fn register_event<E: Event>(
+        Events(events, duplicates): &mut Events,
+        name: Option<&'static str>,
+    ) {
+        let name = name.unwrap_or(E::NAME);
+        let registration: EventRegistration = |type_map| E::reference(type_map, &[]).inner;
+
+        // Duplicates are reported when exporting so every offending type can be named.
+        if let Some((existing, _, existing_registration)) =
+            events.insert(name, (E::sid(), E::WINDOW, registration))
         {
-            panic!("Another event with name {} is already registered!", E::NAME)
+            duplicates.push((name, (existing, existing_registration), E::sid()));
         }
     }
 }
@@ -39,14 +39,102 @@
 ///     ]);
 /// ```
 ///
+/// # Conditional commands
+///
+/// Commands can be gated with `#[cfg(...)]`, which removes both the handler and the command's signature from the bindings when the predicate is false.
+/// This allows commands which only exist on some platforms or in debug builds.
+///
+/// Using `#[handler_cfg(...)]` instead only removes the handler, so the command is always included in the bindings and the frontend compiles on every platform.
+/// Calling the command when its handler is removed fails with Tauri's error for an unknown command.
+/// The command must exist on every platform for its signature to be exported.
+///
+/// ```
+/// use tauri_specta::{collect_commands, Builder};
+///
+/// #[cfg(debug_assertions)]
+/// #[tauri::command]
+/// #[specta::specta]
+/// fn devtools_dump() -> String {
+///     "dump".into()
+/// }
+///
+/// #[tauri::command]
+/// #[specta::specta]
+/// fn open_native_dialog() {
+///     #[cfg(not(target_os = "android"))]
+///     {
+///         // ...
+///     }
+/// }
+///
+/// let mut builder = Builder::<tauri::Wry>::new().commands(collect_commands![
+///     #[cfg(debug_assertions)]
+///     devtools_dump,
+///     #[handler_cfg(not(target_os = "android"))]
+///     open_native_dialog,
+/// ]);
+/// ```
 #[macro_export]
 macro_rules! collect_commands {
-    ($($b:ident $(:: $($p:ident)? $(<$($g:path),*>)? )* ),* $(,)?) => {
-        // We strip generics (::<...>) from being parsed to Tauri as it doesn't support them.
-        $crate::internal::command(
-            ::tauri::generate_handler![$($b $($(::$p)? )* ),*],
-            ::specta::function::collect_functions![$($b $($(::$p)? $(::<$($g),*>)? )* ),*],
-        )
+    ($( $(#[$kind:ident($($pred:tt)*)])* $b:ident $(:: $($p:ident)? $(<$($g:path),*>)? )* ),* $(,)?) => {
+        {
+            $($($crate::__command_attribute!($kind);)*)*
+
+            // Deprecated commands are marked as such in the bindings so registering them shouldn't warn.
+            #[allow(deprecated)]
+            // We strip generics (::<...>) from being parsed to Tauri as it doesn't support them.
+            let commands = $crate::internal::command(
+                ::tauri::generate_handler![$( $(#[cfg($($pred)*)])* $b $($(::$p)? )* ),*],
+                {
+                    // `type_map` is unused when no commands are passed.
+                    #[allow(deprecated, unused_variables)]
+                    fn export(type_map: &mut ::specta::TypeMap) -> ::std::vec::Vec<::specta::datatype::Function> {
+                        #[allow(unused_mut)]
+                        let mut functions = ::std::vec::Vec::new();
+                        $(
+                            $crate::__collect_command_type!(
+                                functions type_map [$(#[$kind($($pred)*)])*] $b $($(::$p)? $(::<$($g),*>)? )*
+                            );
+                        )*
+                        functions
+                    }
+
+                    export
+                },
+            );
+            commands
+        }
+    };
+}
+
+/// Only `cfg` and `handler_cfg` are supported on the commands passed to `collect_commands`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __command_attribute {
+    (cfg) => {};
+    (handler_cfg) => {};
+    ($other:ident) => {
+        compile_error!(concat!(
+            "unsupported attribute `#[",
+            stringify!($other),
+            "]` in `collect_commands!`, expected `#[cfg(...)]` or `#[handler_cfg(...)]`"
+        ));
+    };
+}
+
+/// Push the type of a command, applying its `cfg` attributes so it's only exported when the command exists.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __collect_command_type {
+    ($functions:ident $type_map:ident [#[cfg($($pred:tt)*)] $($attrs:tt)*] $($path:tt)*) => {
+        #[cfg($($pred)*)]
+        $crate::__collect_command_type!($functions $type_map [$($attrs)*] $($path)*);
+    };
+    ($functions:ident $type_map:ident [#[handler_cfg($($pred:tt)*)] $($attrs:tt)*] $($path:tt)*) => {
+        $crate::__collect_command_type!($functions $type_map [$($attrs)*] $($path)*);
+    };
+    ($functions:ident $type_map:ident [] $($path:tt)*) => {
+        $functions.push(::specta::function::fn_datatype!($($path)*)($type_map));
     };
 }
 
@@ -89,9 +177,12 @@ macro_rules! collect_commands {
 ///
 #[macro_export]
 macro_rules! collect_events {
-    ($($event:path),* $(,)?) => {{
+    ($($event:path $(as $name:literal)?),* $(,)?) => {{
         let mut events: $crate::Events = ::core::default::Default::default();
-        $($crate::internal::register_event::<$event>(&mut events);)*
+        $($crate::internal::register_event::<$event>(
+            &mut events,
+            ::core::option::Option::None $(.or(::core::option::Option::Some($name)))?,
+        );)*
         events
     }};
 }
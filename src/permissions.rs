@@ -0,0 +1,157 @@
+//! Tauri v2 [permission](https://v2.tauri.app/security/permissions) files for the registered commands.
+
+use std::{fs, io, path::Path};
+
+use crate::{lang::DO_NOT_EDIT, Error, ExportContext};
+
+/// Marks the start of the generated permissions within `default.toml`.
+const START_MARKER: &str = "# tauri-specta:start";
+/// Marks the end of the generated permissions within `default.toml`.
+const END_MARKER: &str = "# tauri-specta:end";
+
+/// Write an `allow-<command>` and `deny-<command>` permission for each command into `dir/autogenerated/commands`,
+/// and a `default` permission set allowing every command into `dir/default.toml`.
+///
+/// Files are only written when their contents change so this can be called from a build script without retriggering it.
+pub(crate) fn export(cfg: &ExportContext, dir: &Path) -> Result<(), Error> {
+    let commands_dir = dir.join("autogenerated").join("commands");
+    fs::create_dir_all(&commands_dir).map_err(Error::io(&commands_dir))?;
+
+    let mut commands = cfg
+        .commands
+        .iter()
+        .map(|function| function.name().to_string())
+        .collect::<Vec<_>>();
+    commands.sort();
+    commands.dedup();
+
+    for command in &commands {
+        write_if_changed(
+            &commands_dir.join(format!("{command}.toml")),
+            &command_permissions(command),
+        )?;
+    }
+
+    // Remove the permissions of commands which no longer exist.
+    for entry in fs::read_dir(&commands_dir).map_err(Error::io(&commands_dir))? {
+        let path = entry.map_err(Error::io(&commands_dir))?.path();
+        let is_stale = path.extension().is_some_and(|ext| ext == "toml")
+            && path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| !commands.iter().any(|command| command == stem));
+
+        if is_stale {
+            fs::remove_file(&path).map_err(Error::io(&path))?;
+        }
+    }
+
+    let allowed = commands
+        .iter()
+        .map(|command| format!("  \"allow-{}\",", identifier(command)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let default_path = dir.join("default.toml");
+    let default = match fs::read_to_string(&default_path) {
+        Ok(existing) => match replace_generated(&existing, &allowed) {
+            Some(default) => default,
+            // The user maintains the file themselves.
+            None => return Ok(()),
+        },
+        Err(err) if err.kind() == io::ErrorKind::NotFound => format!(
+            r#""$schema" = "schemas/schema.json"
+
+[default]
+description = "Default permissions for the plugin"
+permissions = [
+{START_MARKER}
+{allowed}
+{END_MARKER}
+]
+"#
+        ),
+        Err(err) => return Err(Error::io(&default_path)(err)),
+    };
+
+    write_if_changed(&default_path, &default)
+}
+
+/// Write a Typescript file mapping each command's generated function to the permission registered with [`Builder::required_permission`](crate::Builder::required_permission).
+pub(crate) fn export_manifest(cfg: &ExportContext, path: &Path) -> Result<(), Error> {
+    let mut commands = cfg
+        .commands
+        .iter()
+        .filter_map(|function| {
+            cfg.required_permissions
+                .get(function.name())
+                .map(|permission| (cfg.command_ident(function.name()), permission))
+        })
+        .collect::<Vec<_>>();
+    commands.sort();
+    commands.dedup();
+
+    let permissions = commands
+        .iter()
+        .map(|(name, permission)| format!("\t{name}: {permission:?},\n"))
+        .collect::<String>();
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(Error::io(dir))?;
+    }
+
+    write_if_changed(
+        path,
+        &format!(
+            "{DO_NOT_EDIT}\n\nexport const REQUIRED_PERMISSIONS = {{\n{permissions}}} as const;\n"
+        ),
+    )
+}
+
+/// The identifier Tauri uses for a command's permissions, Eg. `get_user` is `allow-get-user`.
+fn identifier(command: &str) -> String {
+    command.replace('_', "-")
+}
+
+fn command_permissions(command: &str) -> String {
+    let identifier = identifier(command);
+
+    format!(
+        r#"# Automatically generated by tauri-specta - DO NOT EDIT!
+
+"$schema" = "../../schemas/schema.json"
+
+[[permission]]
+identifier = "allow-{identifier}"
+description = "Enables the {command} command without any pre-configured scope."
+commands.allow = ["{command}"]
+
+[[permission]]
+identifier = "deny-{identifier}"
+description = "Denies the {command} command without any pre-configured scope."
+commands.deny = ["{command}"]
+"#
+    )
+}
+
+/// Replace the lines between the markers in `existing`, preserving everything else.
+///
+/// Returns `None` if the markers are missing.
+fn replace_generated(existing: &str, generated: &str) -> Option<String> {
+    let start = existing.find(START_MARKER)? + START_MARKER.len();
+    let end = start + existing[start..].find(END_MARKER)?;
+
+    Some(format!(
+        "{}\n{generated}\n{}",
+        &existing[..start],
+        &existing[end..]
+    ))
+}
+
+fn write_if_changed(path: &Path, contents: &str) -> Result<(), Error> {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+
+    fs::write(path, contents).map_err(Error::io(path))
+}
@@ -0,0 +1,192 @@
+//! Helpers for testing the exported bindings.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use specta_typescript::Typescript;
+//! use tauri_specta::{testing::assert_ts_snapshot, Builder};
+//!
+//! #[test]
+//! fn bindings() {
+//!     let builder = Builder::<tauri::Wry>::new();
+//!
+//!     assert_ts_snapshot(&builder, Typescript::default(), "tests/snapshots/bindings.snap");
+//! }
+//! ```
+//!
+//! With the `test` feature enabled, commands can be invoked on Tauri's `MockRuntime` with [`mock_app`] and [`invoke`].
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use tauri::Runtime;
+
+use crate::{Builder, LanguageExt};
+
+#[cfg(feature = "test")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "test")]
+use tauri::{
+    ipc::{CallbackFn, InvokeBody},
+    test::{mock_builder, mock_context, noop_assets, MockRuntime, INVOKE_KEY},
+    webview::InvokeRequest,
+    App, Manager, WebviewWindowBuilder,
+};
+
+/// The environment variable which causes [`assert_ts_snapshot`] to overwrite existing snapshots when set to `1`.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Assert the bindings exported by a [`Builder`] match the snapshot at `path`.
+///
+/// The snapshot is written if it doesn't exist yet, or if the [`UPDATE_SNAPSHOTS_ENV`] environment variable is set to `1`.
+/// Otherwise this panics with a line-by-line diff of the snapshot and the exported bindings if they don't match.
+///
+/// Relative paths are resolved against the current directory, which is the crate root when run with `cargo test`.
+#[allow(clippy::panic)]
+#[track_caller]
+pub fn assert_ts_snapshot<R, L>(builder: &Builder<R>, language: L, path: impl AsRef<Path>)
+where
+    R: Runtime,
+    L: LanguageExt,
+    L::Error: Send + Sync + 'static,
+{
+    let path = path.as_ref();
+    let actual = builder
+        .export_str(language)
+        .unwrap_or_else(|err| panic!("failed to export bindings: {err}"));
+
+    let update = env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1");
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) if !update => expected,
+        _ => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).unwrap_or_else(|err| {
+                    panic!("failed to create snapshot directory {dir:?}: {err}")
+                });
+            }
+
+            fs::write(path, &actual)
+                .unwrap_or_else(|err| panic!("failed to write snapshot {path:?}: {err}"));
+            return;
+        }
+    };
+
+    if expected != actual {
+        panic!(
+            "snapshot {path:?} doesn't match the exported bindings, rerun with `{UPDATE_SNAPSHOTS_ENV}=1` to update it\n\n{}",
+            diff(&expected, &actual)
+        );
+    }
+}
+
+/// Build a Tauri app on the [`MockRuntime`] which handles the builder's commands and has its events mounted.
+///
+/// State used by the commands can be managed on the returned app with [`Manager::manage`].
+#[cfg(feature = "test")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test")))]
+pub fn mock_app(builder: &Builder<MockRuntime>) -> App<MockRuntime> {
+    let app = mock_builder()
+        .invoke_handler(builder.invoke_handler())
+        .build(mock_context(noop_assets()))
+        .expect("failed to build mock app");
+    builder.mount_events(&app);
+    app
+}
+
+/// Invoke a command on an app built with [`mock_app`], the same way the frontend would.
+///
+/// `args` are the command's arguments keyed by their camelCased names, Eg. `json!({ "filePath": "/tmp" })`.
+/// Async commands are awaited and commands returning a `Result` return their error as `Err`.
+/// Commands are invoked without the prefix of the [plugin name](Builder::plugin_name) as the app's handler is called directly.
+///
+/// # Panics
+///
+/// Panics if the command's response can't be deserialized as `T`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use serde_json::json;
+/// use tauri::test::MockRuntime;
+/// use tauri_specta::{collect_commands, testing, Builder};
+///
+/// #[tauri::command]
+/// #[specta::specta]
+/// fn read_file(path: String) -> Result<String, String> {
+///     Err(format!("{path} doesn't exist"))
+/// }
+///
+/// let builder = Builder::<MockRuntime>::new().commands(collect_commands![read_file]);
+/// let app = testing::mock_app(&builder);
+///
+/// let result = testing::invoke::<String>(&app, "read_file", json!({ "path": "/tmp" }));
+/// assert_eq!(result, Err(json!("/tmp doesn't exist")));
+/// ```
+#[cfg(feature = "test")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test")))]
+#[allow(clippy::panic, clippy::panic_in_result_fn)]
+#[track_caller]
+pub fn invoke<T: DeserializeOwned>(
+    app: &App<MockRuntime>,
+    command: &str,
+    args: serde_json::Value,
+) -> Result<T, serde_json::Value> {
+    let webview = match app.get_webview_window("main") {
+        Some(webview) => webview,
+        None => WebviewWindowBuilder::new(app, "main", Default::default())
+            .build()
+            .expect("failed to build mock webview"),
+    };
+
+    let response = tauri::test::get_ipc_response(
+        &webview,
+        InvokeRequest {
+            cmd: command.into(),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: "http://tauri.localhost"
+                .parse()
+                .expect("failed to parse mock webview url"),
+            body: InvokeBody::Json(args),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        },
+    )?;
+
+    Ok(response.deserialize().unwrap_or_else(|err| {
+        panic!("failed to deserialize the response of command '{command}': {err}")
+    }))
+}
+
+/// A colorized line-by-line diff, with removed lines in red and added lines in green.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    // The length of the longest common subsequence of `expected[i..]` and `actual[j..]`.
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = match expected[i] == actual[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            writeln!(out, "  {}", expected[i]).ok();
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            writeln!(out, "\x1b[31m- {}\x1b[0m", expected[i]).ok();
+            i += 1;
+        } else {
+            writeln!(out, "\x1b[32m+ {}\x1b[0m", actual[j]).ok();
+            j += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,95 @@
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use specta::{datatype::DataType, internal::construct, Generics, SpectaID, Type, TypeMap};
+
+/// A trait object, Eg. `TraitObject<dyn erased_serde::Serialize>`, which is exported as `unknown`.
+///
+/// Specta can't implement [`Type`] for a `dyn Trait` as its concrete type is only known at runtime,
+/// so wrap trait objects in this to use them in commands and events.
+///
+/// Exporting fails unless trait objects are allowed with [`Builder::allow_trait_objects`](crate::Builder::allow_trait_objects).
+///
+/// Specta's macros reject `dyn` in types, so refer to it through a type alias.
+///
+/// # Example
+///
+/// ```
+/// use specta::Type;
+/// use tauri_specta::TraitObject;
+///
+/// pub type AnyState = TraitObject<dyn std::any::Any>;
+///
+/// #[derive(Type)]
+/// pub struct Plugin {
+///     state: AnyState,
+/// }
+/// ```
+pub struct TraitObject<T: ?Sized>(pub Box<T>);
+
+impl<T: ?Sized> From<Box<T>> for TraitObject<T> {
+    fn from(value: Box<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ?Sized> Deref for TraitObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for TraitObject<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> Serialize for TraitObject<T>
+where
+    Box<T>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: ?Sized> Deserialize<'de> for TraitObject<T>
+where
+    Box<T>: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Box::<T>::deserialize(deserializer).map(Self)
+    }
+}
+
+impl<T: ?Sized> Type for TraitObject<T> {
+    fn inline(type_map: &mut TypeMap, _: Generics) -> DataType {
+        let sid = trait_object_sid();
+        let name = Cow::Borrowed("TraitObject");
+
+        type_map.insert(
+            sid,
+            construct::named_data_type(
+                name.clone(),
+                Cow::Borrowed("A trait object whose type is only known at runtime."),
+                None,
+                sid,
+                construct::impl_location(concat!(file!(), ":", line!(), ":", column!())),
+                DataType::Unknown,
+            ),
+        );
+
+        DataType::Reference(construct::data_type_reference(name, sid, vec![]))
+    }
+}
+
+/// The [`SpectaID`] of every [`TraitObject`], regardless of the trait.
+pub(crate) fn trait_object_sid() -> SpectaID {
+    construct::sid("TraitObject", "tauri_specta::TraitObject")
+}
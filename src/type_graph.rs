@@ -0,0 +1,268 @@
+//! The graph formed by named types referencing each other.
+
+use std::{
+    borrow::Cow,
+    collections::{btree_map, BTreeMap, BTreeSet, VecDeque},
+};
+
+use specta::{
+    datatype::{DataType, DataTypeReference, EnumVariants, Field, StructFields},
+    internal::construct,
+    Generics, SpectaID, Type, TypeMap,
+};
+
+/// Where a reference is within the type holding it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    /// Whether the reference is within a list, which `specta_typescript` doesn't validate.
+    pub(crate) in_list: bool,
+    /// Whether the reference is behind an `Option`.
+    pub(crate) nullable: bool,
+}
+
+/// Collect the named types referenced by `ty`, along with where they're referenced.
+pub(crate) fn references(ty: &DataType) -> Vec<(SpectaID, Position)> {
+    let mut references = Vec::new();
+    walk(ty, Position::default(), &mut |reference, position| {
+        references.push((reference.sid(), position));
+        None
+    });
+    references
+}
+
+/// Rebuild `ty` with the new names `rename` returns for the references within it.
+///
+/// Returns `None` when no reference was renamed, so `ty` can be used as is.
+pub(crate) fn rename_references(
+    ty: &DataType,
+    rename: &mut impl FnMut(&DataTypeReference) -> Option<Cow<'static, str>>,
+) -> Option<DataType> {
+    walk(ty, Position::default(), &mut |reference, _| {
+        rename(reference)
+    })
+}
+
+/// Call `f` with every reference within `ty`, rebuilding the types which hold a reference it renamed.
+fn walk<F>(ty: &DataType, position: Position, f: &mut F) -> Option<DataType>
+where
+    F: FnMut(&DataTypeReference, Position) -> Option<Cow<'static, str>>,
+{
+    match ty {
+        DataType::Nullable(ty) => walk(
+            ty,
+            Position {
+                nullable: true,
+                ..position
+            },
+            f,
+        )
+        .map(|ty| DataType::Nullable(Box::new(ty))),
+        DataType::List(l) => walk(
+            l.ty(),
+            Position {
+                in_list: true,
+                ..position
+            },
+            f,
+        )
+        .map(|ty| list(ty, l.length(), l.unique())),
+        DataType::Map(map) => {
+            let key = walk(map.key_ty(), position, f);
+            let value = walk(map.value_ty(), position, f);
+            if key.is_none() && value.is_none() {
+                return None;
+            }
+
+            Some(<BTreeMap<(), ()> as Type>::inline(
+                &mut TypeMap::default(),
+                Generics::Provided(&[
+                    key.unwrap_or_else(|| map.key_ty().clone()),
+                    value.unwrap_or_else(|| map.value_ty().clone()),
+                ]),
+            ))
+        }
+        DataType::Struct(s) => {
+            let fields = match s.fields() {
+                StructFields::Unit => return None,
+                StructFields::Unnamed(fields) => {
+                    construct::struct_unnamed(walk_fields(fields.fields(), position, f)?)
+                }
+                StructFields::Named(fields) => construct::struct_named(
+                    walk_named_fields(fields.fields(), position, f)?,
+                    fields.tag().clone(),
+                ),
+            };
+
+            Some(DataType::Struct(construct::r#struct(
+                s.name().clone(),
+                s.sid().copied(),
+                s.generics().clone(),
+                fields,
+            )))
+        }
+        DataType::Enum(e) => {
+            let variants = walk_all(e.variants(), |(name, variant)| {
+                let inner = match variant.inner() {
+                    EnumVariants::Unit => return None,
+                    EnumVariants::Unnamed(fields) => {
+                        construct::enum_variant_unnamed(walk_fields(fields.fields(), position, f)?)
+                    }
+                    EnumVariants::Named(fields) => construct::enum_variant_named(
+                        walk_named_fields(fields.fields(), position, f)?,
+                        fields.tag().clone(),
+                    ),
+                };
+
+                Some((
+                    name.clone(),
+                    construct::enum_variant(
+                        variant.skip(),
+                        variant.deprecated().cloned(),
+                        variant.docs().clone(),
+                        inner,
+                    ),
+                ))
+            })?;
+
+            // Specta can't read an enum's `SpectaID` back and doesn't use it when exporting, so it's replaced.
+            Some(DataType::Enum(construct::r#enum(
+                e.name().clone(),
+                construct::sid("tauri_specta::type_graph", "enum"),
+                e.repr().clone(),
+                e.skip_bigint_checks(),
+                e.generics().clone(),
+                variants,
+            )))
+        }
+        DataType::Tuple(t) => walk_all(t.elements(), |ty| walk(ty, position, f))
+            .map(|elements| DataType::Tuple(construct::tuple(elements))),
+        DataType::Reference(r) => {
+            let generics = walk_all(r.generics(), |(generic, ty)| {
+                walk(ty, position, f).map(|ty| (generic.clone(), ty))
+            });
+            let name = f(r, position);
+            if generics.is_none() && name.is_none() {
+                return None;
+            }
+
+            Some(DataType::Reference(construct::data_type_reference(
+                name.unwrap_or_else(|| r.name().clone()),
+                r.sid(),
+                generics.unwrap_or_else(|| r.generics().clone()),
+            )))
+        }
+        _ => None,
+    }
+}
+
+fn walk_fields<F>(fields: &[Field], position: Position, f: &mut F) -> Option<Vec<Field>>
+where
+    F: FnMut(&DataTypeReference, Position) -> Option<Cow<'static, str>>,
+{
+    walk_all(fields, |field| walk_field(field, position, f))
+}
+
+fn walk_named_fields<F>(
+    fields: &[(Cow<'static, str>, Field)],
+    position: Position,
+    f: &mut F,
+) -> Option<Vec<(Cow<'static, str>, Field)>>
+where
+    F: FnMut(&DataTypeReference, Position) -> Option<Cow<'static, str>>,
+{
+    walk_all(fields, |(name, field)| {
+        walk_field(field, position, f).map(|field| (name.clone(), field))
+    })
+}
+
+fn walk_field<F>(field: &Field, position: Position, f: &mut F) -> Option<Field>
+where
+    F: FnMut(&DataTypeReference, Position) -> Option<Cow<'static, str>>,
+{
+    let ty = walk(field.ty()?, position, f)?;
+
+    Some(construct::field(
+        field.optional(),
+        field.flatten(),
+        field.deprecated().cloned(),
+        field.docs().clone(),
+        Some(ty),
+    ))
+}
+
+/// Walk every item, returning `None` unless at least one of them was rebuilt.
+fn walk_all<T: Clone>(items: &[T], mut walk: impl FnMut(&T) -> Option<T>) -> Option<Vec<T>> {
+    let walked = items.iter().map(&mut walk).collect::<Vec<_>>();
+    if walked.iter().all(Option::is_none) {
+        return None;
+    }
+
+    Some(
+        items
+            .iter()
+            .zip(walked)
+            .map(|(item, walked)| walked.unwrap_or_else(|| item.clone()))
+            .collect(),
+    )
+}
+
+/// Rebuild a list of `ty`.
+///
+/// Specta can only construct a list with a length from an array type, so the lengths serde implements arrays for (up to 32) are matched,
+/// while longer lists become the equivalent tuple.
+fn list(ty: DataType, length: Option<usize>, unique: bool) -> DataType {
+    macro_rules! array {
+        ($($length:literal)*) => {
+            match length {
+                $(Some($length) => {
+                    return <[(); $length] as Type>::inline(
+                        &mut TypeMap::default(),
+                        Generics::Provided(&[ty]),
+                    )
+                })*
+                _ => {}
+            }
+        };
+    }
+    array!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+
+    match (length, unique) {
+        (Some(length), _) => DataType::Tuple(construct::tuple(vec![ty; length])),
+        (None, false) => {
+            <Vec<()> as Type>::inline(&mut TypeMap::default(), Generics::Provided(&[ty]))
+        }
+        (None, true) => {
+            <BTreeSet<()> as Type>::inline(&mut TypeMap::default(), Generics::Provided(&[ty]))
+        }
+    }
+}
+
+/// Find the shortest cycle of references from `sid` back to itself, starting and ending with `sid`.
+pub(crate) fn shortest_cycle(types: &TypeMap, sid: SpectaID) -> Option<Vec<SpectaID>> {
+    // Breadth-first search, remembering the type each type was first reached from.
+    let mut parents = BTreeMap::new();
+    let mut queue = VecDeque::from([sid]);
+    while let Some(current) = queue.pop_front() {
+        let Some(ndt) = types.get(current) else {
+            continue;
+        };
+
+        for (next, _) in references(&ndt.inner) {
+            if next == sid {
+                let mut cycle = vec![sid, current];
+                let mut current = current;
+                while current != sid {
+                    current = parents[&current];
+                    cycle.push(current);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if let btree_map::Entry::Vacant(entry) = parents.entry(next) {
+                entry.insert(current);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
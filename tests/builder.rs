@@ -0,0 +1,983 @@
+use std::{
+    env, fs, panic,
+    path::PathBuf,
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+use specta::{Type, TypeMap};
+use specta_typescript::Typescript;
+use tauri::{
+    ipc::{CallbackFn, InvokeBody},
+    test::{get_ipc_response, mock_builder, mock_context, noop_assets, MockRuntime, INVOKE_KEY},
+    webview::InvokeRequest,
+    Emitter, EventTarget, Listener,
+};
+use tauri_specta::{
+    collect_commands, collect_events, testing::assert_ts_snapshot, Builder, BuilderValidationError,
+    Case, Event, GraphFormat, TypeCoverage,
+};
+
+macro_rules! types {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Type)]
+            pub struct $name {
+                field: String,
+            }
+        )*
+
+        fn type_collection() -> TypeMap {
+            let mut types = TypeMap::default();
+            $($name::reference(&mut types, &[]);)*
+            types
+        }
+
+        const TYPE_NAMES: &[&str] = &[$(stringify!($name)),*];
+    };
+}
+
+types!(
+    Type1, Type2, Type3, Type4, Type5, Type6, Type7, Type8, Type9, Type10, Type11, Type12, Type13,
+    Type14, Type15, Type16, Type17, Type18, Type19, Type20
+);
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[test]
+fn types_merges_type_collection() {
+    let output = Builder::<tauri::Wry>::new()
+        .types(type_collection())
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    for name in TYPE_NAMES {
+        assert!(
+            output.contains(&format!("export type {name} = ")),
+            "missing type {name}"
+        );
+    }
+}
+
+#[test]
+fn export_str_matches_file_output() {
+    let builder = Builder::<tauri::Wry>::new().types(type_collection());
+    let path = test_dir("export-str-matches-file-output").join("bindings.ts");
+
+    builder
+        .export(Typescript::default(), &path)
+        .expect("failed to export typescript bindings");
+    let written = fs::read_to_string(&path).expect("failed to read exported bindings");
+    fs::remove_file(&path).ok();
+
+    assert_eq!(
+        builder
+            .export_str(Typescript::default())
+            .expect("failed to export typescript bindings"),
+        written
+    );
+}
+
+#[test]
+fn unchanged_bindings_are_not_rewritten() {
+    let builder = Builder::<tauri::Wry>::new().types(type_collection());
+    let path = test_dir("unchanged-bindings-are-not-rewritten").join("bindings.ts");
+
+    let modified = || {
+        fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .expect("failed to read exported bindings")
+    };
+
+    builder
+        .export(Typescript::default(), &path)
+        .expect("failed to export typescript bindings");
+    let first = modified();
+
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    builder
+        .export(Typescript::default(), &path)
+        .expect("failed to export typescript bindings");
+    assert_eq!(modified(), first);
+
+    Builder::<tauri::Wry>::new()
+        .export(Typescript::default(), &path)
+        .expect("failed to export typescript bindings");
+    assert_ne!(modified(), first);
+    fs::remove_file(&path).ok();
+}
+
+const DETERMINISM_OUTPUT_ENV: &str = "TAURI_SPECTA_DETERMINISM_OUTPUT";
+
+/// Exports the bindings to the path in [`DETERMINISM_OUTPUT_ENV`] when run by [`export_is_deterministic_across_processes`].
+#[test]
+fn export_for_determinism_check() {
+    let Some(path) = env::var_os(DETERMINISM_OUTPUT_ENV) else {
+        return;
+    };
+
+    let mut builder = Builder::<tauri::Wry>::new().types(type_collection());
+    for name in TYPE_NAMES {
+        builder = builder.constant(format!("{}_CONSTANT", name.to_uppercase()), *name);
+    }
+
+    builder
+        .export(Typescript::default(), path)
+        .expect("failed to export typescript bindings");
+}
+
+#[test]
+fn export_is_deterministic_across_processes() {
+    let outputs = (0..2)
+        .map(|i| {
+            let path = test_dir(&format!("determinism-{i}")).join("bindings.ts");
+            let status = Command::new(env::current_exe().expect("failed to get test binary"))
+                .args(["--exact", "export_for_determinism_check"])
+                .env(DETERMINISM_OUTPUT_ENV, &path)
+                .status()
+                .expect("failed to run test binary");
+            assert!(status.success());
+
+            let output = fs::read_to_string(&path).expect("failed to read exported bindings");
+            fs::remove_file(&path).ok();
+            output
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(outputs[0], outputs[1]);
+}
+
+mod user {
+    #[derive(specta::Type)]
+    pub struct Settings {
+        pub theme: String,
+    }
+}
+
+mod window {
+    #[derive(specta::Type)]
+    pub struct Settings {
+        pub width: u32,
+    }
+}
+
+#[test]
+fn duplicate_type_names_fail_export() {
+    let mut types = TypeMap::default();
+    user::Settings::reference(&mut types, &[]);
+    window::Settings::reference(&mut types, &[]);
+
+    let err = Builder::<tauri::Wry>::new()
+        .types(types)
+        .export_str(Typescript::default())
+        .expect_err("duplicate type names should fail export");
+    let err = err.to_string();
+
+    assert!(err.contains("'Settings'"), "{err}");
+    assert_eq!(
+        err.matches("'Settings (tests/builder.rs:").count(),
+        2,
+        "both types should be named with where they're defined: {err}"
+    );
+}
+
+mod auth {
+    #[derive(serde::Serialize, specta::Type)]
+    pub struct User {
+        pub token: String,
+    }
+
+    #[tauri::command]
+    #[specta::specta]
+    pub fn current_user() -> User {
+        User {
+            token: String::new(),
+        }
+    }
+}
+
+mod social {
+    #[derive(serde::Serialize, specta::Type)]
+    pub struct User {
+        pub handle: String,
+    }
+
+    #[tauri::command]
+    #[specta::specta]
+    pub fn find_user() -> User {
+        User {
+            handle: String::new(),
+        }
+    }
+}
+
+#[test]
+fn colliding_command_types_fail_export_with_a_rename_hint() {
+    let err = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth::current_user, social::find_user])
+        .export_str(Typescript::default())
+        .expect_err("types with the same name should fail export");
+    let err = err.to_string();
+
+    assert_eq!(err.matches("'User (tests/builder.rs:").count(), 2, "{err}");
+    assert!(err.contains("#[specta(rename = \"...\")]"), "{err}");
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct ThemeChanged(String);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+#[tauri_specta(event_name = "theme-changed")]
+pub struct LegacyThemeChanged(String);
+
+#[test]
+fn duplicate_event_names_fail_export() {
+    let err = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged, LegacyThemeChanged])
+        .export_str(Typescript::default())
+        .expect_err("duplicate event names should fail export");
+    let err = err.to_string();
+
+    assert!(err.contains("'theme-changed'"), "{err}");
+    assert!(err.contains("'ThemeChanged (tests/builder.rs:"), "{err}");
+    assert!(
+        err.contains("'LegacyThemeChanged (tests/builder.rs:"),
+        "{err}"
+    );
+}
+
+#[tauri::command]
+#[specta::specta]
+fn auth_login() -> String {
+    "auth".into()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn files_open() -> String {
+    "files".into()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn settings_load() -> String {
+    "settings".into()
+}
+
+#[test]
+fn duplicate_command_names_are_reported_by_validate() {
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth_login, files_open])
+        .commands(collect_commands![auth_login]);
+
+    let errors = builder
+        .validate()
+        .expect_err("the builder should be invalid");
+    assert!(
+        matches!(
+            &errors[..],
+            [BuilderValidationError::DuplicateCommandName { name }] if name == "auth_login"
+        ),
+        "{errors:?}"
+    );
+    builder
+        .export_str(Typescript::default())
+        .expect_err("duplicate command names should fail export");
+}
+
+#[test]
+fn nested_and_shadowing_constant_groups_are_rejected() {
+    let builder = Builder::<tauri::Wry>::new()
+        .constant("appLimits", 1)
+        .constant_group("app_limits", |b| b.constant("retries", 3))
+        .constant_group("db", |b| {
+            b.constant("pool_size", 10)
+                .constant_group("timeouts", |b| b.constant("connect", 5))
+        });
+
+    let errors = builder
+        .validate()
+        .expect_err("the builder should be invalid");
+    assert!(
+        matches!(
+            &errors[..],
+            [
+                BuilderValidationError::NestedConstantGroup { group, name },
+                BuilderValidationError::ConstantGroupShadowsConstant { name: shadowing },
+            ] if group == "db" && name == "timeouts" && shadowing == "app_limits"
+        ),
+        "{errors:?}"
+    );
+
+    let err = builder
+        .export_str(Typescript::default())
+        .expect_err("invalid constant groups should fail export")
+        .to_string();
+    assert!(err.contains("nested constant group 'db.timeouts'"), "{err}");
+    assert!(err.contains("constant group 'app_limits'"), "{err}");
+}
+
+#[test]
+fn validate_reports_every_problem() {
+    let mut types = TypeMap::default();
+    user::Settings::reference(&mut types, &[]);
+    window::Settings::reference(&mut types, &[]);
+
+    let errors = Builder::<tauri::Wry>::new()
+        .plugin_name("auth plugin")
+        .commands(collect_commands![auth_login, files_open])
+        .commands(collect_commands![auth_login])
+        .events(collect_events![ThemeChanged, LegacyThemeChanged])
+        .constant("filesOpen", true)
+        .types(types)
+        .validate()
+        .expect_err("the builder should be invalid");
+
+    assert!(
+        matches!(
+            &errors[..],
+            [
+                BuilderValidationError::DuplicateEventName { name, types },
+                BuilderValidationError::DuplicateCommandName { name: command },
+                BuilderValidationError::ConstantShadowsCommand { name: constant, .. },
+                BuilderValidationError::DuplicateTypeName { name: ty, .. },
+                BuilderValidationError::InvalidPluginName { name: "auth plugin" },
+            ] if name == "theme-changed" && types.len() == 2 && command == "auth_login" && constant == "filesOpen" && ty == "Settings"
+        ),
+        "{errors:?}"
+    );
+
+    assert_eq!(
+        Builder::<tauri::Wry>::new()
+            .plugin_name("auth")
+            .commands(collect_commands![auth_login, files_open])
+            .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn merge_invoke_handlers_dispatches_across_builders() {
+    let auth = Builder::<MockRuntime>::new().commands(collect_commands![auth_login]);
+    let files = Builder::<MockRuntime>::new().commands(collect_commands![files_open]);
+    let settings = Builder::<MockRuntime>::new().commands(collect_commands![settings_load]);
+
+    let app = mock_builder()
+        .invoke_handler(Builder::merge_invoke_handlers([&auth, &files, &settings]))
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .expect("failed to build webview");
+
+    let invoke = |cmd: &str| {
+        get_ipc_response(
+            &webview,
+            InvokeRequest {
+                cmd: cmd.into(),
+                callback: CallbackFn(0),
+                error: CallbackFn(1),
+                url: "http://tauri.localhost".parse().unwrap(),
+                body: InvokeBody::default(),
+                headers: Default::default(),
+                invoke_key: INVOKE_KEY.to_string(),
+            },
+        )
+        .map(|body| body.deserialize::<String>().unwrap())
+    };
+
+    assert_eq!(invoke("auth_login").unwrap(), "auth");
+    assert_eq!(invoke("files_open").unwrap(), "files");
+    assert_eq!(invoke("settings_load").unwrap(), "settings");
+    assert!(invoke("unknown").is_err());
+}
+
+#[test]
+fn middleware_wraps_commands_in_registration_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let builder = Builder::<MockRuntime>::new()
+        .commands(collect_commands![auth_login, files_open])
+        .add_middleware({
+            let calls = calls.clone();
+            move |invoke, next| {
+                let command = invoke.message.command().to_string();
+                calls.lock().unwrap().push(format!("outer {command}"));
+                next(invoke)
+            }
+        })
+        .add_middleware({
+            let calls = calls.clone();
+            move |invoke, next| {
+                calls.lock().unwrap().push("inner".into());
+                match invoke.message.command() {
+                    "files_open" => {
+                        invoke.resolver.reject("unauthorized");
+                        true
+                    }
+                    _ => next(invoke),
+                }
+            }
+        });
+
+    let app = mock_builder()
+        .invoke_handler(builder.invoke_handler())
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .expect("failed to build webview");
+
+    let invoke = |cmd: &str| {
+        get_ipc_response(
+            &webview,
+            InvokeRequest {
+                cmd: cmd.into(),
+                callback: CallbackFn(0),
+                error: CallbackFn(1),
+                url: "http://tauri.localhost".parse().unwrap(),
+                body: InvokeBody::default(),
+                headers: Default::default(),
+                invoke_key: INVOKE_KEY.to_string(),
+            },
+        )
+        .map(|body| body.deserialize::<String>().unwrap())
+    };
+
+    assert_eq!(invoke("auth_login").unwrap(), "auth");
+    assert!(invoke("files_open").is_err());
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["outer auth_login", "inner", "outer files_open", "inner"]
+    );
+}
+
+#[derive(Type)]
+pub struct UserProfile {
+    posts: Vec<UserPost>,
+}
+
+#[derive(Type)]
+pub struct UserPost {
+    author: PostAuthor,
+}
+
+#[derive(Type)]
+pub struct PostAuthor {
+    profile: Box<UserProfile>,
+}
+
+#[test]
+fn type_cycles_are_reported() {
+    let cycles = Builder::<tauri::Wry>::new()
+        .typ::<UserProfile>()
+        .check_type_cycles()
+        .expect_err("cycle should be found");
+
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0].to_string(),
+        "PostAuthor → UserProfile → UserPost → PostAuthor"
+    );
+}
+
+#[derive(Type)]
+pub struct Team {
+    lead: Box<Member>,
+    office: Box<Office>,
+}
+
+#[derive(Type)]
+pub struct Member {
+    team: Box<Team>,
+}
+
+#[derive(Type)]
+pub struct Office {
+    lead: Box<Member>,
+}
+
+#[test]
+fn every_type_in_a_cycle_is_reported() {
+    let cycles = Builder::<tauri::Wry>::new()
+        .typ::<Team>()
+        .check_type_cycles()
+        .expect_err("cycle should be found");
+
+    let types = cycles
+        .iter()
+        .flat_map(|cycle| cycle.types().iter().map(String::as_str))
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(types, ["Member", "Office", "Team"].into());
+}
+
+#[derive(Type)]
+pub struct Directory {
+    entries: std::collections::HashMap<String, Option<Directory>>,
+}
+
+#[test]
+fn type_cycles_through_optional_map_values_are_exported() {
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<Directory>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains("export type Directory = "), "{output}");
+
+    Builder::<tauri::Wry>::new()
+        .typ::<Directory>()
+        .export_str(tauri_specta::JsonSchema::default())
+        .expect("failed to export json schema");
+}
+
+#[derive(Serialize, Type)]
+pub struct CoverageProfile {
+    settings: CoverageSettings,
+}
+
+#[derive(Serialize, Type)]
+pub struct CoverageSettings {
+    theme: String,
+}
+
+#[derive(Type)]
+pub struct LegacyProfile {
+    avatar: LegacyAvatar,
+}
+
+#[derive(Type)]
+pub struct LegacyAvatar {
+    url: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn load_profile() -> CoverageProfile {
+    CoverageProfile {
+        settings: CoverageSettings {
+            theme: "dark".into(),
+        },
+    }
+}
+
+#[test]
+fn type_coverage_reports_orphaned_types() {
+    let report = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![load_profile])
+        .typ::<CoverageSettings>()
+        .typ::<LegacyProfile>()
+        .type_coverage_report();
+
+    assert_eq!(
+        report.types(),
+        [
+            ("CoverageProfile".to_string(), TypeCoverage::Reachable),
+            ("CoverageSettings".to_string(), TypeCoverage::Reachable),
+            ("LegacyAvatar".to_string(), TypeCoverage::Transitive),
+            ("LegacyProfile".to_string(), TypeCoverage::Unreachable),
+        ]
+    );
+    assert_eq!(report.orphaned().collect::<Vec<_>>(), ["LegacyProfile"]);
+
+    let report = report.to_string();
+    assert!(
+        report.starts_with("4 types: 2 reachable, 1 unreachable, 1 transitive\n"),
+        "{report}"
+    );
+    assert!(
+        report.ends_with("Orphaned types:\n  - LegacyProfile\n"),
+        "{report}"
+    );
+}
+
+#[test]
+fn type_graphs_are_exported_as_dot_and_json() {
+    let builder = Builder::<tauri::Wry>::new().commands(collect_commands![load_profile]);
+    let dir = test_dir("type-graph");
+
+    builder
+        .export_type_graph(GraphFormat::Dot, dir.join("types.dot"))
+        .expect("failed to export type graph");
+    assert_eq!(
+        fs::read_to_string(dir.join("types.dot")).unwrap(),
+        "digraph types {\n\t\"CoverageProfile\";\n\t\"CoverageSettings\";\n\t\"CoverageProfile\" -> \"CoverageSettings\";\n}\n"
+    );
+
+    builder
+        .export_type_graph(GraphFormat::Json, dir.join("types.json"))
+        .expect("failed to export type graph");
+    let graph: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.join("types.json")).unwrap()).unwrap();
+    assert_eq!(
+        graph,
+        serde_json::json!({
+            "CoverageProfile": ["CoverageSettings"],
+            "CoverageSettings": [],
+        })
+    );
+}
+
+#[test]
+fn permissions_are_generated_for_commands() {
+    let dir = test_dir("permissions");
+    let builder = Builder::<tauri::Wry>::new().commands(collect_commands![auth_login, files_open]);
+
+    builder
+        .export_permissions(&dir)
+        .expect("failed to export permissions");
+
+    let command = fs::read_to_string(dir.join("autogenerated/commands/auth_login.toml"))
+        .expect("missing command permissions");
+    assert!(command.contains(r#"identifier = "allow-auth-login""#));
+    assert!(command.contains(r#"commands.allow = ["auth_login"]"#));
+    assert!(command.contains(r#"identifier = "deny-auth-login""#));
+
+    let default_path = dir.join("default.toml");
+    let default = fs::read_to_string(&default_path).expect("missing default permissions");
+    assert!(default.contains(r#""allow-auth-login","#));
+    assert!(default.contains(r#""allow-files-open","#));
+
+    // Permissions added by the user are preserved when regenerating.
+    fs::write(
+        &default_path,
+        default.replace(
+            "# tauri-specta:end",
+            "# tauri-specta:end\n  \"allow-custom\",",
+        ),
+    )
+    .expect("failed to edit default permissions");
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth_login])
+        .export_permissions(&dir)
+        .expect("failed to export permissions");
+
+    let default = fs::read_to_string(&default_path).expect("missing default permissions");
+    assert!(default.contains(r#""allow-auth-login","#));
+    assert!(!default.contains("allow-files-open"));
+    assert!(default.contains(r#""allow-custom","#));
+    assert!(!dir.join("autogenerated/commands/files_open.toml").exists());
+}
+
+#[test]
+fn required_permissions_are_annotated_and_exported() {
+    let path = test_dir("required-permissions").join("permissions.ts");
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth_login, files_open])
+        .required_permission("auth_login", "auth:allow-login");
+
+    let output = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains("@requires-permission auth:allow-login"));
+
+    builder
+        .export_permissions_manifest(&path)
+        .expect("failed to export permissions manifest");
+    let manifest = fs::read_to_string(&path).expect("missing permissions manifest");
+    assert!(manifest.contains(
+        "export const REQUIRED_PERMISSIONS = {\n\tauthLogin: \"auth:allow-login\",\n} as const;"
+    ));
+    assert!(!manifest.contains("filesOpen"));
+}
+
+#[test]
+fn snapshots_are_written_then_compared() {
+    let path = test_dir("snapshots").join("bindings.snap");
+    let builder = Builder::<tauri::Wry>::new().commands(collect_commands![auth_login]);
+
+    assert_ts_snapshot(&builder, Typescript::default(), &path);
+    assert_eq!(
+        fs::read_to_string(&path).expect("missing snapshot"),
+        builder
+            .export_str(Typescript::default())
+            .expect("failed to export typescript bindings")
+    );
+    assert_ts_snapshot(&builder, Typescript::default(), &path);
+
+    let changed = Builder::<tauri::Wry>::new().commands(collect_commands![files_open]);
+    assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        assert_ts_snapshot(&changed, Typescript::default(), &path)
+    }))
+    .is_err());
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct SetupFinished<T: Type>(T);
+
+#[test]
+fn once_handlers_are_only_called_for_the_first_event() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    Builder::<MockRuntime>::new()
+        .events(collect_events![SetupFinished<u32>])
+        .mount_events(&app);
+
+    let received = Arc::new(AtomicUsize::new(0));
+    SetupFinished::<u32>::once(&app, {
+        let received = received.clone();
+        move |event| {
+            assert_eq!(event.payload.0, 1);
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    SetupFinished(1u32)
+        .emit(&app)
+        .expect("failed to emit event");
+    SetupFinished(2u32)
+        .emit(&app)
+        .expect("failed to emit event");
+    assert_eq!(received.load(Ordering::SeqCst), 1);
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct DownloadProgress(u32);
+
+#[test]
+fn renamed_events_are_emitted_under_their_new_name() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    Builder::<MockRuntime>::new()
+        .events(collect_events![DownloadProgress as "app://download/progress"])
+        .mount_events(&app);
+
+    let received = Arc::new(AtomicUsize::new(0));
+    app.listen_any("app://download/progress", {
+        let received = received.clone();
+        move |_| {
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    DownloadProgress(50)
+        .emit(&app)
+        .expect("failed to emit event");
+    assert_eq!(received.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn emit_to_accepts_labels_and_targets_with_explicit_generics() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    Builder::<MockRuntime>::new()
+        .events(collect_events![DownloadProgress])
+        .mount_events(&app);
+
+    let received = Arc::new(AtomicUsize::new(0));
+    DownloadProgress::listen_any(&app, {
+        let received = received.clone();
+        move |_| {
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    DownloadProgress(10)
+        .emit_to::<MockRuntime, _>(&app, "main")
+        .expect("failed to emit event");
+    DownloadProgress(20)
+        .emit_to(&app, EventTarget::Any)
+        .expect("failed to emit event");
+    assert_eq!(received.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn registered_items_can_be_listed() {
+    let builder = Builder::<tauri::Wry>::new()
+        .plugin_name("auth")
+        .commands(collect_commands![auth_login, files_open])
+        .events(collect_events![ThemeChanged])
+        .constant("MAX_USERS", 10)
+        .constant_group("limits", |b| b.constant("RETRIES", 3));
+
+    assert_eq!(
+        builder.command_names(),
+        ["plugin:auth|auth_login", "plugin:auth|files_open"]
+    );
+    assert_eq!(builder.event_names(), ["plugin:auth:theme-changed"]);
+    assert_eq!(builder.constant_keys(), ["MAX_USERS", "limits.RETRIES"]);
+
+    let built = builder.build();
+    assert!(built
+        .command_names()
+        .contains(&"plugin:auth|auth_login".to_string()));
+    assert_eq!(built.event_names(), ["plugin:auth:theme-changed"]);
+}
+
+#[test]
+fn builders_can_be_cloned_and_debugged() {
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth_login])
+        .events(collect_events![ThemeChanged])
+        .constant("MAX_USERS", 10);
+    let clone = builder.clone().commands(collect_commands![files_open]);
+
+    assert_eq!(builder.command_names(), ["auth_login"]);
+    assert_eq!(clone.command_names(), ["auth_login", "files_open"]);
+    assert_eq!(
+        format!("{builder:?}"),
+        r#"Builder { plugin_name: None, commands: ["auth_login"], events: ["theme-changed"], constants: ["MAX_USERS"], types: 1, .. }"#
+    );
+}
+
+#[test]
+fn events_with_invalid_payloads_are_skipped() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    Builder::<MockRuntime>::new()
+        .events(collect_events![ThemeChanged])
+        .mount_events(&app);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    ThemeChanged::listen_any(&app, {
+        let received = received.clone();
+        move |event| received.lock().unwrap().push(event.payload.0)
+    });
+
+    app.emit("theme-changed", 42).expect("failed to emit event");
+    ThemeChanged("dark".into())
+        .emit(&app)
+        .expect("failed to emit event");
+    assert_eq!(*received.lock().unwrap(), ["dark"]);
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct ScriptOutput(String);
+
+#[test]
+fn additional_events_can_be_mounted_after_setup() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    let builder = Builder::<MockRuntime>::new()
+        .plugin_name("scripting")
+        .events(collect_events![ThemeChanged])
+        .additional_events(collect_events![ScriptOutput]);
+
+    let bindings = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(bindings.contains(r#"scriptOutput: "plugin:scripting:script-output""#));
+
+    builder.mount_events(&app);
+    assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        ScriptOutput("early".into()).emit(&app)
+    }))
+    .is_err());
+
+    builder.mount_additional_events(&app, collect_events![ScriptOutput, ThemeChanged]);
+
+    let received = Arc::new(AtomicUsize::new(0));
+    app.listen_any("plugin:scripting:script-output", {
+        let received = received.clone();
+        move |_| {
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    ScriptOutput("hello".into())
+        .emit(&app)
+        .expect("failed to emit event");
+    ThemeChanged("dark".into())
+        .emit(&app)
+        .expect("failed to emit event");
+    assert_eq!(received.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn plugin_events_are_emitted_under_the_name_the_bindings_listen_to() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    let builder = Builder::<MockRuntime>::new()
+        .plugin_name("auth")
+        .events(collect_events![ThemeChanged]);
+    builder.mount_events(&app);
+
+    let bindings = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(bindings.contains(r#"themeChanged: "plugin:auth:theme-changed""#));
+
+    let received = Arc::new(AtomicUsize::new(0));
+    app.listen_any("plugin:auth:theme-changed", {
+        let received = received.clone();
+        move |_| {
+            received.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    ThemeChanged("dark".into())
+        .emit(&app)
+        .expect("failed to emit event");
+    assert_eq!(received.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn constants_shadowing_commands_respect_the_command_case() {
+    let errors = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![auth_login, files_open])
+        .command_case(Case::PascalCase)
+        .rename_command("auth_login", "signIn")
+        .constant("filesOpen", true)
+        .constant("FilesOpen", true)
+        .constant("signIn", true)
+        .validate()
+        .expect_err("the builder should be invalid");
+
+    assert!(
+        matches!(
+            &errors[..],
+            [
+                BuilderValidationError::ConstantShadowsCommand { name: a, command: a_command },
+                BuilderValidationError::ConstantShadowsCommand { name: b, command: b_command },
+            ] if a == "FilesOpen" && a_command == "files_open" && b == "signIn" && b_command == "auth_login"
+        ),
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn merging_keeps_the_plugin_name_and_only_the_event_which_wins_a_name_collision() {
+    let app = mock_builder()
+        .build(mock_context(noop_assets()))
+        .expect("failed to build app");
+    let builder = Builder::<MockRuntime>::new()
+        .events(collect_events![ThemeChanged])
+        .merge(
+            Builder::new()
+                .plugin_name("legacy")
+                .commands(collect_commands![auth_login])
+                .events(collect_events![LegacyThemeChanged]),
+        );
+    builder.mount_events(&app);
+
+    let bindings = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    // The plugin name of the builder being merged into is kept.
+    assert!(
+        bindings.contains(r#"TAURI_INVOKE("auth_login""#),
+        "{bindings}"
+    );
+
+    LegacyThemeChanged("dark".into())
+        .emit(&app)
+        .expect("failed to emit event");
+    assert!(panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        ThemeChanged("dark".into()).emit(&app)
+    }))
+    .is_err());
+}
@@ -0,0 +1,134 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_jsdoc::JSDoc;
+use tauri_specta::{collect_commands, collect_events, Builder, Event, ModuleFormat};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Settings {
+    theme: String,
+    font_size: u32,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn save_settings(settings: Settings) -> Settings {
+    settings
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct SettingsChanged(String);
+
+#[test]
+fn jsdoc_typedefs_are_emitted_once() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_settings])
+        .events(collect_events![SettingsChanged])
+        .export_str(JSDoc::default())
+        .expect("failed to export jsdoc bindings");
+
+    assert_eq!(
+        output
+            .matches("@typedef { { theme: string; font_size: number } } Settings")
+            .count(),
+        1
+    );
+    assert!(output.contains("@param { Settings } settings"));
+    assert!(output.contains("@returns { Promise<Settings> }"));
+    assert!(output.contains(r#"settingsChanged: "settings-changed""#));
+}
+
+#[test]
+fn commonjs_bindings_use_require_and_module_exports() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_settings])
+        .events(collect_events![SettingsChanged])
+        .constant("APP_NAME", "demo")
+        .module_format(ModuleFormat::CommonJs)
+        .export_str(JSDoc::default())
+        .expect("failed to export jsdoc bindings");
+
+    assert!(output.contains(r#"} = require("@tauri-apps/api/core");"#));
+    assert!(!output.contains("import {"));
+    assert!(!output.contains("export const"));
+    assert!(output.contains(
+        "module.exports = { commands, events, APP_NAME, __setInvokeHandler, __setEventHandlers };"
+    ));
+}
+
+#[test]
+fn commonjs_bindings_require_the_tauri_api_path() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_settings])
+        .module_format(ModuleFormat::CommonJs)
+        .tauri_api_path("~/lib/tauri-api")
+        .export_str(JSDoc::default())
+        .expect("failed to export jsdoc bindings");
+
+    assert!(output.contains(r#"} = require("~/lib/tauri-api/core");"#));
+    assert!(!output.contains("@tauri-apps/api"));
+}
+
+#[test]
+fn commonjs_bindings_leave_indented_declarations_untouched() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_settings])
+        .module_format(ModuleFormat::CommonJs)
+        .export_str(JSDoc::default().header("/*\n    export const legacy = true;\n*/"))
+        .expect("failed to export jsdoc bindings");
+
+    assert!(output.contains("\n    export const legacy = true;\n"));
+    assert!(output.contains("\nconst commands = {"));
+    assert!(
+        output.contains("module.exports = { commands, __setInvokeHandler, __setEventHandlers };")
+    );
+}
+
+#[test]
+fn esm_bindings_export_the_globals() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_settings])
+        .module_format(ModuleFormat::Esm)
+        .export_str(JSDoc::default())
+        .expect("failed to export jsdoc bindings");
+
+    assert!(output.contains(r#"} from "@tauri-apps/api/core";"#));
+    assert!(output.contains("export const commands = {"));
+    assert!(output.contains("export { __setInvokeHandler, __setEventHandlers };"));
+    assert!(!output.contains("require("));
+}
+
+#[test]
+fn auto_module_format_follows_the_package_type() {
+    for (package, commonjs) in [
+        (r#"{ "name": "app" }"#, true),
+        (r#"{ "name": "app", "type": "commonjs" }"#, true),
+        (r#"{ "name": "app", "type": "module" }"#, false),
+    ] {
+        let dir = test_dir("jsdoc-auto-module-format");
+        fs::write(dir.join("package.json"), package).expect("failed to write package.json");
+        let path = dir.join("src").join("bindings.js");
+
+        Builder::<tauri::Wry>::new()
+            .commands(collect_commands![save_settings])
+            .export(JSDoc::default(), &path)
+            .expect("failed to export jsdoc bindings");
+
+        let output = fs::read_to_string(&path).expect("failed to read bindings");
+        assert_eq!(output.contains("module.exports = {"), commonjs, "{package}");
+        assert_eq!(
+            output.contains("export const commands"),
+            !commonjs,
+            "{package}"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use tauri_specta::{collect_commands, Builder, JsonSchema};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+fn read_json(path: PathBuf) -> Value {
+    serde_json::from_str(&fs::read_to_string(&path).expect("missing file")).expect("invalid json")
+}
+
+/// A user of the app.
+#[derive(Serialize, Deserialize, Type)]
+pub struct User {
+    name: String,
+    nickname: Option<String>,
+    role: Role,
+    position: (f64, f64),
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Role {
+    Admin,
+    Member { team: String },
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u32) -> User {
+    User {
+        name: id.to_string(),
+        nickname: None,
+        role: Role::Admin,
+        position: (0.0, 0.0),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+fn search_users(query: String, limit: Option<u32>) -> Result<Vec<User>, String> {
+    Err(format!("{query} {limit:?}"))
+}
+
+#[test]
+fn commands_are_exported_as_a_2020_12_schema() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, search_users])
+        .export_str(JsonSchema::default())
+        .expect("failed to export json schema");
+    let schema: Value = serde_json::from_str(&output).expect("invalid json");
+
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(
+        schema["$defs"]["User"]["properties"]["position"],
+        json!({
+            "type": "array",
+            "prefixItems": [{ "type": "number" }, { "type": "number" }],
+            "items": false,
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    );
+    assert_eq!(
+        schema["commands"]["search_users"],
+        json!({
+            "args": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string" },
+                    "limit": { "anyOf": [{ "type": "integer", "minimum": 0 }, { "type": "null" }] },
+                },
+                "required": ["query"],
+            },
+            "result": { "type": "array", "items": { "$ref": "#/$defs/User" } },
+            "error": { "type": "string" },
+        })
+    );
+    assert_eq!(
+        schema["commands"]["get_user"]["args"]["required"],
+        json!(["id"])
+    );
+}
+
+#[test]
+fn types_are_exported_as_draft_07_schemas() {
+    let dir = test_dir("json-schema-draft-07");
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .constant("MAX_USERS", 10)
+        .export_json_schema(&dir)
+        .expect("failed to export json schemas");
+
+    let user = read_json(dir.join("User.json"));
+    assert_eq!(user["$schema"], "http://json-schema.org/draft-07/schema#");
+    assert_eq!(user["title"], "User");
+    assert_eq!(user["$ref"], "#/definitions/User");
+    assert_eq!(
+        user["definitions"]["User"],
+        json!({
+            "type": "object",
+            "description": "A user of the app.",
+            "properties": {
+                "name": { "type": "string" },
+                "nickname": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                "role": { "$ref": "#/definitions/Role" },
+                "position": {
+                    "type": "array",
+                    "items": [{ "type": "number" }, { "type": "number" }],
+                    "minItems": 2,
+                    "maxItems": 2,
+                },
+            },
+            "required": ["name", "nickname", "role", "position"],
+        })
+    );
+    assert_eq!(
+        user["definitions"]["Role"],
+        json!({
+            "anyOf": [
+                { "const": "Admin" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Member": {
+                            "type": "object",
+                            "properties": { "team": { "type": "string" } },
+                            "required": ["team"],
+                        },
+                    },
+                    "required": ["Member"],
+                },
+            ],
+        })
+    );
+    assert!(dir.join("Role.json").exists());
+
+    let constants = read_json(dir.join("constants.json"));
+    assert_eq!(
+        constants["$schema"],
+        "http://json-schema.org/draft-07/schema#"
+    );
+    assert_eq!(constants["properties"]["MAX_USERS"], json!({ "const": 10 }));
+    assert_eq!(constants["required"], json!(["MAX_USERS"]));
+}
+
+/// Emitted when the user changes their theme.
+#[derive(Serialize, Deserialize, Debug, Clone, Type, tauri_specta::Event)]
+pub struct ThemeChanged(String);
+
+#[test]
+fn manifest_uses_the_invoked_and_generated_names() {
+    let path = test_dir("json-schema-manifest").join("manifest.json");
+    Builder::<tauri::Wry>::new()
+        .plugin_name("users")
+        .commands(collect_commands![get_user, search_users])
+        .events(tauri_specta::collect_events![ThemeChanged])
+        .rename_command("search_users", "findUsers")
+        .deprecate_command("get_user", None)
+        .export_manifest(&path)
+        .expect("failed to export manifest");
+    let manifest = read_json(path);
+
+    assert_eq!(
+        manifest["commands"][0],
+        json!({
+            "name": "plugin:users|get_user",
+            "ident": "getUser",
+            "args": [{ "name": "id", "type": { "type": "integer", "minimum": 0 } }],
+            "result": { "$ref": "#/$defs/User" },
+            "error": null,
+            "docs": "",
+            "deprecated": true,
+        })
+    );
+    assert_eq!(manifest["commands"][1]["name"], "plugin:users|search_users");
+    assert_eq!(manifest["commands"][1]["ident"], "findUsers");
+    assert_eq!(
+        manifest["events"],
+        json!([{
+            "name": "plugin:users:theme-changed",
+            "ident": "themeChanged",
+            "payload": { "$ref": "#/$defs/ThemeChanged" },
+        }])
+    );
+    assert_eq!(
+        manifest["$defs"]["User"]["description"],
+        "A user of the app."
+    );
+}
+
+#[test]
+fn openapi_documents_commands_as_paths_and_types_as_components() {
+    let path = test_dir("json-schema-openapi").join("openapi.yaml");
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, search_users])
+        .events(tauri_specta::collect_events![ThemeChanged])
+        .export_openapi(&path)
+        .expect("failed to export OpenAPI document");
+    let openapi = fs::read_to_string(&path).expect("missing OpenAPI document");
+
+    for expected in [
+        "\"openapi\": \"3.0.3\"\n",
+        "\"paths\":\n  \"/commands/get_user\":\n    \"post\":\n      \"operationId\": \"getUser\"\n",
+        "  \"/commands/search_users\":\n    \"post\":\n      \"operationId\": \"searchUsers\"\n",
+        "              \"required\":\n                - \"query\"\n",
+        "                \"$ref\": \"#/components/schemas/User\"\n",
+        "        \"default\":\n          \"content\":\n",
+        "\"components\":\n  \"schemas\":\n    \"Role\":\n",
+        "    \"User\":\n      \"description\": \"A user of the app.\"\n",
+        "          \"nullable\": true\n",
+    ] {
+        assert!(openapi.contains(expected), "missing {expected:?} in {openapi}");
+    }
+    assert!(!openapi.contains("\"null\""), "{openapi}");
+
+    let asyncapi = fs::read_to_string(path.with_extension("asyncapi.yaml"))
+        .expect("missing AsyncAPI document");
+    assert!(
+        asyncapi.contains("\"channels\":\n  \"theme-changed\":\n"),
+        "{asyncapi}"
+    );
+    assert!(
+        asyncapi.contains("\"$ref\": \"#/components/schemas/ThemeChanged\""),
+        "{asyncapi}"
+    );
+}
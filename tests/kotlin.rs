@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri_specta::{collect_commands, Builder, Kotlin};
+
+/// A user of the app.
+#[derive(Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    display_name: String,
+    /// The user's nickname, if they've set one.
+    nickname: Option<String>,
+    age: u8,
+    tags: Vec<String>,
+    status: Status,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Status {
+    Online,
+    Away,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+    Empty,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(shape: Shape) -> User {
+    let _ = shape;
+    unimplemented!()
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Settings {
+    dark_mode: bool,
+    r#in: Option<Status>,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn save_settings(settings: Settings) {
+    let _ = settings;
+}
+
+fn export() -> String {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, save_settings])
+        .export_str(Kotlin::new().package("com.example.plugin"))
+        .expect("failed to export kotlin bindings")
+}
+
+#[test]
+fn types_are_declared_in_the_package() {
+    let output = export();
+
+    assert!(output.contains(
+        "@file:OptIn(ExperimentalSerializationApi::class)\n\npackage com.example.plugin\n\nimport kotlinx.serialization.*\n"
+    ));
+}
+
+#[test]
+fn structs_are_exported_as_data_classes() {
+    let output = export();
+
+    assert!(output.contains(
+        r#"/**
+ * A user of the app.
+ */
+@Serializable
+data class User(
+    val displayName: String,
+    /**
+     * The user's nickname, if they've set one.
+     */
+    val nickname: String? = null,
+    val age: UByte,
+    val tags: List<String>,
+    val status: Status,
+)"#
+    ));
+    assert!(output.contains(
+        r#"@Serializable
+data class Settings(
+    @SerialName("dark_mode") val darkMode: Boolean,
+    val `in`: Status? = null,
+)"#
+    ));
+}
+
+#[test]
+fn enums_are_exported_as_enum_classes_or_sealed_interfaces() {
+    let output = export();
+
+    assert!(output.contains("@Serializable\nenum class Status {\n    Online,\n    Away,\n}"));
+    assert!(output.contains(
+        r#"@Serializable
+@JsonClassDiscriminator("type")
+sealed interface Shape {
+    @Serializable
+    @SerialName("Circle")
+    data class Circle(
+        val radius: Double,
+    ) : Shape
+
+    @Serializable
+    @SerialName("Square")
+    data class Square(
+        val side: Double,
+    ) : Shape
+
+    @Serializable
+    @SerialName("Empty")
+    object Empty : Shape
+}"#
+    ));
+}
@@ -0,0 +1,102 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri_specta::{collect_commands, Builder, QueryKind};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[derive(Serialize, Deserialize, Clone, Type)]
+pub struct User {
+    id: u64,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct NotFound {
+    id: u64,
+}
+
+/// Look up a user.
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u64) -> Result<User, NotFound> {
+    Err(NotFound { id })
+}
+
+#[tauri::command]
+#[specta::specta]
+fn rename_user(id: u64, name: String) -> User {
+    User { id, name }
+}
+
+#[tauri::command]
+#[specta::specta]
+fn watch_users(on_change: tauri::ipc::Channel<User>) {
+    let _ = on_change;
+}
+
+fn builder() -> Builder<tauri::Wry> {
+    Builder::<tauri::Wry>::new().commands(collect_commands![get_user, rename_user])
+}
+
+#[test]
+fn commands_are_exported_as_queries_and_mutations() {
+    let dir = test_dir("react-query-hooks");
+    builder()
+        .command_query_kind("rename_user", QueryKind::Mutation)
+        .export_react_query(
+            Typescript::default().bigint(BigIntExportBehavior::Number),
+            dir.join("hooks.ts"),
+        )
+        .expect("failed to export react query hooks");
+    let hooks = fs::read_to_string(dir.join("hooks.ts")).unwrap();
+
+    assert!(hooks.contains(r#"import { invoke as TAURI_INVOKE } from "@tauri-apps/api/core";"#));
+    assert!(hooks.contains("/**\n * Look up a user.\n */\nexport function useGetUser(id: number): UseQueryResult<User, NotFound> {\n    return useQuery({ queryKey: [\"get_user\", id], queryFn: () => TAURI_INVOKE<User>(\"get_user\", { id }) });\n}"));
+    assert!(hooks.contains("export function useRenameUser(): UseMutationResult<User, Error, { id: number; name: string }> {\n    return useMutation({ mutationFn: ({ id, name }) => TAURI_INVOKE<User>(\"rename_user\", { id, name }) });\n}"));
+    assert!(hooks.contains("export type User = { id: number; name: string }"));
+    assert!(!hooks.contains("TAURI_CHANNEL"));
+}
+
+#[test]
+fn bigints_follow_the_typescript_configuration() {
+    let dir = test_dir("react-query-bigint");
+    assert!(builder()
+        .export_react_query(Typescript::default(), dir.join("hooks.ts"))
+        .is_err());
+
+    builder()
+        .export_react_query(
+            Typescript::default().bigint(BigIntExportBehavior::BigInt),
+            dir.join("hooks.ts"),
+        )
+        .expect("failed to export react query hooks");
+    let hooks = fs::read_to_string(dir.join("hooks.ts")).unwrap();
+    assert!(hooks.contains("export function useGetUser(id: bigint)"));
+}
+
+#[test]
+fn channels_are_imported_when_a_command_takes_one() {
+    let dir = test_dir("react-query-channel");
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![watch_users])
+        .export_react_query(
+            Typescript::default().bigint(BigIntExportBehavior::Number),
+            dir.join("hooks.ts"),
+        )
+        .expect("failed to export react query hooks");
+    let hooks = fs::read_to_string(dir.join("hooks.ts")).unwrap();
+
+    assert!(hooks.contains(
+        r#"import { invoke as TAURI_INVOKE, type Channel as TAURI_CHANNEL } from "@tauri-apps/api/core";"#
+    ));
+    assert!(hooks.contains("{ onChange: TAURI_CHANNEL<User> }"));
+}
@@ -0,0 +1,89 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri_specta::{collect_commands, collect_events, Builder, Event};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct User {
+    id: u64,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct NotFound {
+    id: u64,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u64) -> Result<User, NotFound> {
+    Err(NotFound { id })
+}
+
+#[tauri::command]
+#[specta::specta]
+fn list_users() -> Vec<User> {
+    Vec::new()
+}
+
+/// Emitted when the user changes their theme.
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct ThemeChanged(String);
+
+fn builder() -> Builder<tauri::Wry> {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, list_users])
+        .events(collect_events![ThemeChanged])
+}
+
+#[test]
+fn commands_and_events_are_exported_as_stores() {
+    let dir = test_dir("svelte-stores");
+    builder()
+        .export_svelte(
+            Typescript::default().bigint(BigIntExportBehavior::Number),
+            dir.join("stores.ts"),
+        )
+        .expect("failed to export svelte stores");
+    let stores = fs::read_to_string(dir.join("stores.ts")).unwrap();
+
+    assert!(stores.contains(r#"import { invoke as TAURI_INVOKE } from "@tauri-apps/api/core";"#));
+    assert!(stores.contains(
+        "export type AsyncState<T, E> = { data: T | null; loading: boolean; error: E | null };"
+    ));
+    assert!(stores.contains("export function getUser(id: number) {"));
+    assert!(stores.contains("writable<AsyncState<User, NotFound>>"));
+    assert!(stores.contains(r#"const data = await TAURI_INVOKE<User>("get_user", { id });"#));
+    assert!(stores.contains("export function listUsers() {"));
+    assert!(stores.contains("writable<AsyncState<User[], Error>>"));
+    assert!(stores.contains("export const themeChanged = readable<ThemeChanged | null>(null, "));
+    assert!(stores.contains(r#"TAURI_LISTEN<ThemeChanged>("theme-changed", "#));
+    assert!(!stores.contains("TAURI_CHANNEL"));
+}
+
+#[test]
+fn bigints_follow_the_typescript_configuration() {
+    let dir = test_dir("svelte-bigint");
+    assert!(builder()
+        .export_svelte(Typescript::default(), dir.join("stores.ts"))
+        .is_err());
+
+    builder()
+        .export_svelte(
+            Typescript::default().bigint(BigIntExportBehavior::BigInt),
+            dir.join("stores.ts"),
+        )
+        .expect("failed to export svelte stores");
+    let stores = fs::read_to_string(dir.join("stores.ts")).unwrap();
+    assert!(stores.contains("export type User = { id: bigint; name: string }"));
+}
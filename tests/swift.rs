@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri_specta::{collect_commands, Builder, Swift};
+
+/// A user of the app.
+#[derive(Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    display_name: String,
+    /// The user's nickname, if they've set one.
+    nickname: Option<String>,
+    age: u8,
+    tags: Vec<String>,
+    status: Status,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Status {
+    Online,
+    Away,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+    Empty,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(shape: Shape) -> User {
+    let _ = shape;
+    unimplemented!()
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Settings {
+    dark_mode: bool,
+    r#in: Option<Status>,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn save_settings(settings: Settings) {
+    let _ = settings;
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Event {
+    Click { x: i32, y: i32 },
+    Key(String),
+    Scroll(f64, f64),
+    Quit,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn dispatch(event: Event) {
+    let _ = event;
+}
+
+fn export() -> String {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, save_settings, dispatch])
+        .export_str(Swift::new())
+        .expect("failed to export swift bindings")
+}
+
+#[test]
+fn structs_are_exported_with_memberwise_initializers() {
+    let output = export();
+
+    assert!(output.contains(
+        r#"/// A user of the app.
+struct User: Codable {
+    var displayName: String
+    /// The user's nickname, if they've set one.
+    var nickname: String?
+    var age: UInt8
+    var tags: [String]
+    var status: Status
+
+    init(displayName: String, nickname: String? = nil, age: UInt8, tags: [String], status: Status) {"#
+    ));
+}
+
+#[test]
+fn renamed_fields_are_mapped_with_coding_keys() {
+    let output = export();
+
+    assert!(output.contains(
+        r#"struct Settings: Codable {
+    var darkMode: Bool
+    var `in`: Status?
+
+    init(darkMode: Bool, `in`: Status? = nil) {
+        self.darkMode = darkMode
+        self.`in` = `in`
+    }
+
+    enum CodingKeys: String, CodingKey {
+        case darkMode = "dark_mode"
+        case `in`
+    }
+}"#
+    ));
+    // Without renamed fields the synthesized keys already match.
+    assert!(!output.contains("struct User: Codable {\n    enum CodingKeys"));
+}
+
+#[test]
+fn enums_are_exported_with_associated_values() {
+    let output = export();
+
+    assert!(output.contains(
+        "enum Status: String, Codable {\n    case online = \"Online\"\n    case away = \"Away\"\n}"
+    ));
+    assert!(output.contains(
+        r#"enum Event: Codable {
+    case click(ClickFields)
+    case key(String)
+    case scroll([JSONValue])
+    case quit
+
+    struct ClickFields: Codable {
+        var x: Int32
+        var y: Int32
+"#
+    ));
+    assert!(output.contains(
+        r#"            self = .key(try container.decode(String.self, forKey: AnyCodingKey("Key")))"#
+    ));
+    assert!(output.contains(
+        r#"        switch try container.decode(String.self, forKey: AnyCodingKey("type")) {
+        case "Circle": self = .circle(try decoder.singleValueContainer().decode(CircleFields.self))"#
+    ));
+    assert!(output.contains("enum JSONValue: Codable, Equatable {"));
+    assert!(output.contains("private struct AnyCodingKey: CodingKey {"));
+}
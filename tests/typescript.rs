@@ -0,0 +1,1013 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri_specta::{
+    collect_commands, collect_events, AcronymStyle, Builder, Case, DirConfig, ErrorHandlingMode,
+    Event, ModuleFormat, TraitObjectPolicy, TypescriptDeclarations,
+};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct NotFound {
+    id: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: String) -> Result<String, NotFound> {
+    Err(NotFound { id })
+}
+
+/// Emitted when the user changes their theme.
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct ThemeChanged(String);
+
+#[test]
+fn event_docs_are_emitted() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(
+        "/**\n * Emitted when the user changes their theme.\n */\nthemeChanged: ThemeChanged"
+    ));
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct Envelope<T: Type>(T);
+
+#[derive(Serialize, Debug, Clone, Type, Event)]
+pub struct StatusText<'a>(&'a str);
+
+#[test]
+fn once_is_typed_with_generic_and_borrowed_payloads() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![Envelope<u32>, StatusText<'static>])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("envelope: Envelope<number>"));
+    assert!(output.contains("statusText: StatusText"));
+    assert!(output.contains("export type StatusText = string"));
+    assert!(output.contains("TAURI_EVENT.once(name, arg, __listenTarget__(options, label))"));
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+#[tauri_specta(window = "mini-player")]
+pub struct TrackChanged(String);
+
+#[test]
+fn window_targeted_events_listen_on_their_window() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged, TrackChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert_eq!(TrackChanged::WINDOW, Some("mini-player"));
+    assert!(output.contains("}, {\ntrackChanged: \"mini-player\"\n})"));
+    assert!(!output.contains("themeChanged: \"mini-player\""));
+    assert!(output.contains("window?: string;"));
+}
+
+#[test]
+fn events_can_be_listened_to_and_emitted_to_a_target() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("target?: string | TAURI_API_EVENT.EventTarget;"));
+    assert!(output.contains("const target = options?.target ?? options?.window ?? label;"));
+    assert!(output.contains("TAURI_EVENT.emitTo(target, name, arg)"));
+}
+
+#[test]
+fn event_listeners_accept_an_abort_signal() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("signal?: AbortSignal;"));
+    assert!(output.contains("TAURI_EVENT.listen(name, arg, __listenTarget__(options, label))"));
+}
+
+#[test]
+fn thrown_errors_are_documented() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .error_handling(ErrorHandlingMode::Throw)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output
+        .contains(" * @throws { NotFound }\n */\nasync getUser(id: string) : Promise<string>"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Limits {
+    max_users: u32,
+}
+
+#[test]
+fn constant_types_are_exported() {
+    let output = Builder::<tauri::Wry>::new()
+        .constant_widened("DEFAULT_LIMITS", Limits { max_users: 10 })
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(r#"export const DEFAULT_LIMITS: Limits = {"max_users":10};"#));
+    assert!(output.contains("export type Limits = "));
+}
+
+#[test]
+fn type_aliases_are_exported_with_the_types() {
+    let output = Builder::<tauri::Wry>::new()
+        .type_alias("UserId", "string")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type UserId = string;"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct ExternalId(u128);
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Account {
+    id: ExternalId,
+}
+
+#[test]
+fn overridden_types_are_exported_as_aliases() {
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<Account>()
+        .override_type::<ExternalId>("string")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type ExternalId = string;"));
+    assert!(output.contains("export type Account = { id: ExternalId }"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct InternalState {
+    generation: u32,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn sync_state(state: InternalState) -> u32 {
+    state.generation
+}
+
+#[test]
+fn excluded_types_are_exported_as_unknown() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![sync_state])
+        .exclude_type::<InternalState>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type InternalState = unknown;"));
+    assert!(!output.contains("generation"));
+}
+
+#[test]
+fn constant_groups_are_exported_as_objects() {
+    let output = Builder::<tauri::Wry>::new()
+        .constant_group("db", |b| {
+            b.constant("pool_size", 10_u32)
+                .constant("timeout_secs", 30_u32)
+        })
+        .constant_group("app_limits", |b| {
+            b.constant_widened("defaults", Limits { max_users: 10 })
+        })
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export const db = { poolSize: 10, timeoutSecs: 30 } as const;"));
+    assert!(output.contains(
+        r#"export const appLimits = { defaults: {"max_users":10} as Limits } as const;"#
+    ));
+}
+
+#[test]
+fn deprecated_commands_and_events_are_annotated() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .events(collect_events![ThemeChanged])
+        .deprecate_command("get_user", "Use fetchUser instead")
+        .deprecate_event("theme-changed", None)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(" * @deprecated Use fetchUser instead\n"));
+    assert!(output.contains("/**\n * @deprecated\n * Emitted when the user changes their theme."));
+}
+
+#[derive(Clone, Serialize, Type)]
+pub struct Progress {
+    percent: u8,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn download(on_progress: tauri::ipc::Channel<Vec<Progress>>) {
+    on_progress.send(vec![Progress { percent: 100 }]).ok();
+}
+
+#[test]
+fn channels_are_typed_with_the_tauri_channel() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async download(onProgress: TAURI_CHANNEL<Progress[]>)"));
+    assert!(output.contains("export type Progress = "));
+    assert!(!output.contains("export type TAURI_CHANNEL"));
+}
+
+#[tauri::command]
+#[specta::specta]
+fn watch(on_change: tauri::ipc::Channel<()>) {
+    on_change.send(()).ok();
+}
+
+#[test]
+fn unit_channels_are_typed_with_the_tauri_channel() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![watch])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async watch(onChange: TAURI_CHANNEL<null>)"));
+    assert!(!output.contains("export type TAURI_CHANNEL"));
+}
+
+#[tauri::command]
+#[specta::specta]
+fn tail_logs(filter: String, on_line: tauri::ipc::Channel<String>) {
+    on_line.send(filter).ok();
+}
+
+#[test]
+fn channel_commands_get_async_iterable_streams() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![tail_logs, get_user])
+        .channel_streams(true)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async tailLogs(filter: string, onLine: TAURI_CHANNEL<string>)"));
+    assert!(output.contains(
+        "tailLogsStream(filter: string): AsyncIterable<string> {\n    return __channelStream__(async (onLine) => await TAURI_INVOKE(\"tail_logs\", { filter, onLine }));\n}"
+    ));
+    assert!(!output.contains("getUserStream"));
+    assert!(output.contains("function __channelStream__<T>("));
+}
+
+#[tauri::command]
+#[specta::specta]
+fn list_users() -> Vec<String> {
+    vec![]
+}
+
+#[test]
+fn namespaces_group_commands_and_events() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .namespace("users", |b| {
+            b.commands(collect_commands![list_users])
+                .namespace("admin_tools", |b| b.events(collect_events![ThemeChanged]))
+        })
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    let users = output
+        .find("export namespace users {")
+        .expect("missing users namespace");
+    let admin_tools = output
+        .find("export namespace adminTools {")
+        .expect("missing nested adminTools namespace");
+
+    assert!(output.find("async download(").expect("missing download") < users);
+    assert!(output.find("async listUsers()").expect("missing listUsers") > users);
+    assert!(
+        output
+            .find("themeChanged: ThemeChanged")
+            .expect("missing themeChanged")
+            > admin_tools
+    );
+}
+
+#[test]
+fn export_ts_dir_splits_bindings_into_files() {
+    let dir = test_dir("export-ts-dir");
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .events(collect_events![ThemeChanged])
+        .constant_widened("DEFAULT_LIMITS", Limits { max_users: 10 })
+        .export_ts_dir(Typescript::default(), &dir)
+        .expect("failed to export typescript bindings");
+
+    let read = |name: &str| std::fs::read_to_string(dir.join(name)).expect("missing file");
+
+    assert!(read("types.ts").contains("export type Progress = "));
+    assert!(read("commands.ts").contains(r#"import type { Progress } from "./types";"#));
+    assert!(read("events.ts").contains(r#"import type { ThemeChanged } from "./types";"#));
+    assert!(read("constants.ts").contains("export const DEFAULT_LIMITS: Limits = "));
+
+    let index = read("index.ts");
+    assert!(index.contains(r#"export { commands } from "./commands";"#));
+    assert!(index.contains(r#"export { events } from "./events";"#));
+    assert!(index.contains(r#"export { EVENT_NAMES, type EventName } from "./events";"#));
+    assert!(index.contains(r#"export { DEFAULT_LIMITS } from "./constants";"#));
+    assert!(index.contains(
+        r#"export { __setInvokeHandler, __setEventHandlers, type Result } from "./globals";"#
+    ));
+    assert!(!index.contains("export *"));
+}
+
+#[test]
+fn deno_bindings_use_npm_specifiers_and_explicit_extensions() {
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .module_format(ModuleFormat::Deno);
+
+    let output = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains(r#"from "npm:@tauri-apps/api/core";"#));
+    assert!(!output.contains(r#"from "@tauri-apps/"#));
+
+    let dir = test_dir("export-ts-dir-deno");
+    builder
+        .export_ts_dir(Typescript::default(), &dir)
+        .expect("failed to export typescript bindings");
+
+    let commands = std::fs::read_to_string(dir.join("commands.ts")).expect("missing file");
+    assert!(commands.contains(r#"import type { Progress } from "./types.ts";"#));
+    let index = std::fs::read_to_string(dir.join("index.ts")).expect("missing file");
+    assert!(index.contains(r#"export { commands } from "./commands.ts";"#));
+}
+
+#[test]
+fn tauri_api_path_replaces_the_import_specifier() {
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .module_format(ModuleFormat::Deno)
+        .tauri_api_path("~/lib/tauri-api");
+
+    let output = builder
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains(r#"from "~/lib/tauri-api/core";"#));
+    assert!(!output.contains("@tauri-apps/api"));
+}
+
+#[test]
+fn invoke_and_event_handlers_can_be_replaced() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export function __setInvokeHandler("));
+    assert!(output.contains("export function __setEventHandlers("));
+    assert!(output.contains(r#"await TAURI_INVOKE("download""#));
+    assert!(output.contains("TAURI_EVENT.listen(name, arg, __listenTarget__(options, label))"));
+}
+
+#[tauri::command]
+#[specta::specta]
+fn delete_user(id: String) -> Result<(), NotFound> {
+    Err(NotFound { id })
+}
+
+#[test]
+fn unit_results_resolve_with_null() {
+    let export = |mode| {
+        Builder::<tauri::Wry>::new()
+            .commands(collect_commands![delete_user])
+            .error_handling(mode)
+            .export_str(Typescript::default())
+            .expect("failed to export typescript bindings")
+    };
+
+    let output = export(ErrorHandlingMode::Result);
+    assert!(output.contains("async deleteUser(id: string) : Promise<Result<null, NotFound>>"));
+    assert_eq!(output.matches("export type Result<T, E>").count(), 1);
+
+    let output = export(ErrorHandlingMode::Throw);
+    assert!(output.contains("async deleteUser(id: string) : Promise<null>"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Quota {
+    max_bytes: u64,
+}
+
+#[test]
+fn bigint_constants_follow_the_bigint_behavior() {
+    let export = |bigint| {
+        Builder::<tauri::Wry>::new()
+            .constant("MAX_FILE_SIZE", u64::MAX)
+            .constant("QUOTA", Quota { max_bytes: 1024 })
+            .export_str(Typescript::default().bigint(bigint))
+    };
+
+    let output = export(BigIntExportBehavior::BigInt).expect("failed to export bindings");
+    assert!(output.contains("export const MAX_FILE_SIZE = 18446744073709551615n as const;"));
+    assert!(output.contains(r#"export const QUOTA: Quota = {"max_bytes":1024n};"#));
+
+    let output = export(BigIntExportBehavior::String).expect("failed to export bindings");
+    assert!(output.contains(r#"export const MAX_FILE_SIZE = "18446744073709551615" as const;"#));
+
+    // `Quota` can't be exported without BigInt's either, so only the constant is exported.
+    let err = Builder::<tauri::Wry>::new()
+        .constant("MAX_FILE_SIZE", u64::MAX)
+        .export_str(Typescript::default().bigint(BigIntExportBehavior::Fail))
+        .expect_err("u64::MAX can't be a number");
+    assert!(err.to_string().contains("MAX_FILE_SIZE"), "{err}");
+}
+
+/// Reconcile the vault with the server.
+///
+/// Conflicts are resolved using **last write wins**.
+#[tauri::command]
+#[specta::specta]
+fn reconcile_vault() {}
+
+#[test]
+fn command_docs_are_emitted() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![reconcile_vault])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(
+        "/**\n * Reconcile the vault with the server.\n * \n * Conflicts are resolved using **last write wins**.\n */\nasync reconcileVault()"
+    ));
+}
+
+#[deprecated(note = "use open_project_v2")]
+#[tauri::command]
+#[specta::specta]
+fn open_project() {}
+
+#[test]
+fn deprecated_attributes_are_annotated() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![open_project])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("/**\n * @deprecated use open_project_v2\n */\nasync openProject()"));
+}
+
+#[test]
+fn export_ts_dir_excludes_internal_types_from_the_index() {
+    let dir = test_dir("export-ts-dir-internal");
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![download, get_user])
+        .mark_internal::<Progress>();
+
+    builder
+        .export_ts_dir(Typescript::default(), &dir)
+        .expect("failed to export typescript bindings");
+    let index = std::fs::read_to_string(dir.join("index.ts")).expect("missing index.ts");
+    assert!(index.contains(r#"export type { NotFound } from "./types";"#));
+    assert!(!index.contains("Progress"));
+
+    std::fs::remove_dir_all(&dir).ok();
+    builder
+        .export_ts_dir_with_config(
+            Typescript::default(),
+            &dir,
+            DirConfig::new().index_file(false),
+        )
+        .expect("failed to export typescript bindings");
+    assert!(dir.join("types.ts").exists());
+    assert!(!dir.join("index.ts").exists());
+}
+
+#[test]
+fn declarations_only_declare_the_bindings() {
+    let dir = test_dir("declarations");
+    let builder = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .events(collect_events![ThemeChanged])
+        .constant_widened("DEFAULT_LIMITS", Limits { max_users: 10 });
+
+    builder
+        .export(
+            TypescriptDeclarations::new(Typescript::default()),
+            dir.join("bindings.ts"),
+        )
+        .expect("failed to export typescript declarations");
+    let output = std::fs::read_to_string(dir.join("bindings.d.ts")).expect("missing bindings.d.ts");
+
+    assert!(output.contains("export declare const commands: {\ngetUser(id: string): Promise<Result<string, NotFound>>;\n};"));
+    assert!(output.contains("themeChanged: __Event__<ThemeChanged>;"));
+    assert!(output.contains("export declare const DEFAULT_LIMITS: Limits;"));
+    assert!(!output.contains("TAURI_INVOKE("));
+}
+
+#[test]
+fn constants_of_named_types_are_annotated() {
+    let output = Builder::<tauri::Wry>::new()
+        .constant("DEFAULT_LIMITS", Limits { max_users: 10 })
+        .constant("PRESETS", vec![Limits { max_users: 1 }])
+        .constant("OVERRIDE", None::<Limits>)
+        .constant("APP_MODE", "kiosk")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(r#"export const DEFAULT_LIMITS: Limits = {"max_users":10};"#));
+    assert!(output.contains(r#"export const PRESETS: Limits[] = [{"max_users":1}];"#));
+    assert!(output.contains("export const OVERRIDE: Limits | null = null;"));
+    assert!(output.contains(r#"export const APP_MODE = "kiosk" as const;"#));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+    #[serde(rename = "none")]
+    Unsorted,
+}
+
+#[test]
+fn enum_variants_are_exported_as_arrays() {
+    let output = Builder::<tauri::Wry>::new()
+        .enum_variants::<SortOrder>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type SortOrder = "));
+    assert!(output.contains(
+        r#"export const SortOrderVariants = ["ascending", "descending", "none"] as const;"#
+    ));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Anchor(Position);
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "type")]
+pub enum PointerEvent {
+    Click {
+        x: i32,
+        y: i32,
+    },
+    Scroll {
+        delta: i32,
+        #[serde(flatten)]
+        origin: Position,
+    },
+    Moved(Anchor),
+    Cancel,
+}
+
+#[test]
+fn internally_tagged_enums_are_discriminated_unions() {
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<PointerEvent>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(
+        r#"export type PointerEvent = { type: "Click"; x: number; y: number } | (Position) & { type: "Scroll"; delta: number } | (Anchor) & { type: "Moved" } | { type: "Cancel" }"#
+    ));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct TreeNode {
+    value: i32,
+    children: Vec<TreeNode>,
+    parent: Option<Box<TreeNode>>,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Forest {
+    root: Option<TreeNode>,
+}
+
+#[test]
+fn recursive_types_are_exported_by_reference() {
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<Forest>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type Forest = { root: TreeNode | null }"));
+    assert!(output.contains(
+        "export type TreeNode = { value: number; children: TreeNode[]; parent: TreeNode | null }"
+    ));
+}
+
+#[test]
+fn max_recursion_depth_only_limits_validation() {
+    let export = |depth| {
+        Builder::<tauri::Wry>::new()
+            .typ::<Forest>()
+            .max_recursion_depth(depth)
+            .export_str(Typescript::default())
+            .expect("failed to export typescript bindings")
+    };
+
+    assert_eq!(export(0), export(32));
+}
+
+#[test]
+fn event_names_are_exported_as_constants() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output
+        .contains("export const EVENT_NAMES = {\nthemeChanged: \"theme-changed\"\n} as const;"));
+    assert!(output.contains("export type EventName = keyof typeof EVENT_NAMES;"));
+    assert!(output.contains("themeChanged: EVENT_NAMES.themeChanged"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Page<T> {
+    items: Vec<T>,
+    total: u32,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct User {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Post {
+    title: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn list_posts() -> Page<Post> {
+    Page {
+        items: vec![],
+        total: 0,
+    }
+}
+
+#[test]
+fn generic_types_are_exported_once_with_type_parameters() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![list_posts])
+        .typ::<Page<User>>()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert_eq!(output.matches("export type Page").count(), 1);
+    assert!(output.contains("export type Page<T> = { items: T[]; total: number }"));
+    assert!(output.contains("export type User = { name: string }"));
+    assert!(output.contains("async listPosts() : Promise<Page<Post>>"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Decimal(String);
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Invoice {
+    total: Decimal,
+}
+
+#[test]
+fn overridden_types_can_be_imported() {
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<Invoice>()
+        .override_type_with_import::<Decimal>(
+            "MoneyDecimal",
+            r#"import type { Decimal as MoneyDecimal } from "./money";"#,
+        )
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(r#"import type { Decimal as MoneyDecimal } from "./money";"#));
+    assert!(output.contains("export type Decimal = MoneyDecimal;"));
+    assert!(output.contains("export type Invoice = { total: Decimal }"));
+}
+
+#[test]
+fn imported_overrides_conflicting_with_exported_types_fail_export() {
+    let err = Builder::<tauri::Wry>::new()
+        .typ::<Invoice>()
+        .override_type_with_import::<Decimal>(
+            "Invoice",
+            r#"import type { Invoice } from "./billing";"#,
+        )
+        .export_str(Typescript::default())
+        .expect_err("the import should conflict with the exported type");
+
+    assert!(
+        matches!(err, tauri_specta::Error::TypeOverrideCollision { ref name, .. } if name == "Invoice"),
+        "{err}"
+    );
+}
+
+pub type AnyState = tauri_specta::TraitObject<dyn std::any::Any>;
+
+#[derive(Type)]
+pub struct PluginState {
+    state: AnyState,
+}
+
+#[test]
+fn trait_objects_are_exported_as_unknown_when_allowed() {
+    let err = Builder::<tauri::Wry>::new()
+        .typ::<PluginState>()
+        .export_str(Typescript::default())
+        .expect_err("trait objects should fail export by default");
+    assert!(matches!(err, tauri_specta::Error::TraitObject), "{err}");
+
+    let output = Builder::<tauri::Wry>::new()
+        .typ::<PluginState>()
+        .allow_trait_objects(TraitObjectPolicy::AsUnknown)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains("export type PluginState = { state: TraitObject }"));
+    assert!(output.contains("export type TraitObject = unknown"));
+}
+
+#[test]
+fn schema_version_changes_with_the_commands() {
+    let schema_version = |builder: Builder<tauri::Wry>| {
+        let output = builder
+            .schema_version_constant("SCHEMA_VERSION")
+            .export_str(Typescript::default())
+            .expect("failed to export typescript bindings");
+        let start = output
+            .find("export const SCHEMA_VERSION = \"")
+            .expect("missing SCHEMA_VERSION")
+            + "export const SCHEMA_VERSION = \"".len();
+        let version = output[start..start + 8].to_string();
+        assert!(output[start + 8..].starts_with("\" as const;"));
+        version
+    };
+
+    let version = schema_version(Builder::new().commands(collect_commands![get_user]));
+    assert!(version.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(
+        version,
+        schema_version(Builder::new().commands(collect_commands![get_user]))
+    );
+    assert_ne!(
+        version,
+        schema_version(Builder::new().commands(collect_commands![get_user, list_posts]))
+    );
+}
+
+#[test]
+fn command_and_event_type_maps_use_the_invoked_names() {
+    let output = Builder::<tauri::Wry>::new()
+        .plugin_name("users")
+        .commands(collect_commands![delete_user])
+        .events(collect_events![ThemeChanged])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(
+        "export type Commands = {\n\t\"plugin:users|delete_user\": { args: [id: string]; result: null; error: UsersNotFound };\n};"
+    ));
+    assert!(output.contains(
+        "export type Events = {\n\t\"plugin:users:theme-changed\": UsersThemeChanged;\n};"
+    ));
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct FileChanged(String);
+
+#[test]
+fn merged_plugin_events_are_namespaced_under_the_plugin() {
+    let plugin = Builder::<tauri::Wry>::new()
+        .plugin_name("fs-watch")
+        .events(collect_events![FileChanged]);
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![ThemeChanged])
+        .merge(plugin)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(r#"fileChanged: "plugin:fs-watch:file-changed""#));
+    assert!(output.contains(r#"themeChanged: "theme-changed""#));
+    assert!(output.contains("export namespace fsWatch {"));
+}
+
+#[test]
+fn invoke_options_are_passed_through_to_invoke() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, list_users])
+        .invoke_options(true)
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async getUser(id: string, options?: TAURI_INVOKE_OPTIONS)"));
+    assert!(output.contains(r#"await TAURI_INVOKE("get_user", { id }, options)"#));
+    assert!(output.contains("async listUsers(options?: TAURI_INVOKE_OPTIONS)"));
+    assert!(output.contains(r#"await TAURI_INVOKE("list_users", {}, options)"#));
+}
+
+#[cfg(any())]
+#[tauri::command]
+#[specta::specta]
+fn devtools_dump() -> String {
+    String::new()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn open_native_dialog() {}
+
+#[test]
+fn cfg_gated_commands_are_omitted_unless_only_the_handler_is_gated() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![
+            #[cfg(any())]
+            devtools_dump,
+            #[handler_cfg(any())]
+            open_native_dialog,
+            list_users,
+        ])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(!output.contains("devtoolsDump"));
+    assert!(output.contains("async openNativeDialog()"));
+    assert!(output.contains("async listUsers()"));
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct DownloadProgress(u32);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+#[tauri_specta(event_name = "app://upload/progress")]
+pub struct UploadProgress(u32);
+
+#[test]
+fn events_can_be_renamed() {
+    let output = Builder::<tauri::Wry>::new()
+        .events(collect_events![DownloadProgress as "app://download/progress", UploadProgress])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains(r#"appDownloadProgress: "app://download/progress""#));
+    assert!(output.contains(r#"appUploadProgress: "app://upload/progress""#));
+    assert!(!output.contains("download-progress"));
+}
+
+#[test]
+fn commands_and_events_can_be_renamed_in_the_bindings() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .events(collect_events![ThemeChanged])
+        .rename_command("get_user", "fetchUser")
+        .rename_event("theme-changed", "onThemeChanged")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async fetchUser(id: string)"));
+    assert!(output.contains(r#"TAURI_INVOKE("get_user", { id })"#));
+    assert!(!output.contains("getUser"));
+    assert!(output.contains(r#"onThemeChanged: "theme-changed""#));
+    assert!(output.contains("onThemeChanged: ThemeChanged"));
+    assert!(!output.contains("themeChanged"));
+}
+
+#[test]
+fn default_builder_uses_the_wry_runtime() {
+    let output = <Builder>::default()
+        .commands(collect_commands![get_user])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async getUser(id: string)"));
+
+    // `Default` is still implemented for every runtime.
+    let output = Builder::<tauri::test::MockRuntime>::default()
+        .commands(collect_commands![get_user])
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async getUser(id: string)"));
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_avatar_url(user_id: String) -> String {
+    user_id
+}
+
+#[test]
+fn command_and_event_identifiers_can_be_recased() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_avatar_url])
+        .events(collect_events![ThemeChanged])
+        .command_case(Case::PascalCase)
+        .event_case(Case::SnakeCase)
+        .acronym_style(AcronymStyle::Preserve(vec!["URL".into()]))
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("async GetAvatarURL(userId: string)"));
+    assert!(output.contains(r#"TAURI_INVOKE("get_avatar_url", { userId })"#));
+    assert!(output.contains(r#"theme_changed: "theme-changed""#));
+}
+
+#[test]
+fn type_names_can_be_prefixed_and_suffixed() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .events(collect_events![ThemeChanged])
+        .type_prefix("App")
+        .type_suffix("Dto")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type AppNotFoundDto = "));
+    assert!(output.contains("export type AppThemeChangedDto = "));
+    assert!(output.contains("error: AppNotFoundDto"));
+    assert!(output.contains(r#""theme-changed": AppThemeChangedDto"#));
+    assert!(output.contains("async getUser(id: string)"));
+    assert!(!output.contains("export type NotFound "));
+}
+
+#[test]
+fn plugin_types_are_prefixed_with_the_plugin_name() {
+    let plugin = || {
+        Builder::<tauri::Wry>::new()
+            .plugin_name("fs-watch")
+            .commands(collect_commands![get_user])
+    };
+
+    let output = plugin()
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains("export type FsWatchNotFound = "));
+    assert!(output.contains("error: FsWatchNotFound"));
+
+    let output = plugin()
+        .type_prefix("")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+    assert!(output.contains("export type NotFound = "));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Member {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[specta(inline)]
+pub enum Invite {
+    Existing(Member),
+    New { email: String },
+}
+
+#[tauri::command]
+#[specta::specta]
+fn invite(invite: Invite, pair: [Member; 2]) -> Vec<Member> {
+    let _ = invite;
+    pair.into()
+}
+
+#[test]
+fn type_prefix_renames_references_in_inline_types() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![invite])
+        .type_prefix("App")
+        .export_str(Typescript::default())
+        .expect("failed to export typescript bindings");
+
+    assert!(output.contains("export type AppMember = "));
+    assert!(output.contains("{ Existing: AppMember }"));
+    assert!(output.contains("pair: [AppMember, AppMember]"));
+    assert!(output.contains("Promise<AppMember[]>"));
+    assert!(!output.contains(": Member"));
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::BigIntExportBehavior;
+use tauri_specta::{collect_commands, Builder, Valibot};
+
+/// A registered user.
+#[derive(Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    id: u32,
+    display_name: String,
+    #[specta(optional)]
+    nickname: Option<String>,
+    role: Role,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(untagged)]
+pub enum Id {
+    Number(u32),
+    Name(String),
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Category {
+    name: String,
+    children: Vec<Category>,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Counter {
+    value: u64,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u32) -> User {
+    User {
+        id,
+        display_name: String::new(),
+        nickname: None,
+        role: Role::Guest,
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+fn draw(shape: Shape, id: Id) -> Category {
+    let _ = (shape, id);
+    Category {
+        name: String::new(),
+        children: Vec::new(),
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+fn counter() -> Counter {
+    Counter { value: 0 }
+}
+
+#[test]
+fn structs_are_exported_as_objects() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .export_str(Valibot::default())
+        .expect("failed to export valibot schemas");
+
+    assert!(output.contains(r#"import * as v from "valibot";"#));
+    assert!(output.contains("export const UserSchema = v.object({ id: v.number(), displayName: v.string(), nickname: v.optional(v.nullable(v.string())), role: v.lazy(() => RoleSchema) });"));
+    assert!(output.contains("export type User = v.InferOutput<typeof UserSchema>;"));
+    assert!(output.contains(
+        r#"export const RoleSchema = v.union([v.literal("Admin"), v.literal("Guest")]);"#
+    ));
+}
+
+#[test]
+fn serde_enum_representations_are_exported() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![draw])
+        .export_str(Valibot::default())
+        .expect("failed to export valibot schemas");
+
+    assert!(output.contains(r#"export const ShapeSchema = v.variant("type", [v.object({ type: v.literal("Circle"), radius: v.number() }), v.object({ type: v.literal("Square"), side: v.number() })]);"#));
+    assert!(output.contains("export const IdSchema = v.union([v.number(), v.string()]);"));
+}
+
+#[test]
+fn recursive_schemas_are_annotated_with_their_type() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![draw])
+        .export_str(Valibot::default())
+        .expect("failed to export valibot schemas");
+
+    assert!(output.contains("export type Category = { name: string; children: Category[] };"));
+    assert!(output.contains("export const CategorySchema: v.GenericSchema<Category> = v.object({ name: v.string(), children: v.array(v.lazy(() => CategorySchema)) });"));
+    assert!(!output.contains("v.InferOutput<typeof CategorySchema>"));
+}
+
+#[test]
+fn bigints_follow_the_configured_behaviour() {
+    let builder = || Builder::<tauri::Wry>::new().commands(collect_commands![counter]);
+
+    assert!(builder().export_str(Valibot::default()).is_err());
+
+    let output = builder()
+        .export_str(Valibot::default().bigint(BigIntExportBehavior::BigInt))
+        .expect("failed to export valibot schemas");
+    assert!(output.contains("export const CounterSchema = v.object({ value: v.bigint() });"));
+}
+
+#[test]
+fn type_prefix_renames_schemas_and_aliases() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .type_prefix("App")
+        .export_str(Valibot::default())
+        .expect("failed to export valibot schemas");
+
+    assert!(output.contains("export const AppUserSchema = "));
+    assert!(output.contains("role: v.lazy(() => AppRoleSchema)"));
+    assert!(output.contains("export type AppUser = v.InferOutput<typeof AppUserSchema>;"));
+}
@@ -0,0 +1,91 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri_specta::{collect_commands, collect_events, Builder, Event};
+
+/// An empty directory under the system temp dir which is unique to the calling test and process.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("tauri-specta-{name}-{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("failed to create test directory");
+    dir
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct User {
+    id: u64,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct NotFound {
+    id: u64,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u64) -> Result<User, NotFound> {
+    Err(NotFound { id })
+}
+
+#[tauri::command]
+#[specta::specta]
+fn list_users() -> Vec<User> {
+    Vec::new()
+}
+
+/// Emitted when the user changes their theme.
+#[derive(Serialize, Deserialize, Debug, Clone, Type, Event)]
+pub struct ThemeChanged(String);
+
+fn builder() -> Builder<tauri::Wry> {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, list_users])
+        .events(collect_events![ThemeChanged])
+}
+
+#[test]
+fn commands_and_events_are_exported_as_composables() {
+    let dir = test_dir("vue-composables");
+    builder()
+        .export_vue_composables(
+            Typescript::default().bigint(BigIntExportBehavior::Number),
+            dir.join("composables.ts"),
+        )
+        .expect("failed to export vue composables");
+    let composables = fs::read_to_string(dir.join("composables.ts")).unwrap();
+
+    assert!(
+        composables.contains(r#"import { invoke as TAURI_INVOKE } from "@tauri-apps/api/core";"#)
+    );
+    assert!(composables.contains("export function useGetUser(id: Ref<number> | number) {"));
+    assert!(composables.contains("const error = ref<NotFound | null>(null);"));
+    assert!(composables
+        .contains(r#"data.value = await TAURI_INVOKE<User>("get_user", { id: unref(id) });"#));
+    assert!(composables.contains("watch(() => [unref(id)], refetch);"));
+    assert!(composables.contains("export function useListUsers() {"));
+    assert!(composables.contains(r#"data.value = await TAURI_INVOKE<User[]>("list_users");"#));
+    assert!(composables
+        .contains("export function useThemeChanged(handler: (payload: ThemeChanged) => void) {"));
+    assert!(composables.contains(r#"TAURI_LISTEN<ThemeChanged>("theme-changed", "#));
+    assert!(!composables.contains("TAURI_CHANNEL"));
+}
+
+#[test]
+fn bigints_follow_the_typescript_configuration() {
+    let dir = test_dir("vue-bigint");
+    assert!(builder()
+        .export_vue_composables(Typescript::default(), dir.join("composables.ts"))
+        .is_err());
+
+    builder()
+        .export_vue_composables(
+            Typescript::default().bigint(BigIntExportBehavior::BigInt),
+            dir.join("composables.ts"),
+        )
+        .expect("failed to export vue composables");
+    let composables = fs::read_to_string(dir.join("composables.ts")).unwrap();
+    assert!(composables.contains("export type User = { id: bigint; name: string }"));
+}
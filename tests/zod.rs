@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use specta_typescript::BigIntExportBehavior;
+use tauri_specta::{collect_commands, Builder, Zod};
+
+/// A registered user.
+#[derive(Serialize, Deserialize, Type)]
+pub struct User {
+    id: u32,
+    name: String,
+    nickname: Option<String>,
+    role: Role,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct NotFound {
+    id: u32,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_user(id: u32) -> Result<User, NotFound> {
+    Err(NotFound { id })
+}
+
+#[tauri::command]
+#[specta::specta]
+fn log_out() {}
+
+#[test]
+fn structs_are_exported_as_objects() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains(r#"import { z } from "zod";"#));
+    assert!(output.contains("export const UserSchema = z.object({ id: z.number(), name: z.string(), nickname: z.string().nullable(), role: z.lazy(() => RoleSchema) });"));
+    assert!(output.contains("export type User = z.infer<typeof UserSchema>;"));
+}
+
+#[test]
+fn enums_are_exported_as_unions() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user])
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains(
+        r#"export const RoleSchema = z.union([z.literal("Admin"), z.literal("Guest")]);"#
+    ));
+}
+
+#[test]
+fn commands_are_exported_as_functions() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![get_user, log_out])
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains(r#"export const GetUserSchema = z.function().args(z.number()).returns(z.promise(z.discriminatedUnion("status", [z.object({ status: z.literal("ok"), data: z.lazy(() => UserSchema) }), z.object({ status: z.literal("error"), error: z.lazy(() => NotFoundSchema) })])));"#));
+    assert!(output
+        .contains("export const LogOutSchema = z.function().args().returns(z.promise(z.void()));"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Member {
+    name: String,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn pair(members: [Member; 2]) -> Vec<Member> {
+    members.into()
+}
+
+#[test]
+fn type_prefix_renames_schemas_and_aliases() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![pair])
+        .type_prefix("App")
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains("export const AppMemberSchema = "));
+    assert!(output.contains("export type AppMember = z.infer<typeof AppMemberSchema>"));
+    assert!(output.contains("z.array(z.lazy(() => AppMemberSchema)).length(2)"));
+    assert!(!output.contains(" MemberSchema"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "type")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(tag = "kind", content = "data")]
+pub enum Message {
+    Text(String),
+    Ping,
+}
+
+#[derive(Serialize, Deserialize, Type)]
+#[serde(untagged)]
+pub enum Id {
+    Number(u32),
+    Name(String),
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Draft {
+    #[specta(optional)]
+    title: Option<String>,
+    shape: Shape,
+    message: Message,
+    id: Id,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn save_draft(draft: Draft) -> Draft {
+    draft
+}
+
+#[test]
+fn serde_enum_representations_and_optional_fields_are_exported() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![save_draft])
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains(r#"export const ShapeSchema = z.discriminatedUnion("type", [z.object({ type: z.literal("Circle"), radius: z.number() }), z.object({ type: z.literal("Square"), side: z.number() })]);"#));
+    assert!(output.contains(r#"export const MessageSchema = z.discriminatedUnion("kind", [z.object({ kind: z.literal("Text"), data: z.string() }), z.object({ kind: z.literal("Ping") })]);"#));
+    assert!(output.contains("export const IdSchema = z.union([z.number(), z.string()]);"));
+    assert!(output.contains("title: z.string().nullable().optional()"));
+    assert!(output.contains("export const SaveDraftSchema = z.function().args(z.lazy(() => DraftSchema)).returns(z.promise(z.lazy(() => DraftSchema)));"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Category {
+    name: String,
+    children: Vec<Category>,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn categories() -> Category {
+    Category {
+        name: String::new(),
+        children: Vec::new(),
+    }
+}
+
+#[test]
+fn recursive_schemas_are_annotated_with_their_type() {
+    let output = Builder::<tauri::Wry>::new()
+        .commands(collect_commands![categories])
+        .export_str(Zod::default())
+        .expect("failed to export zod schemas");
+
+    assert!(output.contains("export type Category = { name: string; children: Category[] };"));
+    assert!(output.contains("export const CategorySchema: z.ZodType<Category> = z.object({ name: z.string(), children: z.array(z.lazy(() => CategorySchema)) });"));
+    assert!(!output.contains("z.infer<typeof CategorySchema>"));
+}
+
+#[derive(Serialize, Deserialize, Type)]
+pub struct Counter {
+    value: u64,
+}
+
+#[tauri::command]
+#[specta::specta]
+fn counter() -> Counter {
+    Counter { value: 0 }
+}
+
+#[test]
+fn bigints_follow_the_configured_behaviour() {
+    let builder = || Builder::<tauri::Wry>::new().commands(collect_commands![counter]);
+
+    assert!(builder().export_str(Zod::default()).is_err());
+
+    let output = builder()
+        .export_str(Zod::default().bigint(BigIntExportBehavior::BigInt))
+        .expect("failed to export zod schemas");
+    assert!(output.contains("export const CounterSchema = z.object({ value: z.bigint() });"));
+
+    let output = builder()
+        .export_str(Zod::default().bigint(BigIntExportBehavior::String))
+        .expect("failed to export zod schemas");
+    assert!(output.contains("export const CounterSchema = z.object({ value: z.string() });"));
+}